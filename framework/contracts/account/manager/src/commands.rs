@@ -5,6 +5,7 @@ use abstract_std::{
         AdapterBaseMsg, AuthorizedAddressesResponse, BaseExecuteMsg, BaseQueryMsg,
         ExecuteMsg as AdapterExecMsg, QueryMsg as AdapterQuery,
     },
+    app::{BaseExecuteMsg as AppBaseExecuteMsg, ExecuteMsg as AppExecMsg},
     manager::{
         state::{
             AccountInfo, SuspensionStatus, ACCOUNT_MODULES, CONFIG, DEPENDENTS, INFO,
@@ -45,6 +46,7 @@ use crate::{
 
 pub const REGISTER_MODULES_DEPENDENCIES: u64 = 1;
 pub const HANDLE_ADAPTER_AUTHORIZED_REMOVE: u64 = 2;
+pub const HANDLE_APP_UNINSTALL: u64 = 3;
 
 #[abstract_response(MANAGER)]
 pub struct ManagerResponse;
@@ -127,8 +129,10 @@ pub(crate) fn _install_modules(
         .api
         .addr_canonicalize(module_factory_address.as_str())?;
 
-    let (infos, init_msgs): (Vec<_>, Vec<_>) =
-        modules.into_iter().map(|m| (m.module, m.init_msg)).unzip();
+    let (infos, init_msgs): (Vec<_>, Vec<_>) = modules
+        .into_iter()
+        .map(|m| (m.module, (m.init_msg, m.funds)))
+        .unzip();
     let modules = version_control
         .query_modules_configs(infos, &deps.querier)
         .map_err(|error| ManagerError::QueryModulesFailed { error })?;
@@ -138,7 +142,9 @@ pub(crate) fn _install_modules(
     let mut add_to_manager = Vec::with_capacity(modules.len());
 
     let salt: Binary = generate_instantiate_salt(&account_id);
-    for (ModuleResponse { module, .. }, init_msg) in modules.into_iter().zip(init_msgs) {
+    for (ModuleResponse { module, .. }, (init_msg, module_funds)) in
+        modules.into_iter().zip(init_msgs)
+    {
         // Check if module is already enabled.
         if ACCOUNT_MODULES
             .may_load(deps.storage, &module.info.id())?
@@ -181,7 +187,9 @@ pub(crate) fn _install_modules(
             }
             _ => return Err(ManagerError::ModuleNotInstallable(module.info.to_string())),
         };
-        manager_modules.push(FactoryModuleInstallConfig::new(module.info, init_msg_salt));
+        manager_modules.push(
+            FactoryModuleInstallConfig::new(module.info, init_msg_salt).with_funds(module_funds),
+        );
     }
 
     INSTALL_MODULES_CONTEXT.save(deps.storage, &install_context)?;
@@ -435,6 +443,19 @@ pub(crate) fn _uninstall_module(deps: DepsMut, module_id: String) -> ManagerResu
     )?;
 
     let mut response = ManagerResponse::new("uninstall_module", vec![("module", &module_id)]);
+    // Let the app clean up while it's still whitelisted on the proxy.
+    // Apps released before the uninstall hook reject the message, which must not block their removal.
+    if let ModuleReference::App(_) = module.reference {
+        let module_addr = load_module_addr(deps.storage, &module_id)?;
+        response = response.add_submessage(SubMsg::reply_on_error(
+            wasm_execute(
+                module_addr,
+                &AppExecMsg::<Empty, Empty>::Base(AppBaseExecuteMsg::Uninstall {}),
+                vec![],
+            )?,
+            HANDLE_APP_UNINSTALL,
+        ));
+    }
     // Remove module from proxy whitelist if it supposed to be removed
     if module.should_be_whitelisted() {
         let proxy = ACCOUNT_MODULES.load(deps.storage, PROXY)?;
@@ -1163,6 +1184,17 @@ pub(crate) fn adapter_authorized_remove(deps: DepsMut, result: SubMsgResult) ->
     Ok(Response::new())
 }
 
+/// The app's uninstall hook failed.
+/// Apps released before the uninstall hook don't know the message, their uninstall proceeds without it.
+/// Any other error aborts the uninstall.
+pub(crate) fn app_uninstall_failed(result: SubMsgResult) -> ManagerResult {
+    let error = result.unwrap_err();
+    if !error.contains("unknown variant") {
+        return Err(ManagerError::AppUninstallFailed { error });
+    }
+    Ok(Response::new().add_attribute("uninstall_hook_error", error))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1975,5 +2007,32 @@ mod tests {
         }
     }
 
+    mod app_uninstall_failed {
+        use super::*;
+
+        #[test]
+        fn tolerates_apps_without_uninstall_hook() -> ManagerTestResult {
+            let error = "Error parsing into type app::ExecuteMsg: unknown variant `uninstall`";
+
+            let res = app_uninstall_failed(SubMsgResult::Err(error.to_owned()))?;
+            assert_that!(res.attributes)
+                .is_equal_to(vec![Attribute::new("uninstall_hook_error", error)]);
+
+            Ok(())
+        }
+
+        #[test]
+        fn propagates_hook_errors() -> ManagerTestResult {
+            let res = app_uninstall_failed(SubMsgResult::Err("insufficient funds".to_owned()));
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::AppUninstallFailed {
+                    error: "insufficient funds".to_owned(),
+                });
+
+            Ok(())
+        }
+    }
+
     // upgrade_modules tests are in the integration tests `upgrades`
 }
@@ -240,6 +240,7 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             queries::handle_sub_accounts_query(deps, start_after, limit)
         }
         QueryMsg::TopLevelOwner {} => queries::handle_top_level_owner_query(deps, env),
+        QueryMsg::Snapshot {} => queries::handle_snapshot_query(deps),
     }
 }
 
@@ -271,6 +272,7 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> ManagerResult {
         commands::HANDLE_ADAPTER_AUTHORIZED_REMOVE => {
             commands::adapter_authorized_remove(deps, msg.result)
         }
+        commands::HANDLE_APP_UNINSTALL => commands::app_uninstall_failed(msg.result),
         _ => Err(ManagerError::UnexpectedReply {}),
     }
 }
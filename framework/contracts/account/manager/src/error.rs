@@ -124,4 +124,7 @@ pub enum ManagerError {
 
     #[error("Failed to query modules to install: {error}")]
     QueryModulesFailed { error: VersionControlError },
+
+    #[error("Uninstall hook of the app failed: {error}")]
+    AppUninstallFailed { error: String },
 }
@@ -11,13 +11,14 @@ use abstract_sdk::{
 use abstract_std::{
     manager::{
         state::{Config, SUB_ACCOUNTS, SUSPENSION_STATUS},
-        SubAccountIdsResponse,
+        SnapshotResponse, SubAccountIdsResponse,
     },
     objects::{
         module::{self, ModuleInfo},
         nested_admin::{query_top_level_owner, TopLevelOwnerResponse},
+        oracle::Oracle,
     },
-    AbstractError,
+    proxy, AbstractError, PROXY,
 };
 use cosmwasm_std::{to_json_binary, Addr, Binary, Deps, Env, Order, StdError, StdResult};
 use cw2::ContractVersion;
@@ -44,6 +45,10 @@ pub fn handle_account_info_query(deps: Deps) -> StdResult<Binary> {
 }
 
 pub fn handle_config_query(deps: Deps) -> StdResult<Binary> {
+    to_json_binary(&query_config(deps)?)
+}
+
+fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
     let account_id = ACCOUNT_ID.load(deps.storage)?;
     let Config {
         version_control_address,
@@ -51,7 +56,7 @@ pub fn handle_config_query(deps: Deps) -> StdResult<Binary> {
         ..
     } = CONFIG.load(deps.storage)?;
     let is_suspended = SUSPENSION_STATUS.load(deps.storage)?;
-    to_json_binary(&ConfigResponse {
+    Ok(ConfigResponse {
         account_id,
         is_suspended,
         version_control_address,
@@ -74,6 +79,50 @@ pub fn handle_module_info_query(
 
     let ids_and_addr = res?;
 
+    to_json_binary(&ModuleInfosResponse {
+        module_infos: query_module_infos(deps, ids_and_addr)?,
+    })
+}
+
+pub fn handle_snapshot_query(deps: Deps) -> StdResult<Binary> {
+    let ownership = cw_ownable::get_ownership(deps.storage)?;
+    let info = INFO.load(deps.storage)?;
+    let config = query_config(deps)?;
+
+    let ids_and_addr = ACCOUNT_MODULES
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(String, Addr)>>>()?;
+    let modules = query_module_infos(deps, ids_and_addr)?;
+
+    let proxy_addr = ACCOUNT_MODULES.load(deps.storage, PROXY)?;
+    let proxy_config: proxy::ConfigResponse = deps
+        .querier
+        .query_wasm_smart(&proxy_addr, &proxy::QueryMsg::Config {})?;
+    let whitelisted_addresses = proxy_config
+        .modules
+        .into_iter()
+        .map(|module| deps.api.addr_validate(&module))
+        .collect::<StdResult<Vec<Addr>>>()?;
+    // The base asset is optional on the proxy
+    let base_asset = Oracle::new()
+        .query_base_asset(&deps.querier, proxy_addr)
+        .map_err(|e| StdError::generic_err(e.to_string()))?;
+
+    to_json_binary(&SnapshotResponse {
+        ownership,
+        info,
+        config,
+        modules,
+        whitelisted_addresses,
+        base_asset,
+    })
+}
+
+/// Query the version of each of the provided modules
+fn query_module_infos(
+    deps: Deps,
+    ids_and_addr: Vec<(String, Addr)>,
+) -> StdResult<Vec<ManagerModuleInfo>> {
     let config = CONFIG.load(deps.storage)?;
     let version_control = VersionControlContract::new(config.version_control_address);
 
@@ -86,10 +135,7 @@ pub fn handle_module_info_query(
             address,
         })
     }
-
-    to_json_binary(&ModuleInfosResponse {
-        module_infos: resp_vec,
-    })
+    Ok(resp_vec)
 }
 
 pub fn handle_sub_accounts_query(
@@ -3,7 +3,7 @@ use abstract_integration_tests::{create_default_account, AResult};
 use abstract_interface::*;
 use abstract_manager::error::ManagerError;
 use abstract_std::{
-    manager::ModuleInstallConfig,
+    manager::{self, ModuleInstallConfig},
     objects::{
         module::{ModuleInfo, ModuleStatus, ModuleVersion},
         nested_admin::TopLevelOwnerResponse,
@@ -121,6 +121,51 @@ fn account_app_ownership() -> AResult {
     Ok(())
 }
 
+#[test]
+fn account_snapshot() -> AResult {
+    let chain = MockBech32::new("mock");
+    let sender = chain.sender();
+    let deployment = Abstract::deploy_on(chain.clone(), sender.to_string())?;
+    let account = create_default_account(&deployment.account_factory)?;
+
+    deployment
+        .version_control
+        .claim_namespace(TEST_ACCOUNT_ID, "tester".to_owned())?;
+
+    let app = MockApp::new_test(chain.clone());
+    app.deploy(APP_VERSION.parse().unwrap(), DeployStrategy::Try)?;
+    account.install_app(&app, &MockInitMsg {}, None)?;
+    account.manager.execute(
+        &manager::ExecuteMsg::UpdateInfo {
+            name: Some("snapshot".to_owned()),
+            description: Some("account to snapshot".to_owned()),
+            link: None,
+        },
+        None,
+    )?;
+
+    let snapshot = account.manager.snapshot()?;
+
+    assert_eq!(snapshot.ownership.owner, Some(sender.clone()));
+    assert_eq!(snapshot.info.name, "snapshot");
+    assert_eq!(
+        snapshot.info.description,
+        Some("account to snapshot".to_owned())
+    );
+    assert_eq!(snapshot.config.account_id, TEST_ACCOUNT_ID);
+    let app_info = snapshot
+        .modules
+        .iter()
+        .find(|module| module.id == APP_ID)
+        .unwrap();
+    assert_eq!(app_info.address, app.address()?);
+    assert_eq!(app_info.version.version, APP_VERSION);
+    assert!(snapshot.modules.iter().any(|module| module.id == PROXY));
+    assert!(snapshot.whitelisted_addresses.contains(&app.address()?));
+    assert_eq!(snapshot.base_asset, None);
+    Ok(())
+}
+
 #[test]
 fn subaccount_app_ownership() -> AResult {
     let chain = MockBech32::new("mock");
@@ -194,6 +239,32 @@ fn cant_reinstall_app_after_uninstall() -> AResult {
     Ok(())
 }
 
+#[test]
+fn uninstall_hook_refunds_proxy() -> AResult {
+    let chain = MockBech32::new("mock");
+    let sender = chain.sender();
+    let deployment = Abstract::deploy_on(chain.clone(), sender.to_string())?;
+    let account = create_default_account(&deployment.account_factory)?;
+
+    deployment
+        .version_control
+        .claim_namespace(TEST_ACCOUNT_ID, "tester".to_owned())?;
+
+    let app = MockApp::new_test(chain.clone());
+    app.deploy(APP_VERSION.parse().unwrap(), DeployStrategy::Try)?;
+    account.install_app(&app, &MockInitMsg {}, None)?;
+
+    // The app holds funds in escrow for the account
+    chain.set_balance(&app.address()?, vec![coin(100, "TEST")])?;
+
+    account.manager.uninstall_module(APP_ID.to_owned())?;
+
+    assert_that!(chain.query_all_balances(&app.address()?)?).is_empty();
+    assert_that!(chain.query_all_balances(&account.proxy.address()?)?)
+        .is_equal_to(vec![coin(100, "TEST")]);
+    Ok(())
+}
+
 #[test]
 fn deploy_strategy_uploaded() -> AResult {
     let chain = MockBech32::new("mock");
@@ -292,7 +363,11 @@ fn deploy_strategy_deployed() -> AResult {
 
     // Deploy Error
     let error_res = app.deploy(APP_VERSION.parse().unwrap(), DeployStrategy::Error);
-    assert!(error_res.is_err());
+    assert!(matches!(
+        error_res,
+        Err(AbstractInterfaceError::AlreadyDeployed { module, version })
+            if module == APP_ID && version == APP_VERSION
+    ));
 
     // Deploy Force
     let force_res = app.deploy(APP_VERSION.parse().unwrap(), DeployStrategy::Force);
@@ -10,16 +10,43 @@ use abstract_std::{
         module::{ModuleInfo, ModuleVersion, Monetization},
         module_reference::ModuleReference,
         namespace::Namespace,
-        AccountId, ABSTRACT_ACCOUNT_ID,
+        AccountId, AssetEntry, ABSTRACT_ACCOUNT_ID,
     },
     version_control::{NamespaceResponse, UpdateModule},
     PROXY,
 };
 use abstract_testing::prelude::*;
 use cosmwasm_std::{coin, CosmosMsg};
+use cw_asset::{AssetInfo, AssetInfoUnchecked};
 use cw_orch::prelude::*;
 use speculoos::prelude::*;
 
+#[test]
+fn funded_account_with_assets_fixture() -> AResult {
+    let chain = MockBech32::new("mock");
+    let sender = chain.sender();
+    let deployment = Abstract::deploy_on(chain.clone(), sender.to_string())?;
+
+    let account = create_funded_account_with_assets(
+        chain.clone(),
+        &deployment,
+        vec![("chain>token", AssetInfoUnchecked::native("utoken"))],
+        vec![coin(100, "utoken")],
+    )?;
+
+    let asset: AssetInfo = deployment
+        .ans_host
+        .resolve(&AssetEntry::new("chain>token"))?;
+    assert_that!(asset).is_equal_to(AssetInfo::native("utoken"));
+
+    let proxy_balance = chain
+        .bank_querier()
+        .balance(account.proxy.address()?, Some("utoken".to_owned()))?;
+    assert_that!(proxy_balance).is_equal_to(vec![coin(100, "utoken")]);
+
+    Ok(())
+}
+
 #[test]
 fn instantiate() -> AResult {
     let chain = MockBech32::new("mock");
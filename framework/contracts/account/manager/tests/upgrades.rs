@@ -263,6 +263,109 @@ fn upgrade_app() -> AResult {
     Ok(())
 }
 
+#[test]
+fn upgrade_app_in_place_keeps_state() -> AResult {
+    let chain = MockBech32::new("mock");
+    let sender = chain.sender();
+    let abstr = Abstract::deploy_on(chain.clone(), sender.to_string())?;
+    let account = create_default_account(&abstr.account_factory)?;
+    let AbstractAccount { manager, proxy: _ } = &account;
+    abstr
+        .version_control
+        .claim_namespace(TEST_ACCOUNT_ID, TEST_NAMESPACE.to_string())?;
+    deploy_modules(&chain);
+
+    install_module_version(manager, adapter_1::MOCK_ADAPTER_ID, V1)?;
+    install_module_version(manager, adapter_2::MOCK_ADAPTER_ID, V1)?;
+    let app1 = install_module_version(manager, app_1::MOCK_APP_ID, V1)?;
+    let app1 = Addr::unchecked(app1);
+    let config_before: app::AppConfigResponse = chain.query(
+        &app::QueryMsg::<Empty>::Base(app::BaseQueryMsg::BaseConfig {}),
+        &app1,
+    )?;
+
+    let migrate_msg = app::MigrateMsg {
+        base: app::BaseMigrateMsg {},
+        module: MockMigrateMsg,
+    };
+    // swapping the app alone is guarded by the dependency requirements of v2
+    let res = manager.upgrade_module_version(
+        app_1::MOCK_APP_ID,
+        ModuleVersion::Version(V2.to_string()),
+        &migrate_msg,
+    );
+    assert_that!(res.unwrap_err().root().to_string()).contains(
+        ManagerError::VersionRequirementNotMet {
+            module_id: adapter_1::MOCK_ADAPTER_ID.into(),
+            version: V1.into(),
+            comp: "^2.0.0".into(),
+            post_migration: true,
+        }
+        .to_string(),
+    );
+
+    // swap the app together with its dependencies
+    manager.upgrade(vec![
+        (
+            ModuleInfo::from_id(app_1::MOCK_APP_ID, ModuleVersion::Version(V2.to_string()))?,
+            Some(to_json_binary(&migrate_msg)?),
+        ),
+        (
+            ModuleInfo::from_id_latest(adapter_1::MOCK_ADAPTER_ID)?,
+            None,
+        ),
+        (
+            ModuleInfo::from_id_latest(adapter_2::MOCK_ADAPTER_ID)?,
+            None,
+        ),
+    ])?;
+
+    // the app was migrated in place, so its address and state are unchanged
+    assert_that!(manager.module_address(app_1::MOCK_APP_ID)?).is_equal_to(app1.clone());
+    let config_after: app::AppConfigResponse = chain.query(
+        &app::QueryMsg::<Empty>::Base(app::BaseQueryMsg::BaseConfig {}),
+        &app1,
+    )?;
+    assert_that!(config_after).is_equal_to(config_before);
+
+    let ModuleVersionsResponse { versions } =
+        manager.module_versions(vec![app_1::MOCK_APP_ID.to_string()])?;
+    assert_that!(versions).is_equal_to(vec![ContractVersion {
+        contract: app_1::MOCK_APP_ID.into(),
+        version: V2.into(),
+    }]);
+    Ok(())
+}
+
+#[test]
+fn upgrade_module_version_to_requested_version() -> AResult {
+    let chain = MockBech32::new("mock");
+    let sender = chain.sender();
+    let abstr = Abstract::deploy_on(chain.clone(), sender.to_string())?;
+    let account = create_default_account(&abstr.account_factory)?;
+    let AbstractAccount { manager, proxy: _ } = &account;
+    abstr
+        .version_control
+        .claim_namespace(TEST_ACCOUNT_ID, TEST_NAMESPACE.to_string())?;
+    deploy_modules(&chain);
+
+    install_module_version(manager, adapter_1::MOCK_ADAPTER_ID, V1)?;
+
+    manager.upgrade_module_version(
+        adapter_1::MOCK_ADAPTER_ID,
+        ModuleVersion::Version(V2.to_string()),
+        &Empty {},
+    )?;
+
+    let ModuleVersionsResponse { versions } =
+        manager.module_versions(vec![adapter_1::MOCK_ADAPTER_ID.to_string()])?;
+    assert_that!(versions).is_equal_to(vec![ContractVersion {
+        contract: adapter_1::MOCK_ADAPTER_ID.into(),
+        version: V2.into(),
+    }]);
+    Ok(())
+}
+
 #[test]
 fn uninstall_modules() -> AResult {
     let chain = MockBech32::new("mock");
@@ -3,7 +3,15 @@ use abstract_sdk::std::{
     proxy::state::{ADMIN, ANS_HOST, STATE},
     IBC_CLIENT,
 };
-use abstract_std::objects::{oracle::Oracle, price_source::UncheckedPriceSource, AssetEntry};
+use abstract_std::{
+    objects::{
+        oracle::{self, Oracle, OracleError},
+        price_source::UncheckedPriceSource,
+        AssetEntry,
+    },
+    proxy::{AssetsConfigResponse, AssetsInfoResponse, BaseAssetResponse, QueryMsg},
+    AbstractError,
+};
 use cosmwasm_std::{wasm_execute, CosmosMsg, DepsMut, Empty, MessageInfo, StdError, SubMsg};
 
 use crate::{
@@ -87,6 +95,80 @@ pub fn update_assets(
     Ok(ProxyResponse::action("update_proxy_assets"))
 }
 
+/// Copy the price sources of another proxy
+pub fn import_assets(
+    deps: DepsMut,
+    msg_info: MessageInfo,
+    from_proxy: String,
+    overwrite: bool,
+) -> ProxyResult {
+    // Only Admin can call this method
+    ADMIN.assert_admin(deps.as_ref(), &msg_info.sender)?;
+    let from_proxy = deps.api.addr_validate(&from_proxy)?;
+    let ans_host = &ANS_HOST.load(deps.storage)?;
+    let oracle = Oracle::new();
+
+    // Price sources are relative to the base asset, so both proxies must share it.
+    let BaseAssetResponse {
+        base_asset: source_base_asset,
+    } = deps
+        .querier
+        .query_wasm_smart(&from_proxy, &QueryMsg::BaseAsset {})?;
+    if let Ok(base_asset) = oracle.base_asset(deps.as_ref()) {
+        if base_asset != source_base_asset {
+            return Err(ProxyError::BaseAssetMismatch {
+                base_asset,
+                source_base_asset,
+            });
+        }
+    }
+
+    // The oracle size is limited, so a single page holds all the price sources.
+    let AssetsConfigResponse { assets } = deps.querier.query_wasm_smart(
+        &from_proxy,
+        &QueryMsg::AssetsConfig {
+            start_after: None,
+            limit: Some(oracle::LIST_SIZE_LIMIT),
+        },
+    )?;
+    let AssetsInfoResponse {
+        assets: assets_info,
+    } = deps.querier.query_wasm_smart(
+        &from_proxy,
+        &QueryMsg::AssetsInfo {
+            start_after: None,
+            limit: Some(oracle::LIST_SIZE_LIMIT),
+        },
+    )?;
+
+    // Register the assets in order of complexity so dependencies exist when an asset is added.
+    let entries: Vec<AssetEntry> = assets.iter().map(|(entry, _)| entry.clone()).collect();
+    let resolved = ans_host.query_assets(&deps.querier, &entries)?;
+    let mut assets = assets
+        .into_iter()
+        .zip(resolved)
+        .map(|(asset, info)| {
+            let complexity = assets_info
+                .iter()
+                .find(|(i, _)| i == &info)
+                .map(|(_, oracle_asset)| oracle_asset.complexity)
+                .ok_or_else(|| AbstractError::from(OracleError::NoPriceSource(info.to_string())))?;
+            Ok((complexity, asset))
+        })
+        .collect::<ProxyResult<Vec<_>>>()?;
+    assets.sort_by_key(|(complexity, _)| *complexity);
+    let assets = assets.into_iter().map(|(_, asset)| asset).collect();
+
+    oracle.import_assets(deps, ans_host, assets, overwrite)?;
+    Ok(ProxyResponse::new(
+        "import_proxy_assets",
+        vec![
+            ("from_proxy", from_proxy.to_string()),
+            ("overwrite", overwrite.to_string()),
+        ],
+    ))
+}
+
 /// Add a contract to the whitelist
 pub fn add_modules(deps: DepsMut, msg_info: MessageInfo, modules: Vec<String>) -> ProxyResult {
     ADMIN.assert_admin(deps.as_ref(), &msg_info.sender)?;
@@ -466,6 +548,7 @@ mod test {
                 msg: abstract_std::ibc_client::ExecuteMsg::SendFunds {
                     host_chain: "juno".parse().unwrap(),
                     funds: funds.clone(),
+                    timeout_seconds: None,
                 },
             };
 
@@ -492,6 +575,7 @@ mod test {
                     msg: to_json_binary(&abstract_std::ibc_client::ExecuteMsg::SendFunds {
                         host_chain: "juno".parse().unwrap(),
                         funds: funds.clone(),
+                        timeout_seconds: None,
                     })
                     .unwrap(),
                     funds,
@@ -499,4 +583,179 @@ mod test {
             )));
         }
     }
+
+    mod import_assets {
+        use super::*;
+
+        use abstract_std::proxy::{InstantiateMsg, QueryMsg};
+        use cosmwasm_std::{from_json, Binary, Decimal, DepsMut};
+        use cw_asset::AssetInfo;
+        use cw_controllers::AdminError;
+
+        use crate::contract::{instantiate, query};
+
+        const SOURCE_PROXY: &str = "source_proxy";
+
+        fn base_asset(asset: &str) -> (AssetEntry, UncheckedPriceSource) {
+            (AssetEntry::from(asset), UncheckedPriceSource::None)
+        }
+
+        fn eur_as_usd(percent: u64) -> (AssetEntry, UncheckedPriceSource) {
+            (
+                AssetEntry::from(EUR),
+                UncheckedPriceSource::ValueAs {
+                    asset: AssetEntry::new(USD),
+                    multiplier: Decimal::percent(percent),
+                },
+            )
+        }
+
+        fn init_with_ans(deps: DepsMut) {
+            let msg = InstantiateMsg {
+                account_id: TEST_ACCOUNT_ID,
+                ans_host_address: TEST_ANS_HOST.to_string(),
+                manager_addr: TEST_MANAGER.to_string(),
+                base_asset: None,
+            };
+            instantiate(deps, mock_env(), mock_info(OWNER, &[]), msg).unwrap();
+        }
+
+        fn configured_deps(to_add: Vec<(AssetEntry, UncheckedPriceSource)>) -> MockDeps {
+            let mut deps = mock_dependencies();
+            deps.querier = MockAnsHost::new().with_defaults().to_querier();
+            init_with_ans(deps.as_mut());
+            if !to_add.is_empty() {
+                execute_as_admin(
+                    &mut deps,
+                    ExecuteMsg::UpdateAssets {
+                        to_add,
+                        to_remove: vec![],
+                    },
+                )
+                .unwrap();
+            }
+            deps
+        }
+
+        /// Dependencies whose querier answers the oracle queries of [`SOURCE_PROXY`] with the configuration of `source`.
+        fn importing_deps(
+            source: &MockDeps,
+            to_add: Vec<(AssetEntry, UncheckedPriceSource)>,
+        ) -> MockDeps {
+            let responses: Vec<(QueryMsg, Binary)> = [
+                QueryMsg::BaseAsset {},
+                QueryMsg::AssetsConfig {
+                    start_after: None,
+                    limit: Some(oracle::LIST_SIZE_LIMIT),
+                },
+                QueryMsg::AssetsInfo {
+                    start_after: None,
+                    limit: Some(oracle::LIST_SIZE_LIMIT),
+                },
+            ]
+            .into_iter()
+            .map(|msg| {
+                (
+                    msg.clone(),
+                    query(source.as_ref(), mock_env(), msg).unwrap(),
+                )
+            })
+            .collect();
+
+            let mut deps = configured_deps(to_add);
+            deps.querier = MockAnsHost::new()
+                .with_defaults()
+                .insert_into(MockQuerierBuilder::default())
+                .with_smart_handler(SOURCE_PROXY, move |msg| {
+                    let msg: QueryMsg = from_json(msg).unwrap();
+                    let (_, response) = responses.iter().find(|(m, _)| m == &msg).unwrap();
+                    Ok(response.clone())
+                })
+                .build();
+            deps
+        }
+
+        fn assets_config(deps: &MockDeps) -> Vec<(AssetEntry, UncheckedPriceSource)> {
+            let config: AssetsConfigResponse = from_json(
+                query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::AssetsConfig {
+                        start_after: None,
+                        limit: None,
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            config.assets
+        }
+
+        fn import_msg(overwrite: bool) -> ExecuteMsg {
+            ExecuteMsg::ImportAssets {
+                from_proxy: SOURCE_PROXY.to_string(),
+                overwrite,
+            }
+        }
+
+        #[test]
+        fn only_admin() {
+            let source = configured_deps(vec![base_asset(USD), eur_as_usd(50)]);
+            let mut deps = importing_deps(&source, vec![]);
+
+            let res = execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("not_admin", &[]),
+                import_msg(false),
+            );
+            assert_that(&res)
+                .is_err()
+                .is_equal_to(ProxyError::Admin(AdminError::NotAdmin {}));
+        }
+
+        #[test]
+        fn imports_into_unconfigured_account() {
+            let source = configured_deps(vec![base_asset(USD), eur_as_usd(50)]);
+            let mut deps = importing_deps(&source, vec![]);
+
+            execute_as_admin(&mut deps, import_msg(false)).unwrap();
+
+            assert_that!(assets_config(&deps)).is_equal_to(assets_config(&source));
+        }
+
+        #[test]
+        fn fills_gaps_without_overwrite() {
+            let source = configured_deps(vec![base_asset(USD), eur_as_usd(50)]);
+            let mut deps = importing_deps(&source, vec![base_asset(USD), eur_as_usd(25)]);
+
+            execute_as_admin(&mut deps, import_msg(false)).unwrap();
+
+            assert_that!(assets_config(&deps)).is_equal_to(vec![eur_as_usd(25), base_asset(USD)]);
+        }
+
+        #[test]
+        fn replaces_configured_assets_with_overwrite() {
+            let source = configured_deps(vec![base_asset(USD), eur_as_usd(50)]);
+            let mut deps = importing_deps(&source, vec![base_asset(USD), eur_as_usd(25)]);
+
+            execute_as_admin(&mut deps, import_msg(true)).unwrap();
+
+            assert_that!(assets_config(&deps)).is_equal_to(assets_config(&source));
+        }
+
+        #[test]
+        fn rejects_different_base_asset() {
+            let source = configured_deps(vec![base_asset(USD), eur_as_usd(50)]);
+            let mut deps = importing_deps(&source, vec![base_asset(EUR)]);
+
+            let res = execute_as_admin(&mut deps, import_msg(true));
+            assert_that(&res)
+                .is_err()
+                .is_equal_to(ProxyError::BaseAssetMismatch {
+                    base_asset: AssetInfo::native(EUR),
+                    source_base_asset: AssetInfo::native(USD),
+                });
+        }
+    }
 }
@@ -83,6 +83,10 @@ pub fn execute(deps: DepsMut, _env: Env, info: MessageInfo, msg: ExecuteMsg) ->
         ExecuteMsg::UpdateAssets { to_add, to_remove } => {
             update_assets(deps, info, to_add, to_remove)
         }
+        ExecuteMsg::ImportAssets {
+            from_proxy,
+            overwrite,
+        } => import_assets(deps, info, from_proxy, overwrite),
     }
 }
 
@@ -1,7 +1,7 @@
 use abstract_sdk::AbstractSdkError;
 use abstract_std::{objects::ans_host::AnsHostError, AbstractError};
 use cosmwasm_std::{StdError, Uint128};
-use cw_asset::AssetError;
+use cw_asset::{AssetError, AssetInfo};
 use cw_utils::ParseReplyError;
 use thiserror::Error;
 
@@ -46,6 +46,14 @@ pub enum ProxyError {
     #[error("no base asset registered on proxy")]
     MissingBaseAsset,
 
+    #[error(
+        "base asset {base_asset} differs from base asset {source_base_asset} of the imported proxy"
+    )]
+    BaseAssetMismatch {
+        base_asset: AssetInfo,
+        source_base_asset: AssetInfo,
+    },
+
     #[error("The proposed update resulted in a bad configuration: {0}")]
     BadUpdate(String),
 
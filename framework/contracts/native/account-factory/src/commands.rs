@@ -22,8 +22,8 @@ use abstract_std::{
     AbstractError, IBC_HOST,
 };
 use cosmwasm_std::{
-    ensure_eq, instantiate2_address, to_json_binary, Coins, CosmosMsg, DepsMut, Empty, Env,
-    MessageInfo, Storage, SubMsg, SubMsgResult, WasmMsg,
+    ensure_eq, instantiate2_address, to_json_binary, Binary, Coins, CosmosMsg, Deps, DepsMut,
+    Empty, Env, MessageInfo, QuerierWrapper, Storage, SubMsg, SubMsgResult, WasmMsg,
 };
 
 use crate::{
@@ -49,6 +49,9 @@ pub fn execute_create_account(
     install_modules: Vec<ModuleInstallConfig>,
     account_id: Option<AccountId>,
 ) -> AccountFactoryResult {
+    if PAUSED.may_load(deps.storage)?.unwrap_or_default() {
+        return Err(AccountFactoryError::CreationPaused {});
+    }
     let config = CONFIG.load(deps.storage)?;
     let version_control = VersionControlContract::new(config.version_control_contract.clone());
 
@@ -100,20 +103,8 @@ pub fn execute_create_account(
         None => generate_new_local_account_id(deps.storage, &info)?,
     };
 
-    // Query version_control for code_id of Proxy and Module contract
-    let (manager_module, proxy_module) = {
-        let mut modules = version_control.query_modules_configs(
-            vec![
-                ModuleInfo::from_id_latest(PROXY)?,
-                ModuleInfo::from_id_latest(MANAGER)?,
-            ],
-            &deps.querier,
-        )?;
-        let manager_module: Module = modules.pop().unwrap().module;
-        let proxy_module: Module = modules.pop().unwrap().module;
-
-        (manager_module, proxy_module)
-    };
+    let (proxy_module, manager_module) =
+        query_account_base_modules(&version_control, &deps.querier)?;
 
     let simulate_resp: SimulateInstallModulesResponse = deps.querier.query_wasm_smart(
         config.module_factory_address.to_string(),
@@ -121,7 +112,11 @@ pub fn execute_create_account(
             modules: install_modules.iter().map(|m| m.module.clone()).collect(),
         },
     )?;
-    let funds_for_install = simulate_resp.total_required_funds;
+    let mut funds_for_install = Coins::try_from(simulate_resp.total_required_funds)?;
+    for coin in install_modules.iter().flat_map(|m| m.funds.clone()) {
+        funds_for_install.add(coin)?;
+    }
+    let funds_for_install = funds_for_install.into_vec();
     let funds_for_namespace_fee = if namespace.is_some() {
         version_control
             .namespace_registration_fee(&deps.querier)?
@@ -149,42 +144,10 @@ pub fn execute_create_account(
     let salt = generate_instantiate_salt(&account_id);
 
     // Get code_ids
-    let (proxy_code_id, manager_code_id) = if let (
-        ModuleReference::AccountBase(proxy_code_id),
-        ModuleReference::AccountBase(manager_code_id),
-    ) = (
-        proxy_module.reference.clone(),
-        manager_module.reference.clone(),
-    ) {
-        (proxy_code_id, manager_code_id)
-    } else {
-        return Err(AccountFactoryError::WrongModuleKind(
-            proxy_module.info.to_string(),
-            "account_base".to_string(),
-        ));
-    };
+    let (proxy_code_id, manager_code_id) = account_base_code_ids(&proxy_module, &manager_module)?;
 
-    // Get checksums
-    let proxy_checksum = deps.querier.query_wasm_code_info(proxy_code_id)?.checksum;
-    let manager_checksum = deps.querier.query_wasm_code_info(manager_code_id)?.checksum;
-
-    let proxy_addr = instantiate2_address(
-        &proxy_checksum,
-        &deps.api.addr_canonicalize(env.contract.address.as_str())?,
-        salt.as_slice(),
-    )?;
-    let proxy_addr_human = deps.api.addr_humanize(&proxy_addr)?;
-    let manager_addr = instantiate2_address(
-        &manager_checksum,
-        &deps.api.addr_canonicalize(env.contract.address.as_str())?,
-        salt.as_slice(),
-    )?;
-    let manager_addr_human = deps.api.addr_humanize(&manager_addr)?;
-
-    let account_base = AccountBase {
-        manager: manager_addr_human,
-        proxy: proxy_addr_human,
-    };
+    let account_base =
+        account_base_addresses(deps.as_ref(), &env, &salt, proxy_code_id, manager_code_id)?;
     // save context for after-init check
     let context = Context {
         account_id,
@@ -286,6 +249,65 @@ pub fn execute_create_account(
     )))
 }
 
+/// Query version_control for the latest Proxy and Manager modules
+pub(crate) fn query_account_base_modules(
+    version_control: &VersionControlContract,
+    querier: &QuerierWrapper,
+) -> AccountFactoryResult<(Module, Module)> {
+    let mut modules = version_control.query_modules_configs(
+        vec![
+            ModuleInfo::from_id_latest(PROXY)?,
+            ModuleInfo::from_id_latest(MANAGER)?,
+        ],
+        querier,
+    )?;
+    let manager_module: Module = modules.pop().unwrap().module;
+    let proxy_module: Module = modules.pop().unwrap().module;
+
+    Ok((proxy_module, manager_module))
+}
+
+/// Code ids of the Proxy and Manager modules
+pub(crate) fn account_base_code_ids(
+    proxy_module: &Module,
+    manager_module: &Module,
+) -> AccountFactoryResult<(u64, u64)> {
+    if let (
+        ModuleReference::AccountBase(proxy_code_id),
+        ModuleReference::AccountBase(manager_code_id),
+    ) = (&proxy_module.reference, &manager_module.reference)
+    {
+        Ok((*proxy_code_id, *manager_code_id))
+    } else {
+        Err(AccountFactoryError::WrongModuleKind(
+            proxy_module.info.to_string(),
+            "account_base".to_string(),
+        ))
+    }
+}
+
+/// Instantiate2 addresses of the Proxy and Manager instantiated by this contract with `salt`
+pub(crate) fn account_base_addresses(
+    deps: Deps,
+    env: &Env,
+    salt: &Binary,
+    proxy_code_id: u64,
+    manager_code_id: u64,
+) -> AccountFactoryResult<AccountBase> {
+    // Get checksums
+    let proxy_checksum = deps.querier.query_wasm_code_info(proxy_code_id)?.checksum;
+    let manager_checksum = deps.querier.query_wasm_code_info(manager_code_id)?.checksum;
+
+    let creator = deps.api.addr_canonicalize(env.contract.address.as_str())?;
+    let proxy_addr = instantiate2_address(&proxy_checksum, &creator, salt.as_slice())?;
+    let manager_addr = instantiate2_address(&manager_checksum, &creator, salt.as_slice())?;
+
+    Ok(AccountBase {
+        manager: deps.api.addr_humanize(&manager_addr)?,
+        proxy: deps.api.addr_humanize(&proxy_addr)?,
+    })
+}
+
 // Generate new local account id
 fn generate_new_local_account_id(
     storage: &mut dyn Storage,
@@ -364,3 +386,14 @@ pub fn execute_update_config(
 
     Ok(AccountFactoryResponse::action("update_config"))
 }
+
+pub fn execute_set_paused(deps: DepsMut, info: MessageInfo, paused: bool) -> AccountFactoryResult {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    PAUSED.save(deps.storage, &paused)?;
+
+    Ok(AccountFactoryResponse::new(
+        "set_paused",
+        vec![("paused", paused.to_string())],
+    ))
+}
@@ -4,7 +4,7 @@ use abstract_sdk::{
     std::{account_factory::*, ACCOUNT_FACTORY},
 };
 use cosmwasm_std::{
-    to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdResult,
+    to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdError, StdResult,
 };
 
 use crate::{commands, error::AccountFactoryError, queries, state::*};
@@ -56,6 +56,7 @@ pub fn execute(
             version_control_contract,
             module_factory_address,
         ),
+        ExecuteMsg::SetPaused { paused } => commands::execute_set_paused(deps, info, paused),
         ExecuteMsg::CreateAccount {
             governance,
             link,
@@ -97,9 +98,13 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> AccountFactoryResult {
 }
 
 #[cfg_attr(feature = "export", cosmwasm_std::entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_json_binary(&queries::query_config(deps)?),
+        QueryMsg::NextAccount {} => to_json_binary(
+            &queries::query_next_account(deps, env)
+                .map_err(|error| StdError::generic_err(error.to_string()))?,
+        ),
         QueryMsg::Ownership {} => query_ownership!(deps),
     }
 }
@@ -261,6 +266,57 @@ mod tests {
         }
     }
 
+    mod set_paused {
+        use abstract_std::objects::gov_type::GovernanceDetails;
+
+        use super::*;
+
+        fn create_account_msg() -> ExecuteMsg {
+            ExecuteMsg::CreateAccount {
+                governance: GovernanceDetails::Monarchy {
+                    monarch: OWNER.to_string(),
+                },
+                name: "account".to_string(),
+                base_asset: None,
+                description: None,
+                link: None,
+                account_id: None,
+                namespace: None,
+                install_modules: vec![],
+            }
+        }
+
+        #[test]
+        fn only_owner() -> AccountFactoryTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut())?;
+
+            test_only_owner(deps.as_mut(), ExecuteMsg::SetPaused { paused: true })?;
+
+            Ok(())
+        }
+
+        #[test]
+        fn creation_rejected_while_paused() -> AccountFactoryTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut())?;
+
+            execute_as_owner(deps.as_mut(), ExecuteMsg::SetPaused { paused: true })?;
+
+            let res = execute_as(deps.as_mut(), "creator", create_account_msg());
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(AccountFactoryError::CreationPaused {});
+
+            // Queries remain available
+            let res = query(deps.as_ref(), mock_env(), QueryMsg::Config {})?;
+            let config: ConfigResponse = from_json(res)?;
+            assert_that!(config.is_paused).is_true();
+
+            Ok(())
+        }
+    }
+
     mod update_ownership {
         use cw_ownable::Action;
 
@@ -54,4 +54,7 @@ pub enum AccountFactoryError {
 
     #[error("Predictable local account id sequence can't be lower than 2147483648")]
     PredictableAccountIdFailed {},
+
+    #[error("Account creation is paused")]
+    CreationPaused {},
 }
@@ -1,7 +1,12 @@
-use abstract_sdk::std::account_factory::*;
-use cosmwasm_std::{Deps, StdResult};
+use abstract_sdk::{feature_objects::VersionControlContract, std::account_factory::*};
+use abstract_std::objects::{salt::generate_instantiate_salt, AccountId};
+use cosmwasm_std::{Deps, Env, StdResult};
 
-use crate::state::*;
+use crate::{
+    commands::{account_base_addresses, account_base_code_ids, query_account_base_modules},
+    contract::AccountFactoryResult,
+    state::*,
+};
 
 pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
     let state: Config = CONFIG.load(deps.storage)?;
@@ -11,7 +16,30 @@ pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
         ans_host_contract: state.ans_host_contract,
         module_factory_address: state.module_factory_address,
         local_account_sequence: LOCAL_ACCOUNT_SEQUENCE.may_load(deps.storage)?.unwrap_or(0),
+        is_paused: PAUSED.may_load(deps.storage)?.unwrap_or_default(),
     };
 
     Ok(resp)
 }
+
+pub fn query_next_account(deps: Deps, env: Env) -> AccountFactoryResult<NextAccountResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let version_control = VersionControlContract::new(config.version_control_contract);
+
+    let account_id = AccountId::local(LOCAL_ACCOUNT_SEQUENCE.may_load(deps.storage)?.unwrap_or(0));
+    let (proxy_module, manager_module) =
+        query_account_base_modules(&version_control, &deps.querier)?;
+    let (proxy_code_id, manager_code_id) = account_base_code_ids(&proxy_module, &manager_module)?;
+    let account_base = account_base_addresses(
+        deps,
+        &env,
+        &generate_instantiate_salt(&account_id),
+        proxy_code_id,
+        manager_code_id,
+    )?;
+
+    Ok(NextAccountResponse {
+        account_id,
+        account_base,
+    })
+}
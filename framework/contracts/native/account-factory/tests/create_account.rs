@@ -33,6 +33,7 @@ fn instantiate() -> AResult {
         version_control_contract: deployment.version_control.address()?,
         module_factory_address: deployment.module_factory.address()?,
         local_account_sequence: 1,
+        is_paused: false,
     };
 
     assert_that!(&factory_config).is_equal_to(&expected);
@@ -70,6 +71,7 @@ fn create_one_account() -> AResult {
         version_control_contract: deployment.version_control.address()?,
         module_factory_address: deployment.module_factory.address()?,
         local_account_sequence: 2,
+        is_paused: false,
     };
 
     assert_that!(&factory_config).is_equal_to(&expected);
@@ -145,6 +147,7 @@ fn create_two_account_s() -> AResult {
         module_factory_address: deployment.module_factory.address()?,
         // we created two accounts
         local_account_sequence: account_2_id.seq() + 1,
+        is_paused: false,
     };
 
     assert_that!(&factory_config).is_equal_to(&expected);
@@ -173,6 +176,46 @@ fn create_two_account_s() -> AResult {
     Ok(())
 }
 
+#[test]
+fn next_account_is_predicted() -> AResult {
+    let chain = MockBech32::new("mock");
+    let sender = chain.sender();
+    let deployment = Abstract::deploy_on(chain, sender.to_string())?;
+
+    let factory = &deployment.account_factory;
+    let next_account = factory.next_account()?;
+    assert_that!(next_account.account_id).is_equal_to(TEST_ACCOUNT_ID);
+    assert_that!(next_account.account_id.seq())
+        .is_equal_to(factory.config()?.local_account_sequence);
+
+    let account_creation = factory.create_account(
+        GovernanceDetails::Monarchy {
+            monarch: sender.to_string(),
+        },
+        vec![],
+        String::from("first_account"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        &[],
+    )?;
+    let manager = account_creation.event_attr_value(ABSTRACT_EVENT_TYPE, "manager_address")?;
+    let proxy = account_creation.event_attr_value(ABSTRACT_EVENT_TYPE, "proxy_address")?;
+    assert_that!(next_account.account_base).is_equal_to(AccountBase {
+        manager: Addr::unchecked(manager),
+        proxy: Addr::unchecked(proxy),
+    });
+
+    let next_account = factory.next_account()?;
+    assert_that!(next_account.account_id).is_equal_to(AccountId::new(
+        TEST_ACCOUNT_ID.seq() + 1,
+        AccountTrace::Local,
+    )?);
+    Ok(())
+}
+
 #[test]
 fn sender_is_not_admin_monarchy() -> AResult {
     let chain = MockBech32::new("mock");
@@ -274,7 +317,7 @@ fn create_one_account_with_base_asset() -> AResult {
     let asset_name = "juno";
     let asset = AssetInfoBase::Native("ujuno".to_string());
     let checked_asset = AssetInfo::Native("ujuno".to_string());
-    ans_host.update_asset_addresses(vec![(asset_name.to_string(), asset)], vec![])?;
+    ans_host.update_asset_addresses(false, vec![(asset_name.to_string(), asset)], vec![])?;
 
     let account = factory.create_new_account(
         AccountDetails {
@@ -350,3 +393,40 @@ fn create_one_account_with_namespace_fee() -> AResult {
     Abstract::deploy_on(chain.clone(), sender.to_string())?;
     abstract_integration_tests::account_factory::create_one_account_with_namespace_fee(chain)
 }
+
+#[test]
+fn create_account_after_unpause() -> AResult {
+    let chain = MockBech32::new("mock");
+    let sender = chain.sender();
+    let deployment = Abstract::deploy_on(chain, sender.to_string())?;
+
+    let factory = &deployment.account_factory;
+    factory.set_paused(true)?;
+    assert_that!(factory.config()?.is_paused).is_true();
+
+    let create_account = || {
+        factory.create_account(
+            GovernanceDetails::Monarchy {
+                monarch: sender.to_string(),
+            },
+            vec![],
+            String::from("paused_account"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            &[],
+        )
+    };
+    let err: abstract_account_factory::error::AccountFactoryError =
+        create_account().unwrap_err().downcast()?;
+    assert_that!(err)
+        .is_equal_to(abstract_account_factory::error::AccountFactoryError::CreationPaused {});
+
+    factory.set_paused(false)?;
+    create_account()?;
+
+    assert_that!(factory.config()?.local_account_sequence).is_equal_to(2);
+    Ok(())
+}
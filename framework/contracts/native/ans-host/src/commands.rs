@@ -1,3 +1,5 @@
+use std::collections::BTreeSet;
+
 use abstract_sdk::execute_update_ownership;
 use abstract_std::{
     ans_host::{state::*, AssetPair, ExecuteMsg},
@@ -31,9 +33,11 @@ pub fn handle_message(
         ExecuteMsg::UpdateContractAddresses { to_add, to_remove } => {
             update_contract_addresses(deps, info, to_add, to_remove)
         }
-        ExecuteMsg::UpdateAssetAddresses { to_add, to_remove } => {
-            update_asset_addresses(deps, info, to_add, to_remove)
-        }
+        ExecuteMsg::UpdateAssetAddresses {
+            allow_overwrite,
+            to_add,
+            to_remove,
+        } => update_asset_addresses(deps, info, allow_overwrite, to_add, to_remove),
         ExecuteMsg::UpdateChannels { to_add, to_remove } => {
             update_channels(deps, info, to_add, to_remove)
         }
@@ -82,20 +86,28 @@ pub fn update_contract_addresses(
 }
 
 /// Adds, updates or removes provided addresses.
+/// Rejects an update that adds the same asset twice unless `allow_overwrite` is set.
 pub fn update_asset_addresses(
     deps: DepsMut,
     msg_info: MessageInfo,
+    allow_overwrite: bool,
     to_add: Vec<(String, AssetInfoUnchecked)>,
     to_remove: Vec<String>,
 ) -> AnsHostResult {
     // Only Admin can call this method
     cw_ownable::assert_owner(deps.storage, &msg_info.sender)?;
 
+    let mut added = BTreeSet::new();
     for (name, new_asset) in to_add.into_iter() {
         // validate asset
         let asset = new_asset.check(deps.as_ref().api, None)?;
 
         let entry = AssetEntry::from(name);
+        if !added.insert(entry.clone()) && !allow_overwrite {
+            return Err(AnsHostError::DuplicateAsset {
+                asset: entry.to_string(),
+            });
+        }
 
         ASSET_ADDRESSES.save(deps.storage, &entry, &asset)?;
         REV_ASSET_ADDRESSES.save(deps.storage, &asset, &entry)?;
@@ -382,6 +394,7 @@ mod test {
 
     fn register_assets_helper(deps: DepsMut, assets: Vec<AssetEntry>) -> AnsHostTestResult {
         let msg = ExecuteMsg::UpdateAssetAddresses {
+            allow_overwrite: false,
             to_add: assets
                 .iter()
                 .map(|a| (a.to_string(), AssetInfoUnchecked::native(a.to_string())))
@@ -735,7 +748,11 @@ mod test {
             to_add: Vec<(String, AssetInfoUnchecked)>,
             to_remove: Vec<String>,
         ) -> ExecuteMsg {
-            ExecuteMsg::UpdateAssetAddresses { to_add, to_remove }
+            ExecuteMsg::UpdateAssetAddresses {
+                allow_overwrite: false,
+                to_add,
+                to_remove,
+            }
         }
 
         fn from_checked_entry(
@@ -811,7 +828,30 @@ mod test {
             mock_init(deps.as_mut()).unwrap();
 
             let mut map_tester = setup_map_tester();
-            map_tester.test_add_two_same(&mut deps)
+            let err = map_tester.test_add_two_same(&mut deps).unwrap_err();
+            assert_that!(err).is_equal_to(AnsHostError::DuplicateAsset {
+                asset: "test".into(),
+            });
+            Ok(())
+        }
+
+        #[test]
+        fn add_asset_address_twice_in_same_msg_with_overwrite() -> AnsHostTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut()).unwrap();
+
+            let first = unchecked_asset_map_entry("juno", AssetInfoBase::Native("ujuno".into()));
+            let second = unchecked_asset_map_entry("juno", AssetInfoBase::Native("ujunox".into()));
+            let msg = ExecuteMsg::UpdateAssetAddresses {
+                allow_overwrite: true,
+                to_add: vec![first, second],
+                to_remove: vec![],
+            };
+            execute_helper(deps.as_mut(), msg)?;
+
+            let asset = ASSET_ADDRESSES.load(&deps.storage, &AssetEntry::from("juno"))?;
+            assert_that!(asset).is_equal_to(AssetInfo::native("ujunox"));
+            Ok(())
         }
 
         #[test]
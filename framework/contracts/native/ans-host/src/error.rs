@@ -40,6 +40,9 @@ pub enum AnsHostError {
     #[error("Asset {} is not registered", asset)]
     UnregisteredAsset { asset: String },
 
+    #[error("Asset {} is added more than once in the same update", asset)]
+    DuplicateAsset { asset: String },
+
     #[error("Dex {} is already registered", dex)]
     DexAlreadyRegistered { dex: String },
 }
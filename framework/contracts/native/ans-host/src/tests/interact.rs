@@ -21,6 +21,7 @@ fn unauthorized_ans_host_update() {
     let env = mock_env();
     let asset_info = AssetInfo::Native("asset_1".to_string());
     let msg = ExecuteMsg::UpdateAssetAddresses {
+        allow_overwrite: false,
         to_add: vec![("asset".to_string(), asset_info.into())],
         to_remove: vec![],
     };
@@ -64,6 +65,7 @@ fn authorized_ans_host_update() {
     let env = mock_env();
     let asset_info = AssetInfo::Native("asset_1".to_string());
     let msg = ExecuteMsg::UpdateAssetAddresses {
+        allow_overwrite: false,
         to_add: vec![("asset".to_string(), asset_info.into())],
         to_remove: vec![],
     };
@@ -8,7 +8,10 @@ use abstract_std::{
     app::AppState,
     ibc::{Callback, ModuleQuery},
     ibc_client::{
-        state::{IbcInfrastructure, ACCOUNTS, CONFIG, IBC_INFRA, REVERSE_POLYTONE_NOTE},
+        state::{
+            IbcInfrastructure, ACCOUNTS, CONFIG, IBC_INFRA, MODULE_IBC_NONCES,
+            REVERSE_POLYTONE_NOTE,
+        },
         IbcClientCallback, InstalledModuleIdentification,
     },
     ibc_host::{self, HostAction, InternalAction},
@@ -21,7 +24,7 @@ use abstract_std::{
     IBC_CLIENT, ICS20,
 };
 use cosmwasm_std::{
-    ensure, to_json_binary, wasm_execute, Binary, Coin, CosmosMsg, Deps, DepsMut, Empty, Env,
+    ensure, to_json_binary, wasm_execute, Addr, Binary, Coin, CosmosMsg, Deps, DepsMut, Empty, Env,
     IbcMsg, MessageInfo, QueryRequest, Storage, WasmQuery,
 };
 use cw_storage_plus::Item;
@@ -95,9 +98,57 @@ pub fn execute_register_infrastructure(
     REVERSE_POLYTONE_NOTE.save(deps.storage, &note, &host_chain)?;
 
     // When registering a new chain host, we need to get the remote proxy address of the local note.
-    // We do so by calling an empty message on the polytone note. This will come back in form of a execute by callback
+    let note_proxy_msg = who_am_i_msg(&env, note)?;
+
+    Ok(IbcClientResponse::action("allow_chain_port").add_message(note_proxy_msg))
+}
+
+/// Updates the note and/or remote host of a registered chain.
+/// Changing the note resets the remote proxy, which gets queried again.
+pub fn execute_update_infrastructure(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    host_chain: TruncatedChainId,
+    host: Option<String>,
+    note: Option<String>,
+) -> IbcClientResult {
+    host_chain.verify()?;
+
+    // auth check
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    let mut ibc_infra = IBC_INFRA
+        .may_load(deps.storage, &host_chain)?
+        .ok_or_else(|| IbcClientError::UnregisteredChain(host_chain.to_string()))?;
+    let mut response = IbcClientResponse::action("update_infrastructure");
 
-    let note_proxy_msg = wasm_execute(
+    if let Some(host) = host {
+        ibc_infra.remote_abstract_host = host;
+    }
+    if let Some(note) = note {
+        let note = deps.api.addr_validate(&note)?;
+        if note != ibc_infra.polytone_note {
+            if REVERSE_POLYTONE_NOTE.has(deps.storage, &note) {
+                return Err(IbcClientError::HostAddressExists {});
+            }
+            REVERSE_POLYTONE_NOTE.remove(deps.storage, &ibc_infra.polytone_note);
+            REVERSE_POLYTONE_NOTE.save(deps.storage, &note, &host_chain)?;
+
+            ibc_infra.polytone_note = note.clone();
+            ibc_infra.remote_proxy = None;
+            response = response.add_message(who_am_i_msg(&env, note)?);
+        }
+    }
+    IBC_INFRA.save(deps.storage, &host_chain, &ibc_infra)?;
+
+    Ok(response)
+}
+
+/// Calls an empty message on the polytone note to get the remote proxy address of the local note.
+/// This will come back in form of a execute by callback
+fn who_am_i_msg(env: &Env, note: Addr) -> IbcClientResult<CosmosMsg> {
+    let msg = wasm_execute(
         note,
         &polytone_note::msg::ExecuteMsg::Execute {
             msgs: vec![],
@@ -109,8 +160,7 @@ pub fn execute_register_infrastructure(
         },
         vec![],
     )?;
-
-    Ok(IbcClientResponse::action("allow_chain_port").add_message(note_proxy_msg))
+    Ok(msg.into())
 }
 
 // allows admins to clear host if needed
@@ -140,6 +190,7 @@ fn send_remote_host_action(
     host_chain: TruncatedChainId,
     action: HostAction,
     callback_request: Option<CallbackRequest>,
+    timeout_seconds: u64,
 ) -> IbcClientResult<CosmosMsg<Empty>> {
     // Send this message via the Polytone implementation
     let ibc_infra = IBC_INFRA.load(deps.storage, &host_chain)?;
@@ -163,7 +214,7 @@ fn send_remote_host_action(
             )?
             .into()],
             callback: callback_request,
-            timeout_seconds: PACKET_LIFETIME.into(),
+            timeout_seconds: timeout_seconds.into(),
         },
         vec![],
     )?;
@@ -171,16 +222,33 @@ fn send_remote_host_action(
     Ok(note_message.into())
 }
 
+/// Resolve the timeout of a packet in seconds, [`PACKET_LIFETIME`] if not set.
+/// Errors if it's zero or if adding it to the block time overflows.
+fn packet_timeout(env: &Env, timeout_seconds: Option<u64>) -> IbcClientResult<u64> {
+    let timeout_seconds = timeout_seconds.unwrap_or(PACKET_LIFETIME);
+    let fits_block_time = timeout_seconds
+        .checked_mul(1_000_000_000)
+        .and_then(|nanos| env.block.time.nanos().checked_add(nanos))
+        .is_some();
+    ensure!(
+        timeout_seconds > 0 && fits_block_time,
+        IbcClientError::InvalidTimeout { timeout_seconds }
+    );
+    Ok(timeout_seconds)
+}
+
 /// Sends a packet with an optional callback.
 /// This is the top-level function to do IBC related actions.
 pub fn execute_send_packet(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     host_chain: TruncatedChainId,
     action: HostAction,
+    timeout_seconds: Option<u64>,
 ) -> IbcClientResult {
     host_chain.verify()?;
+    let timeout_seconds = packet_timeout(&env, timeout_seconds)?;
 
     let cfg = CONFIG.load(deps.storage)?;
 
@@ -203,6 +271,7 @@ pub fn execute_send_packet(
                 host_chain,
                 action,
                 None,
+                timeout_seconds,
             )?
         }
         HostAction::Internal(_) => {
@@ -293,6 +362,13 @@ pub fn execute_send_module_to_module_packet(
     let note_contract = ibc_infra.polytone_note;
     let remote_ibc_host = ibc_infra.remote_abstract_host;
 
+    // Assign the next nonce for this (module, host chain) pair, used by the target for replay protection
+    let nonce = MODULE_IBC_NONCES
+        .may_load(deps.storage, (&info.sender, &host_chain))?
+        .unwrap_or_default()
+        + 1;
+    MODULE_IBC_NONCES.save(deps.storage, (&info.sender, &host_chain), &nonce)?;
+
     // message that will be called on the local note contract
     let note_message = wasm_execute(
         note_contract.to_string(),
@@ -304,6 +380,7 @@ pub fn execute_send_module_to_module_packet(
                     msg,
                     source_module,
                     target_module,
+                    nonce: Some(nonce),
                 },
                 vec![],
             )?
@@ -405,6 +482,7 @@ pub fn execute_register_account(
             receiver: env.contract.address.to_string(),
             msg: to_json_binary(&IbcClientCallback::CreateAccount { account_id })?,
         }),
+        PACKET_LIFETIME,
     )?;
 
     Ok(IbcClientResponse::action("handle_register").add_message(note_message))
@@ -416,8 +494,10 @@ pub fn execute_send_funds(
     info: MessageInfo,
     host_chain: TruncatedChainId,
     funds: Vec<Coin>,
+    timeout_seconds: Option<u64>,
 ) -> IbcClientResult {
     host_chain.verify()?;
+    let timeout_seconds = packet_timeout(&env, timeout_seconds)?;
 
     let cfg = CONFIG.load(deps.storage)?;
     let ans = cfg.ans_host;
@@ -441,6 +521,7 @@ pub fn execute_send_funds(
     };
     let ics20_channel_id = ics20_channel_entry.resolve(&deps.querier, &ans)?;
 
+    let timeout = env.block.time.plus_seconds(timeout_seconds);
     let mut transfers: Vec<CosmosMsg> = vec![];
     for amount in funds {
         // construct a packet to send
@@ -450,7 +531,7 @@ pub fn execute_send_funds(
                 channel_id: ics20_channel_id.clone(),
                 to_address: remote_addr.clone(),
                 amount,
-                timeout: env.block.time.plus_seconds(PACKET_LIFETIME).into(),
+                timeout: timeout.into(),
             }
             .into(),
         );
@@ -61,15 +61,23 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> I
             version_control,
         } => commands::execute_update_config(deps, info, ans_host, version_control)
             .map_err(Into::into),
-        ExecuteMsg::RemoteAction { host_chain, action } => {
-            commands::execute_send_packet(deps, env, info, host_chain, action)
-        }
+        ExecuteMsg::RemoteAction {
+            host_chain,
+            action,
+            timeout_seconds,
+        } => commands::execute_send_packet(deps, env, info, host_chain, action, timeout_seconds),
         ExecuteMsg::RegisterInfrastructure { chain, note, host } => {
             commands::execute_register_infrastructure(deps, env, info, chain, host, note)
         }
-        ExecuteMsg::SendFunds { host_chain, funds } => {
-            commands::execute_send_funds(deps, env, info, host_chain, funds).map_err(Into::into)
+        ExecuteMsg::UpdateInfrastructure { chain, note, host } => {
+            commands::execute_update_infrastructure(deps, env, info, chain, host, note)
         }
+        ExecuteMsg::SendFunds {
+            host_chain,
+            funds,
+            timeout_seconds,
+        } => commands::execute_send_funds(deps, env, info, host_chain, funds, timeout_seconds)
+            .map_err(Into::into),
         ExecuteMsg::Register {
             host_chain,
             base_asset,
@@ -462,6 +470,183 @@ mod tests {
         }
     }
 
+    mod update_infrastructure {
+        use std::str::FromStr;
+
+        use abstract_std::objects::TruncatedChainId;
+        use cosmwasm_std::wasm_execute;
+        use polytone::callbacks::CallbackRequest;
+
+        use super::*;
+        use crate::commands::PACKET_LIFETIME;
+
+        fn register_test_chain(deps: DepsMut) -> IbcClientTestResult {
+            let chain_name = TruncatedChainId::from_str(TEST_CHAIN)?;
+            let infra = IbcInfrastructure {
+                polytone_note: Addr::unchecked("note"),
+                remote_abstract_host: "test_remote_host".into(),
+                remote_proxy: Some("remote_proxy".into()),
+            };
+            IBC_INFRA.save(deps.storage, &chain_name, &infra)?;
+            REVERSE_POLYTONE_NOTE.save(deps.storage, &infra.polytone_note, &chain_name)?;
+            Ok(())
+        }
+
+        #[test]
+        fn only_admin() -> IbcClientResult<()> {
+            test_only_admin(ExecuteMsg::UpdateInfrastructure {
+                chain: "host-chain".parse().unwrap(),
+                note: None,
+                host: Some(String::from("host")),
+            })
+        }
+
+        #[test]
+        fn cannot_update_unregistered_chain() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut())?;
+
+            let msg = ExecuteMsg::UpdateInfrastructure {
+                chain: TEST_CHAIN.parse().unwrap(),
+                note: None,
+                host: Some(String::from("new_remote_host")),
+            };
+
+            let res = execute_as_admin(deps.as_mut(), msg);
+            assert_that!(&res)
+                .is_err()
+                .matches(|e| matches!(e, IbcClientError::UnregisteredChain(_)));
+
+            Ok(())
+        }
+
+        #[test]
+        fn cannot_update_to_registered_note() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut())?;
+            register_test_chain(deps.as_mut())?;
+            REVERSE_POLYTONE_NOTE.save(
+                deps.as_mut().storage,
+                &Addr::unchecked("other_note"),
+                &TruncatedChainId::from_str("other-chain")?,
+            )?;
+
+            let msg = ExecuteMsg::UpdateInfrastructure {
+                chain: TEST_CHAIN.parse().unwrap(),
+                note: Some(String::from("other_note")),
+                host: None,
+            };
+
+            let res = execute_as_admin(deps.as_mut(), msg);
+            assert_that!(&res)
+                .is_err()
+                .matches(|e| matches!(e, IbcClientError::HostAddressExists {}));
+
+            Ok(())
+        }
+
+        #[test]
+        fn update_host() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut())?;
+            register_test_chain(deps.as_mut())?;
+
+            let chain_name = TruncatedChainId::from_str(TEST_CHAIN)?;
+            let msg = ExecuteMsg::UpdateInfrastructure {
+                chain: chain_name.clone(),
+                note: None,
+                host: Some(String::from("new_remote_host")),
+            };
+
+            let res = execute_as_admin(deps.as_mut(), msg)?;
+            assert_eq!(IbcClientResponse::action("update_infrastructure"), res);
+
+            let host_response: HostResponse = from_json(query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::Host {
+                    chain_name: chain_name.clone(),
+                },
+            )?)?;
+            assert_eq!(
+                HostResponse {
+                    remote_host: "new_remote_host".into(),
+                    remote_polytone_proxy: Some("remote_proxy".into())
+                },
+                host_response
+            );
+
+            let remote_hosts_response: ListRemoteHostsResponse = from_json(query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::ListRemoteHosts {},
+            )?)?;
+            assert_eq!(
+                vec![(chain_name, "new_remote_host".to_owned())],
+                remote_hosts_response.hosts
+            );
+
+            Ok(())
+        }
+
+        #[test]
+        fn update_note() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut())?;
+            register_test_chain(deps.as_mut())?;
+
+            let chain_name = TruncatedChainId::from_str(TEST_CHAIN)?;
+            let new_note = String::from("new_note");
+            let msg = ExecuteMsg::UpdateInfrastructure {
+                chain: chain_name.clone(),
+                note: Some(new_note.clone()),
+                host: None,
+            };
+
+            let note_proxy_msg = wasm_execute(
+                new_note.clone(),
+                &polytone_note::msg::ExecuteMsg::Execute {
+                    msgs: vec![],
+                    callback: Some(CallbackRequest {
+                        receiver: mock_env().contract.address.to_string(),
+                        msg: to_json_binary(&IbcClientCallback::WhoAmI {})?,
+                    }),
+                    timeout_seconds: PACKET_LIFETIME.into(),
+                },
+                vec![],
+            )?;
+
+            let res = execute_as_admin(deps.as_mut(), msg)?;
+            assert_eq!(
+                IbcClientResponse::action("update_infrastructure").add_message(note_proxy_msg),
+                res
+            );
+
+            let expected_ibc_infra = IbcInfrastructure {
+                polytone_note: Addr::unchecked(new_note.clone()),
+                remote_abstract_host: "test_remote_host".into(),
+                remote_proxy: None,
+            };
+            let ibc_infratructures_response: ListIbcInfrastructureResponse = from_json(query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::ListIbcInfrastructures {},
+            )?)?;
+            assert_eq!(
+                vec![(chain_name.clone(), expected_ibc_infra)],
+                ibc_infratructures_response.counterparts
+            );
+
+            // Old note is released, new note maps to the chain
+            assert!(!REVERSE_POLYTONE_NOTE.has(deps.as_ref().storage, &Addr::unchecked("note")));
+            let reverse_note =
+                REVERSE_POLYTONE_NOTE.load(deps.as_ref().storage, &Addr::unchecked(new_note))?;
+            assert_eq!(chain_name, reverse_note);
+
+            Ok(())
+        }
+    }
+
     mod remote_action {
         use super::*;
         use std::str::FromStr;
@@ -493,6 +678,7 @@ mod tests {
                         link: None,
                     }],
                 },
+                timeout_seconds: None,
             };
 
             let res = execute_as(deps.as_mut(), TEST_MANAGER, msg);
@@ -524,6 +710,7 @@ mod tests {
                     namespace: None,
                     install_modules: vec![],
                 }),
+                timeout_seconds: None,
             };
 
             let res = execute_as(deps.as_mut(), TEST_PROXY, msg);
@@ -565,6 +752,7 @@ mod tests {
             let msg = ExecuteMsg::RemoteAction {
                 host_chain: chain_name,
                 action: action.clone(),
+                timeout_seconds: None,
             };
 
             let res = execute_as(deps.as_mut(), TEST_PROXY, msg)?;
@@ -595,6 +783,28 @@ mod tests {
             );
             Ok(())
         }
+
+        #[test]
+        fn throw_on_invalid_timeout() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = mocked_account_querier_builder().build();
+            mock_init(deps.as_mut())?;
+
+            for timeout_seconds in [0, u64::MAX] {
+                let msg = ExecuteMsg::RemoteAction {
+                    host_chain: TruncatedChainId::from_str(TEST_CHAIN)?,
+                    action: HostAction::Dispatch {
+                        manager_msgs: vec![],
+                    },
+                    timeout_seconds: Some(timeout_seconds),
+                };
+
+                let res = execute_as(deps.as_mut(), TEST_PROXY, msg);
+
+                assert_eq!(res, Err(IbcClientError::InvalidTimeout { timeout_seconds }));
+            }
+            Ok(())
+        }
     }
 
     mod send_funds {
@@ -619,6 +829,7 @@ mod tests {
             let msg = ExecuteMsg::SendFunds {
                 host_chain: chain_name,
                 funds: coins(1, "denom"),
+                timeout_seconds: None,
             };
 
             let res = execute_as(deps.as_mut(), TEST_MANAGER, msg);
@@ -658,6 +869,7 @@ mod tests {
             let msg = ExecuteMsg::SendFunds {
                 host_chain: chain_name,
                 funds: funds.clone(),
+                timeout_seconds: None,
             };
 
             let res = execute_as(deps.as_mut(), TEST_PROXY, msg)?;
@@ -682,6 +894,71 @@ mod tests {
 
             Ok(())
         }
+
+        #[test]
+        fn custom_timeout() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            let chain_name = TruncatedChainId::from_str(TEST_CHAIN)?;
+            let channel_entry = ChannelEntry {
+                connected_chain: chain_name.clone(),
+                protocol: String::from(ICS20),
+            };
+            let channel_id = String::from("1");
+            let channels: Vec<(&ChannelEntry, String)> = vec![(&channel_entry, channel_id.clone())];
+            deps.querier = mocked_account_querier_builder().channels(channels).build();
+            mock_init(deps.as_mut())?;
+
+            let remote_addr = String::from("remote_addr");
+
+            ACCOUNTS.save(
+                deps.as_mut().storage,
+                (TEST_ACCOUNT_ID.trace(), TEST_ACCOUNT_ID.seq(), &chain_name),
+                &remote_addr,
+            )?;
+
+            let msg = ExecuteMsg::SendFunds {
+                host_chain: chain_name,
+                funds: coins(1, "denom"),
+                timeout_seconds: Some(600),
+            };
+
+            let res = execute_as(deps.as_mut(), TEST_PROXY, msg)?;
+
+            let transfer_msg: CosmosMsg = IbcMsg::Transfer {
+                channel_id,
+                to_address: remote_addr,
+                amount: Coin::new(1, "denom"),
+                timeout: mock_env().block.time.plus_seconds(600).into(),
+            }
+            .into();
+
+            assert_eq!(
+                IbcClientResponse::action("handle_send_funds").add_message(transfer_msg),
+                res
+            );
+
+            Ok(())
+        }
+
+        #[test]
+        fn throw_on_invalid_timeout() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = mocked_account_querier_builder().build();
+            mock_init(deps.as_mut())?;
+
+            for timeout_seconds in [0, u64::MAX] {
+                let msg = ExecuteMsg::SendFunds {
+                    host_chain: TruncatedChainId::from_str(TEST_CHAIN)?,
+                    funds: coins(1, "denom"),
+                    timeout_seconds: Some(timeout_seconds),
+                };
+
+                let res = execute_as(deps.as_mut(), TEST_PROXY, msg);
+
+                assert_eq!(res, Err(IbcClientError::InvalidTimeout { timeout_seconds }));
+            }
+            Ok(())
+        }
     }
 
     mod register_account {
@@ -968,8 +1245,12 @@ mod tests {
     mod callback {
         use std::str::FromStr;
 
-        use abstract_std::objects::{account::TEST_ACCOUNT_ID, TruncatedChainId};
-        use cosmwasm_std::{from_json, Binary, Event, SubMsgResponse};
+        use abstract_std::{
+            ibc::{IbcResponseMsg, IbcResult},
+            ibc_host::DispatchAck,
+            objects::{account::TEST_ACCOUNT_ID, TruncatedChainId},
+        };
+        use cosmwasm_std::{from_json, Binary, DepsMut, Event, Response, SubMsgResponse};
         use polytone::callbacks::{Callback, CallbackMessage, ExecutionResponse};
 
         use super::*;
@@ -1343,6 +1624,112 @@ mod tests {
 
             Ok(())
         }
+
+        /// Protobuf encoding of a `MsgExecuteContractResponse`, as polytone returns it
+        fn execute_response_data(data: Binary) -> Binary {
+            let mut encoded = vec![0x0a, data.len() as u8];
+            encoded.extend_from_slice(&data);
+            Binary(encoded)
+        }
+
+        fn module_remote_action_callback(
+            deps: DepsMut,
+            data: Binary,
+        ) -> Result<(Response, IbcResponseMsg), IbcClientError> {
+            let env = mock_env();
+            let note = Addr::unchecked("note");
+            let chain_name = TruncatedChainId::from_str(TEST_CHAIN)?;
+            REVERSE_POLYTONE_NOTE.save(deps.storage, &note, &chain_name)?;
+
+            let callback = abstract_std::ibc::Callback {
+                msg: to_json_binary("callback")?,
+            };
+            let initiator_msg = to_json_binary("initiator")?;
+            let response = ExecutionResponse {
+                executed_by: String::from("remote_proxy"),
+                result: vec![SubMsgResponse {
+                    events: vec![],
+                    data: Some(execute_response_data(data)),
+                }],
+            };
+            let msg = ExecuteMsg::Callback(CallbackMessage {
+                initiator: env.contract.address,
+                initiator_msg: to_json_binary(&IbcClientCallback::ModuleRemoteAction {
+                    sender_address: String::from("sender_module"),
+                    callback: callback.clone(),
+                    initiator_msg: initiator_msg.clone(),
+                })?,
+                result: Callback::Execute(Ok(response.clone())),
+            });
+
+            let res = execute_as(deps, note.as_ref(), msg)?;
+            let response_msg = IbcResponseMsg {
+                callback,
+                result: IbcResult::Execute {
+                    initiator_msg,
+                    result: Ok(response),
+                },
+            };
+            Ok((res, response_msg))
+        }
+
+        #[test]
+        fn failed_dispatch_is_an_execution_error() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut())?;
+
+            let ack = to_json_binary(&DispatchAck::Error(String::from("manager message failed")))?;
+            let (res, mut expected) = module_remote_action_callback(deps.as_mut(), ack)?;
+
+            let IbcResult::Execute { result, .. } = &mut expected.result else {
+                unreachable!()
+            };
+            *result = Err(String::from("manager message failed"));
+            assert_eq!(
+                IbcClientResponse::action("module_action_ibc_callback")
+                    .add_message(expected.into_cosmos_msg("sender_module")?)
+                    .add_attribute("chain", TEST_CHAIN),
+                res
+            );
+
+            Ok(())
+        }
+
+        #[test]
+        fn successful_dispatch_is_not_an_error() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut())?;
+
+            let ack = to_json_binary(&DispatchAck::Ok(to_json_binary("manager data")?))?;
+            let (res, expected) = module_remote_action_callback(deps.as_mut(), ack)?;
+
+            assert_eq!(
+                IbcClientResponse::action("module_action_ibc_callback")
+                    .add_message(expected.into_cosmos_msg("sender_module")?)
+                    .add_attribute("chain", TEST_CHAIN),
+                res
+            );
+
+            Ok(())
+        }
+
+        #[test]
+        fn successful_execution_data_is_forwarded() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut())?;
+
+            let (res, expected) =
+                module_remote_action_callback(deps.as_mut(), to_json_binary("module data")?)?;
+
+            assert_eq!(
+                IbcClientResponse::action("module_action_ibc_callback")
+                    .add_message(expected.into_cosmos_msg("sender_module")?)
+                    .add_attribute("chain", TEST_CHAIN),
+                res
+            );
+
+            Ok(())
+        }
     }
     mod list_proxies_by_account_id {
         use super::*;
@@ -56,4 +56,7 @@ pub enum IbcClientError {
 
     #[error("IBC Client is not installed on {account_id}")]
     IbcClientNotInstalled { account_id: AccountId },
+
+    #[error("Invalid packet timeout of {timeout_seconds} seconds, it must be positive and can't overflow the block time")]
+    InvalidTimeout { timeout_seconds: u64 },
 }
@@ -4,7 +4,10 @@ use abstract_sdk::{
 };
 use abstract_std::{
     account_factory,
-    ibc_host::state::CONFIG,
+    ibc_host::{
+        state::{CONFIG, DISPATCH_GAS_BUDGET},
+        ExecuteMsg,
+    },
     manager::{self, ModuleInstallConfig},
     objects::{AccountId, AssetEntry, TruncatedChainId},
     proxy,
@@ -12,12 +15,13 @@ use abstract_std::{
     PROXY,
 };
 use cosmwasm_std::{
-    to_json_binary, wasm_execute, CosmosMsg, Deps, DepsMut, Env, IbcMsg, Response, SubMsg,
+    ensure_eq, to_json_binary, wasm_execute, CosmosMsg, Deps, DepsMut, Env, IbcMsg, MessageInfo,
+    Response, SubMsg,
 };
 
 use crate::{
     contract::{HostResponse, HostResult},
-    endpoints::reply::{INIT_BEFORE_ACTION_REPLY_ID, RESPONSE_REPLY_ID},
+    endpoints::reply::{DISPATCH_REPLY_ID, INIT_BEFORE_ACTION_REPLY_ID, RESPONSE_REPLY_ID},
     HostError,
 };
 
@@ -76,25 +80,65 @@ pub fn receive_register(
         .add_attribute("action", "register"))
 }
 
-/// Execute manager message on local manager.
+/// Dispatch manager messages on the local account.
+/// The messages are executed by the host in a single sub-message, so a failure
+/// is acknowledged with a [`DispatchAck::Error`](abstract_std::ibc_host::DispatchAck) instead of failing the packet.
+/// The whole dispatch is limited to the dispatch gas budget, if set.
 pub fn receive_dispatch(
-    _deps: DepsMut,
+    deps: Deps,
+    env: Env,
     account: AccountBase,
     manager_msgs: Vec<manager::ExecuteMsg>,
 ) -> HostResult {
+    let dispatch_msg = wasm_execute(
+        env.contract.address,
+        &ExecuteMsg::ExecuteDispatch {
+            manager: account.manager.into_string(),
+            manager_msgs,
+        },
+        vec![],
+    )?;
+
+    let sub_msg = SubMsg::reply_always(dispatch_msg, DISPATCH_REPLY_ID);
+    let sub_msg = match DISPATCH_GAS_BUDGET.may_load(deps.storage)? {
+        Some(gas_budget) => sub_msg.with_gas_limit(gas_budget),
+        None => sub_msg,
+    };
+
+    Ok(Response::new()
+        .add_attribute("action", "receive_dispatch")
+        .add_submessage(sub_msg))
+}
+
+/// Execute manager message on local manager.
+pub fn execute_dispatch(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    manager: String,
+    manager_msgs: Vec<manager::ExecuteMsg>,
+) -> HostResult {
+    // Only the host can execute a dispatch, see `receive_dispatch`
+    ensure_eq!(
+        info.sender,
+        env.contract.address,
+        HostError::UnauthorizedDispatch {}
+    );
+    let manager = deps.api.addr_validate(&manager)?;
+
     // execute the message on the manager
     let msgs = manager_msgs
         .into_iter()
-        .map(|msg| wasm_execute(&account.manager, &msg, vec![]))
+        .map(|msg| wasm_execute(&manager, &msg, vec![]))
         .collect::<Result<Vec<_>, _>>()?;
 
     let response = Response::new()
-        .add_attribute("action", "receive_dispatch")
+        .add_attribute("action", "execute_dispatch")
         // This is used to forward the data of the calling message
         // This means that only the last present data of will be forwarded
         .add_submessages(
             msgs.into_iter()
-                .map(|m| SubMsg::reply_on_success(m.clone(), RESPONSE_REPLY_ID)),
+                .map(|m| SubMsg::reply_on_success(m, RESPONSE_REPLY_ID)),
         );
 
     Ok(response)
@@ -167,3 +211,109 @@ pub fn get_account(deps: Deps, account_id: &AccountId) -> Result<AccountBase, Ho
     let account_base = version_control.account_base(account_id, &deps.querier)?;
     Ok(account_base)
 }
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Addr,
+    };
+
+    use super::*;
+
+    fn manager_msgs() -> Vec<manager::ExecuteMsg> {
+        vec![
+            manager::ExecuteMsg::UpdateStatus {
+                is_suspended: Some(true),
+            },
+            manager::ExecuteMsg::UpdateStatus {
+                is_suspended: Some(false),
+            },
+        ]
+    }
+
+    fn account() -> AccountBase {
+        AccountBase {
+            manager: Addr::unchecked("manager"),
+            proxy: Addr::unchecked("proxy"),
+        }
+    }
+
+    /// Gas limits of the dispatch sub-message and of the manager messages it executes
+    fn dispatch_gas_limits(gas_budget: Option<u64>) -> (Vec<Option<u64>>, Vec<Option<u64>>) {
+        let mut deps = mock_dependencies();
+        if let Some(gas_budget) = gas_budget {
+            DISPATCH_GAS_BUDGET
+                .save(deps.as_mut().storage, &gas_budget)
+                .unwrap();
+        }
+        let env = mock_env();
+        let info = mock_info(env.contract.address.as_str(), &[]);
+
+        let dispatch =
+            receive_dispatch(deps.as_ref(), env.clone(), account(), manager_msgs()).unwrap();
+        let executed = execute_dispatch(
+            deps.as_mut(),
+            env,
+            info,
+            "manager".to_owned(),
+            manager_msgs(),
+        )
+        .unwrap();
+        let gas_limits =
+            |response: Response| response.messages.into_iter().map(|m| m.gas_limit).collect();
+        (gas_limits(dispatch), gas_limits(executed))
+    }
+
+    #[test]
+    fn dispatch_is_wrapped_in_a_single_sub_message() {
+        let deps = mock_dependencies();
+        let env = mock_env();
+
+        let response =
+            receive_dispatch(deps.as_ref(), env.clone(), account(), manager_msgs()).unwrap();
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::reply_always(
+                wasm_execute(
+                    env.contract.address,
+                    &ExecuteMsg::ExecuteDispatch {
+                        manager: "manager".to_owned(),
+                        manager_msgs: manager_msgs(),
+                    },
+                    vec![],
+                )
+                .unwrap(),
+                DISPATCH_REPLY_ID,
+            )]
+        );
+    }
+
+    #[test]
+    fn only_host_can_execute_dispatch() {
+        let mut deps = mock_dependencies();
+        let err = execute_dispatch(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("user", &[]),
+            "manager".to_owned(),
+            manager_msgs(),
+        )
+        .unwrap_err();
+        assert_eq!(err, HostError::UnauthorizedDispatch {});
+    }
+
+    #[test]
+    fn dispatch_without_gas_budget() {
+        assert_eq!(dispatch_gas_limits(None), (vec![None], vec![None, None]));
+    }
+
+    #[test]
+    fn dispatch_with_gas_budget() {
+        // The budget is shared by all manager messages of the dispatch
+        assert_eq!(
+            dispatch_gas_limits(Some(500_000)),
+            (vec![Some(500_000)], vec![None, None])
+        );
+    }
+}
@@ -1,12 +1,15 @@
 use abstract_sdk::{feature_objects::VersionControlContract, std::ibc_host::ExecuteMsg};
 use abstract_std::{
-    ibc_host::state::{CHAIN_PROXIES, CONFIG, REVERSE_CHAIN_PROXIES},
-    objects::TruncatedChainId,
+    ibc_host::state::{
+        CHAIN_PROXIES, CONFIG, DISPATCH_GAS_BUDGET, MODULE_REFERENCES, REVERSE_CHAIN_PROXIES,
+    },
+    objects::{module::ModuleInfo, TruncatedChainId},
 };
 use cosmwasm_std::{DepsMut, Env, MessageInfo};
 
 use super::packet::{handle_host_action, handle_module_execute};
 use crate::{
+    account_commands::execute_dispatch,
     contract::{HostResponse, HostResult},
     HostError,
 };
@@ -28,6 +31,12 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> H
             register_chain_proxy(deps, info, chain, proxy)
         }
         ExecuteMsg::RemoveChainProxy { chain } => remove_chain_proxy(deps, info, chain),
+        ExecuteMsg::UpdateDispatchGasBudget { gas_budget } => {
+            update_dispatch_gas_budget(deps, info, gas_budget)
+        }
+        ExecuteMsg::InvalidateModuleReferences { modules } => {
+            invalidate_module_references(deps, info, modules)
+        }
         ExecuteMsg::Execute {
             proxy_address,
             account_id,
@@ -39,6 +48,10 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> H
 
             handle_host_action(deps, env, client_chain, proxy_address, account_id, action)
         }
+        ExecuteMsg::ExecuteDispatch {
+            manager,
+            manager_msgs,
+        } => execute_dispatch(deps, env, info, manager, manager_msgs),
         ExecuteMsg::UpdateOwnership(action) => {
             cw_ownable::update_ownership(deps, &env.block, &info.sender, action)?;
             Ok(HostResponse::action("update_ownership"))
@@ -47,10 +60,19 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> H
             msg,
             source_module,
             target_module,
+            nonce,
         } => {
             let src_chain: TruncatedChainId =
                 REVERSE_CHAIN_PROXIES.load(deps.storage, &info.sender)?;
-            handle_module_execute(deps, env, src_chain, source_module, target_module, msg)
+            handle_module_execute(
+                deps,
+                env,
+                src_chain,
+                source_module,
+                target_module,
+                msg,
+                nonce,
+            )
         }
     }
 }
@@ -76,6 +98,8 @@ fn update_config(
         // validate address format
         config.version_control =
             VersionControlContract::new(deps.api.addr_validate(&version_control_address)?);
+        // References cached from the previous version control may not be registered on the new one
+        MODULE_REFERENCES.clear(deps.storage);
     }
 
     if let Some(account_factory_address) = account_factory_address {
@@ -87,6 +111,24 @@ fn update_config(
     Ok(HostResponse::action("update_config"))
 }
 
+/// Remove modules from the cached version-control references
+fn invalidate_module_references(
+    deps: DepsMut,
+    info: MessageInfo,
+    modules: Vec<ModuleInfo>,
+) -> HostResult {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.version_control.address {
+        cw_ownable::assert_owner(deps.storage, &info.sender)
+            .map_err(|_| HostError::UnauthorizedInvalidation {})?;
+    }
+
+    for module in modules {
+        MODULE_REFERENCES.remove(deps.storage, &module);
+    }
+    Ok(HostResponse::action("invalidate_module_references"))
+}
+
 /// Register the polytone proxy address for a given chain
 /// The polytone proxy will send messages to this address when it needs to execute actions on a local account.
 fn register_chain_proxy(
@@ -123,3 +165,18 @@ fn remove_chain_proxy(deps: DepsMut, info: MessageInfo, chain: TruncatedChainId)
     CHAIN_PROXIES.remove(deps.storage, &chain);
     Ok(HostResponse::action("register_chain_client"))
 }
+
+/// Set or remove the gas limit of the manager messages executed by a dispatch
+fn update_dispatch_gas_budget(
+    deps: DepsMut,
+    info: MessageInfo,
+    gas_budget: Option<u64>,
+) -> HostResult {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    match gas_budget {
+        Some(gas_budget) => DISPATCH_GAS_BUDGET.save(deps.storage, &gas_budget)?,
+        None => DISPATCH_GAS_BUDGET.remove(deps.storage),
+    }
+    Ok(HostResponse::action("update_dispatch_gas_budget"))
+}
@@ -1,14 +1,17 @@
 use abstract_std::{
     base::ExecuteMsg as MiddlewareExecMsg,
     ibc::{ModuleIbcInfo, ModuleIbcMsg},
-    ibc_client::InstalledModuleIdentification,
+    ibc_client::{InstalledModuleIdentification, ModuleAddr},
     ibc_host::{
-        state::{ActionAfterCreationCache, CONFIG, TEMP_ACTION_AFTER_CREATION},
+        state::{ActionAfterCreationCache, CONFIG, MODULE_REFERENCES, TEMP_ACTION_AFTER_CREATION},
         HelperAction, HostAction, InternalAction,
     },
+    manager::{self, ModuleInstallConfig},
     objects::{
-        account::AccountTrace, module::ModuleInfo, module_reference::ModuleReference, AccountId,
-        TruncatedChainId,
+        account::AccountTrace,
+        module::{ModuleInfo, ModuleVersion},
+        module_reference::ModuleReference,
+        AccountId, TruncatedChainId,
     },
 };
 use cosmwasm_std::{
@@ -67,23 +70,29 @@ pub fn handle_host_action(
             if let Ok(account) = account_commands::get_account(deps.as_ref(), &account_id) {
                 match action {
                     HostAction::Dispatch { manager_msgs } => {
-                        receive_dispatch(deps, account, manager_msgs)
+                        receive_dispatch(deps.as_ref(), env, account, manager_msgs)
                     }
                     HostAction::Helpers(helper_action) => match helper_action {
                         HelperAction::SendAllBack => {
                             receive_send_all_back(deps, env, account, proxy_address, client_chain)
                         }
-                        _ => unimplemented!(""),
+                        helper_action => Err(HostError::UnsupportedHostAction {
+                            action: format!("{helper_action:?}"),
+                        }),
                     },
                     HostAction::Internal(InternalAction::Register { .. }) => {
                         unreachable!("This action is handled above")
                     }
-                    _ => unimplemented!(""),
+                    action => Err(HostError::UnsupportedHostAction {
+                        action: format!("{action:?}"),
+                    }),
                 }
             } else {
                 // If no account is created already, we create one and execute the action on reply
                 // The account metadata are not set with this call
                 // One will have to change them at a later point if they decide to
+                // Modules installed at the start of a dispatch are installed with the account instead
+                let (install_modules, action) = split_install_modules(action);
                 let name = format!(
                     "Remote Abstract Account for {}/{}",
                     client_chain.as_str(),
@@ -109,7 +118,7 @@ pub fn handle_host_action(
                     None,
                     None,
                     None,
-                    vec![],
+                    install_modules,
                     true,
                 )
             }
@@ -118,6 +127,29 @@ pub fn handle_host_action(
     .map_err(Into::into)
 }
 
+/// Split the leading `InstallModules` messages of a dispatch from the rest of the action.
+/// Only leading messages are taken so the remaining messages keep their order relative to the installs.
+fn split_install_modules(action: HostAction) -> (Vec<ModuleInstallConfig>, HostAction) {
+    let HostAction::Dispatch { manager_msgs } = action else {
+        return (vec![], action);
+    };
+
+    let mut install_modules = vec![];
+    let mut manager_msgs = manager_msgs.into_iter().peekable();
+    while let Some(manager::ExecuteMsg::InstallModules { modules }) =
+        manager_msgs.next_if(|msg| matches!(msg, manager::ExecuteMsg::InstallModules { .. }))
+    {
+        install_modules.extend(modules);
+    }
+
+    (
+        install_modules,
+        HostAction::Dispatch {
+            manager_msgs: manager_msgs.collect(),
+        },
+    )
+}
+
 /// Handle actions that are passed to the IBC host contract and originate from a registered module
 pub fn handle_module_execute(
     deps: DepsMut,
@@ -126,10 +158,10 @@ pub fn handle_module_execute(
     source_module: InstalledModuleIdentification,
     target_module: ModuleInfo,
     msg: Binary,
+    nonce: Option<u64>,
 ) -> HostResult {
+    let src_account_id = source_module.account_id.clone();
     // We resolve the target module
-    let vc = CONFIG.load(deps.storage)?.version_control;
-
     let target_module = InstalledModuleIdentification {
         module_info: target_module,
         // Account can only call modules that are installed on its ICAA.
@@ -139,15 +171,20 @@ pub fn handle_module_execute(
             .map(|a| client_to_host_module_account_id(&env, src_chain.clone(), a)),
     };
 
-    let target_module_resolved = target_module.addr(deps.as_ref(), vc)?;
+    let target_module_resolved = resolve_target_module(deps, &target_module)?;
 
     match target_module_resolved.reference {
+        ModuleReference::App(_) | ModuleReference::Adapter(_) | ModuleReference::Standalone(_) => {}
         ModuleReference::AccountBase(_) | ModuleReference::Native(_) => {
             return Err(HostError::WrongModuleAction(
                 "Can't send module-to-module message to an account or a native module".to_string(),
             ))
         }
-        _ => {}
+        reference => {
+            return Err(HostError::UnsupportedModuleReference {
+                reference: format!("{reference:?}"),
+            })
+        }
     }
 
     let response = Response::new().add_attribute("action", "module-ibc-call");
@@ -160,6 +197,8 @@ pub fn handle_module_execute(
                 module: source_module.module_info,
             },
             msg,
+            src_account_id,
+            nonce,
         }),
         vec![],
     )?;
@@ -176,7 +215,12 @@ pub fn handle_host_module_query(
     // We resolve the target module
     let vc = CONFIG.load(deps.storage)?.version_control;
 
-    let target_module_resolved = target_module.addr(deps, vc)?;
+    // The cache can't be filled from a query, but a reference cached by a module call is still used
+    let target_module_resolved =
+        match MODULE_REFERENCES.may_load(deps.storage, &target_module.module_info)? {
+            Some(reference) => target_module.addr_with_reference(deps, vc, reference)?,
+            None => target_module.addr(deps, vc)?,
+        };
 
     let query = QueryRequest::<Empty>::from(WasmQuery::Smart {
         contract_addr: target_module_resolved.address.into_string(),
@@ -194,6 +238,32 @@ pub fn handle_host_module_query(
     Ok(bin)
 }
 
+/// Resolve the target of a module-to-module call.
+/// The version-control reference of a module with an exact version is cached, as registered versions are immutable.
+/// Version control invalidates the cached reference when it yanks or removes the version.
+/// `latest` points to a new reference after every upgrade so it is always queried.
+fn resolve_target_module(
+    deps: DepsMut,
+    target_module: &InstalledModuleIdentification,
+) -> HostResult<ModuleAddr> {
+    let vc = CONFIG.load(deps.storage)?.version_control;
+
+    if let ModuleVersion::Latest = target_module.module_info.version {
+        return Ok(target_module.addr(deps.as_ref(), vc)?);
+    }
+
+    let reference = match MODULE_REFERENCES.may_load(deps.storage, &target_module.module_info)? {
+        Some(reference) => reference,
+        None => {
+            let reference =
+                vc.query_module_reference_raw(&target_module.module_info, &deps.querier)?;
+            MODULE_REFERENCES.save(deps.storage, &target_module.module_info, &reference)?;
+            reference
+        }
+    };
+    Ok(target_module.addr_with_reference(deps.as_ref(), vc, reference)?)
+}
+
 /// We need to figure what trace module is implying here
 pub fn client_to_host_module_account_id(
     env: &Env,
@@ -218,3 +288,278 @@ pub fn client_to_host_module_account_id(
     };
     account_id
 }
+
+#[cfg(test)]
+mod test {
+    use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+
+    use abstract_std::{
+        ibc_host::{ExecuteMsg, InstantiateMsg},
+        objects::{module::Module, version_control::VersionControlError},
+        version_control::{
+            state::REGISTERED_MODULES, ModuleConfiguration, ModuleResponse, ModulesResponse,
+            QueryMsg,
+        },
+    };
+    use cosmwasm_std::{
+        from_json,
+        testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage},
+        to_json_binary, Addr, CosmosMsg, OwnedDeps, WasmMsg,
+    };
+
+    use super::*;
+    use crate::contract::{execute, instantiate};
+
+    const VERSION_CONTROL: &str = "version_control";
+    const ADAPTER_ID: &str = "abstract:adapter";
+
+    struct TestingSetup {
+        deps: OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        /// Registered versions of the adapter and their address, the last one is `latest`
+        registry: Rc<RefCell<BTreeMap<String, Addr>>>,
+        /// Number of smart module queries made to version control
+        vc_queries: Rc<RefCell<u32>>,
+        /// Number of raw registry reads made on version control
+        registry_reads: Rc<RefCell<u32>>,
+    }
+
+    fn setup() -> TestingSetup {
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InstantiateMsg {
+                account_factory_address: "account_factory".to_string(),
+                version_control_address: VERSION_CONTROL.to_string(),
+                ans_host_address: "ans_host".to_string(),
+            },
+        )
+        .unwrap();
+
+        let registry = Rc::new(RefCell::new(BTreeMap::from([(
+            "1.0.0".to_owned(),
+            Addr::unchecked("adapter_v1"),
+        )])));
+        let vc_queries = Rc::new(RefCell::new(0));
+        let registry_reads = Rc::new(RefCell::new(0));
+        let (modules, query_counter, read_counter) =
+            (registry.clone(), vc_queries.clone(), registry_reads.clone());
+        deps.querier.update_wasm(move |query| match query {
+            WasmQuery::Raw { contract_addr, key } => {
+                assert_eq!(contract_addr, VERSION_CONTROL);
+                *read_counter.borrow_mut() += 1;
+                let reference = modules.borrow().iter().find_map(|(version, addr)| {
+                    let info = ModuleInfo::from_id(ADAPTER_ID, v(version)).unwrap();
+                    (*REGISTERED_MODULES.key(&info) == *key.as_slice())
+                        .then(|| ModuleReference::Adapter(addr.clone()))
+                });
+                let value = reference
+                    .map(|reference| to_json_binary(&reference).unwrap())
+                    .unwrap_or_default();
+                SystemResult::Ok(ContractResult::Ok(value))
+            }
+            WasmQuery::Smart { contract_addr, msg } => {
+                assert_eq!(contract_addr, VERSION_CONTROL);
+                let QueryMsg::Modules { infos } = from_json(msg).unwrap() else {
+                    panic!("unexpected version control query")
+                };
+                *query_counter.borrow_mut() += 1;
+                let latest = modules.borrow().values().last().unwrap().clone();
+                let modules = infos
+                    .into_iter()
+                    .map(|info| ModuleResponse {
+                        module: Module {
+                            info,
+                            reference: ModuleReference::Adapter(latest.clone()),
+                        },
+                        config: ModuleConfiguration::default(),
+                    })
+                    .collect();
+                SystemResult::Ok(ContractResult::Ok(
+                    to_json_binary(&ModulesResponse { modules }).unwrap(),
+                ))
+            }
+            _ => panic!("unexpected query {query:?}"),
+        });
+
+        TestingSetup {
+            deps,
+            registry,
+            vc_queries,
+            registry_reads,
+        }
+    }
+
+    /// Call the adapter from a remote module and return the address the call was routed to
+    fn call_adapter(deps: DepsMut, version: ModuleVersion) -> HostResult<String> {
+        let response = handle_module_execute(
+            deps,
+            mock_env(),
+            "juno".parse().unwrap(),
+            InstalledModuleIdentification {
+                module_info: ModuleInfo::from_id("abstract:caller", ModuleVersion::Latest).unwrap(),
+                account_id: None,
+            },
+            ModuleInfo::from_id(ADAPTER_ID, version).unwrap(),
+            Binary::default(),
+            None,
+        )?;
+        let CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, .. }) = &response.messages[0].msg
+        else {
+            panic!("expected a module call")
+        };
+        Ok(contract_addr.clone())
+    }
+
+    fn invalidate(deps: DepsMut, sender: &str, version: &str) -> HostResult {
+        execute(
+            deps,
+            mock_env(),
+            mock_info(sender, &[]),
+            ExecuteMsg::InvalidateModuleReferences {
+                modules: vec![ModuleInfo::from_id(ADAPTER_ID, v(version)).unwrap()],
+            },
+        )
+    }
+
+    fn v(version: &str) -> ModuleVersion {
+        ModuleVersion::Version(version.to_owned())
+    }
+
+    #[test]
+    fn cached_reference_skips_version_control() {
+        let TestingSetup {
+            mut deps,
+            vc_queries,
+            registry_reads,
+            ..
+        } = setup();
+
+        assert_eq!(
+            call_adapter(deps.as_mut(), v("1.0.0")).unwrap(),
+            "adapter_v1"
+        );
+        assert_eq!(
+            call_adapter(deps.as_mut(), v("1.0.0")).unwrap(),
+            "adapter_v1"
+        );
+        // Only the first call reads the registry
+        assert_eq!(*registry_reads.borrow(), 1);
+        assert_eq!(*vc_queries.borrow(), 0);
+
+        let module_info = ModuleInfo::from_id(ADAPTER_ID, v("1.0.0")).unwrap();
+        assert_eq!(
+            MODULE_REFERENCES
+                .load(deps.as_ref().storage, &module_info)
+                .unwrap(),
+            ModuleReference::Adapter(Addr::unchecked("adapter_v1"))
+        );
+    }
+
+    #[test]
+    fn upgrade_is_not_served_from_cache() {
+        let TestingSetup {
+            mut deps,
+            registry,
+            vc_queries,
+            ..
+        } = setup();
+
+        assert_eq!(
+            call_adapter(deps.as_mut(), v("1.0.0")).unwrap(),
+            "adapter_v1"
+        );
+        assert_eq!(
+            call_adapter(deps.as_mut(), ModuleVersion::Latest).unwrap(),
+            "adapter_v1"
+        );
+
+        // A new version of the adapter gets registered
+        registry
+            .borrow_mut()
+            .insert("2.0.0".to_owned(), Addr::unchecked("adapter_v2"));
+
+        assert_eq!(
+            call_adapter(deps.as_mut(), ModuleVersion::Latest).unwrap(),
+            "adapter_v2"
+        );
+        assert_eq!(
+            call_adapter(deps.as_mut(), v("2.0.0")).unwrap(),
+            "adapter_v2"
+        );
+        // The pinned version still resolves to its own reference
+        assert_eq!(
+            call_adapter(deps.as_mut(), v("1.0.0")).unwrap(),
+            "adapter_v1"
+        );
+        assert_eq!(*vc_queries.borrow(), 2);
+    }
+
+    #[test]
+    fn version_control_update_clears_cache() {
+        let TestingSetup {
+            mut deps,
+            registry_reads,
+            ..
+        } = setup();
+
+        call_adapter(deps.as_mut(), v("1.0.0")).unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::UpdateConfig {
+                ans_host_address: None,
+                account_factory_address: None,
+                version_control_address: Some(VERSION_CONTROL.to_string()),
+            },
+        )
+        .unwrap();
+        assert!(MODULE_REFERENCES.is_empty(deps.as_ref().storage));
+
+        call_adapter(deps.as_mut(), v("1.0.0")).unwrap();
+        assert_eq!(*registry_reads.borrow(), 2);
+    }
+
+    #[test]
+    fn yanked_version_is_invalidated() {
+        let TestingSetup {
+            mut deps, registry, ..
+        } = setup();
+
+        assert_eq!(
+            call_adapter(deps.as_mut(), v("1.0.0")).unwrap(),
+            "adapter_v1"
+        );
+
+        // Version control yanks the adapter version and invalidates the cached reference
+        registry.borrow_mut().remove("1.0.0");
+        invalidate(deps.as_mut(), VERSION_CONTROL, "1.0.0").unwrap();
+
+        let err = call_adapter(deps.as_mut(), v("1.0.0")).unwrap_err();
+        assert_eq!(
+            err,
+            HostError::VersionControlError(VersionControlError::ModuleNotFound {
+                module: ModuleInfo::from_id(ADAPTER_ID, v("1.0.0"))
+                    .unwrap()
+                    .to_string(),
+                registry_addr: Addr::unchecked(VERSION_CONTROL),
+            })
+        );
+    }
+
+    #[test]
+    fn invalidation_is_permissioned() {
+        let TestingSetup { mut deps, .. } = setup();
+
+        call_adapter(deps.as_mut(), v("1.0.0")).unwrap();
+
+        let err = invalidate(deps.as_mut(), "stranger", "1.0.0").unwrap_err();
+        assert_eq!(err, HostError::UnauthorizedInvalidation {});
+
+        // The owner can invalidate too
+        invalidate(deps.as_mut(), "admin", "1.0.0").unwrap();
+        assert!(MODULE_REFERENCES.is_empty(deps.as_ref().storage));
+    }
+}
@@ -3,7 +3,7 @@ use std::str::FromStr;
 use abstract_sdk::std::ibc_host::QueryMsg;
 use abstract_std::{
     ibc_host::{
-        state::{CHAIN_PROXIES, CONFIG},
+        state::{CHAIN_PROXIES, CONFIG, DISPATCH_GAS_BUDGET},
         ClientProxiesResponse, ClientProxyResponse, ConfigResponse,
     },
     objects::TruncatedChainId,
@@ -36,6 +36,7 @@ fn config(deps: Deps) -> HostResult<ConfigResponse> {
         ans_host_address: state.ans_host.address,
         account_factory_address: state.account_factory,
         version_control_address: state.version_control.address,
+        dispatch_gas_budget: DISPATCH_GAS_BUDGET.may_load(deps.storage)?,
     })
 }
 
@@ -1,5 +1,5 @@
-use abstract_std::ibc_host::state::TEMP_ACTION_AFTER_CREATION;
-use cosmwasm_std::{DepsMut, Env, Reply, Response};
+use abstract_std::ibc_host::{state::TEMP_ACTION_AFTER_CREATION, DispatchAck};
+use cosmwasm_std::{to_json_binary, Binary, DepsMut, Env, Reply, Response, SubMsgResult};
 use cw_utils::MsgExecuteContractResponse;
 
 use super::packet::handle_host_action;
@@ -10,6 +10,7 @@ use crate::{
 
 pub const INIT_BEFORE_ACTION_REPLY_ID: u64 = 28379;
 pub const RESPONSE_REPLY_ID: u64 = 362738;
+pub const DISPATCH_REPLY_ID: u64 = 926371;
 
 /// Handle reply after the Account is created, reply with the proxy address of the created account.
 pub fn reply_execute_action(deps: DepsMut, env: Env, _reply: Reply) -> Result<Response, HostError> {
@@ -49,3 +50,22 @@ pub fn reply_forward_response_data(result: Reply) -> HostResult {
 
     Ok(resp)
 }
+
+/// Acknowledge a dispatch with a [`DispatchAck`], wrapping the error of a failed dispatch
+/// or the data of a successful one.
+/// Note that the error message of a failed dispatch is redacted by wasmd.
+pub fn reply_dispatch_ack(result: Reply) -> HostResult {
+    if let SubMsgResult::Err(error) = result.result {
+        return Ok(
+            HostResponse::new("dispatch_ack", vec![("success", "false")])
+                .set_data(to_json_binary(&DispatchAck::Error(error))?),
+        );
+    }
+
+    let data = match cw_utils::parse_reply_execute_data(result) {
+        Ok(MsgExecuteContractResponse { data: Some(data) }) => data,
+        _ => Binary::default(),
+    };
+    Ok(HostResponse::new("dispatch_ack", vec![("success", "true")])
+        .set_data(to_json_binary(&DispatchAck::Ok(data))?))
+}
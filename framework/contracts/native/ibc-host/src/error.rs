@@ -40,12 +40,24 @@ pub enum HostError {
     #[error("Expected port {0} got {1} instead.")]
     ClientMismatch(String, String),
 
+    #[error("Dispatched manager messages can only be executed by the host itself")]
+    UnauthorizedDispatch {},
+
     #[error("Chain or proxy address already registered.")]
     ProxyAddressExists {},
 
     #[error("Can't send a module-to-module packet to {0}, wrong module type")]
     WrongModuleAction(String),
 
+    #[error("Module reference {reference} is not supported by this host")]
+    UnsupportedModuleReference { reference: String },
+
+    #[error("Host action {action} is not supported by this host")]
+    UnsupportedHostAction { action: String },
+
+    #[error("Module references can only be invalidated by the owner or version control")]
+    UnauthorizedInvalidation {},
+
     #[error("Missing module {module_info} on account {account_id}")]
     MissingModule {
         module_info: String,
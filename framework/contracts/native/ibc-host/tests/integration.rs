@@ -1,24 +1,34 @@
+use std::str::FromStr;
+
 use abstract_adapter::mock::MockInitMsg;
 use abstract_ibc_host::HostError;
 use abstract_interface::{
-    Abstract, AdapterDeployer, DeployStrategy, ExecuteMsgFns as InterfaceExecuteMsgFns,
+    Abstract, AbstractAccount, AccountDetails, AdapterDeployer, DeployStrategy,
+    ExecuteMsgFns as InterfaceExecuteMsgFns, ManagerQueryFns, VCExecFns,
 };
 use abstract_std::{
+    ibc_client::InstalledModuleIdentification,
     ibc_host::{
-        ClientProxyResponse, ConfigResponse, ExecuteMsgFns, HostAction, InternalAction, QueryMsgFns,
+        ClientProxyResponse, ConfigResponse, DispatchAck, ExecuteMsgFns, HostAction,
+        InternalAction, QueryMsgFns,
     },
     manager::ModuleInstallConfig,
     objects::{
-        gov_type::GovernanceDetails, module::ModuleInfo, AccountId, AssetEntry,
-        UncheckedChannelEntry,
+        gov_type::GovernanceDetails,
+        module::{ModuleInfo, ModuleVersion},
+        module_reference::ModuleReference,
+        AccountId, AssetEntry, TruncatedChainId, UncheckedChannelEntry,
     },
-    ACCOUNT_FACTORY, ICS20, MANAGER, PROXY,
+    AbstractError, ACCOUNT_FACTORY, ICS20, MANAGER, PROXY,
 };
-use cosmwasm_std::Event;
+use cosmwasm_std::{from_json, to_json_binary, Binary, Event};
 use cw_orch::prelude::*;
 use cw_ownable::OwnershipError;
 
-use crate::mock_adapter::{MockAdapter, MOCK_ADAPTER_ID};
+use crate::{
+    mock_adapter::{MockAdapter, MOCK_ADAPTER_ID},
+    mock_standalone::{MockMsg, MockStandalone, MOCK_STANDALONE_ID},
+};
 
 mod mock_adapter {
     use abstract_adapter::gen_adapter_mock;
@@ -29,6 +39,58 @@ mod mock_adapter {
     gen_adapter_mock!(MockAdapter, MOCK_ADAPTER_ID, "1.0.0", &[]);
 }
 
+mod mock_standalone {
+    use abstract_std::base::ExecuteMsg as MiddlewareExecMsg;
+    use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+
+    use super::*;
+
+    pub const MOCK_STANDALONE_ID: &str = "tester:standalone";
+
+    #[cosmwasm_schema::cw_serde]
+    pub struct MockMsg {}
+
+    pub type MockExecMsg = MiddlewareExecMsg<Empty, Empty>;
+
+    fn instantiate(
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        _msg: MockMsg,
+    ) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    /// Records the source of received module-to-module messages
+    fn execute(
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        msg: MockExecMsg,
+    ) -> StdResult<Response> {
+        match msg {
+            MiddlewareExecMsg::ModuleIbc(module_ibc) => Ok(Response::new().add_attribute(
+                "module_ibc_source",
+                module_ibc.src_module_info.module.to_string(),
+            )),
+            _ => Ok(Response::new()),
+        }
+    }
+
+    fn query(_deps: Deps, _env: Env, _msg: MockMsg) -> StdResult<Binary> {
+        Ok(Binary::default())
+    }
+
+    #[cw_orch::interface(MockMsg, MockExecMsg, MockMsg, Empty)]
+    pub struct MockStandalone;
+
+    impl<T: CwEnv> Uploadable for MockStandalone<T> {
+        fn wrapper() -> <Mock as ::cw_orch::environment::TxHandler>::ContractSource {
+            Box::new(ContractWrapper::new_with_empty(execute, instantiate, query))
+        }
+    }
+}
+
 #[test]
 fn account_creation() -> anyhow::Result<()> {
     let chain = MockBech32::new("mock");
@@ -52,6 +114,7 @@ fn account_creation() -> anyhow::Result<()> {
             ans_host_address: abstr_origin.ans_host.address()?,
             version_control_address: abstr_origin.version_control.address()?,
             account_factory_address: abstr_origin.account_factory.address()?,
+            dispatch_gas_budget: None,
         },
         config_response
     );
@@ -176,6 +239,7 @@ fn account_creation_full() -> anyhow::Result<()> {
             ans_host_address: abstr_origin.ans_host.address()?,
             version_control_address: abstr_origin.version_control.address()?,
             account_factory_address: abstr_origin.account_factory.address()?,
+            dispatch_gas_budget: None,
         },
         config_response
     );
@@ -188,6 +252,7 @@ fn account_creation_full() -> anyhow::Result<()> {
 
     // Add asset to set base_asset
     abstr_origin.ans_host.update_asset_addresses(
+        false,
         vec![("juno>juno".to_owned(), "native:juno".parse().unwrap())],
         vec![],
     )?;
@@ -313,6 +378,180 @@ fn account_action() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn cannot_update_dispatch_gas_budget_as_non_owner() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+
+    let admin = chain.addr_make("admin");
+    let mut origin_chain = chain.clone();
+    origin_chain.set_sender(admin.clone());
+
+    let abstr_origin = Abstract::deploy_on(origin_chain.clone(), admin.to_string())?;
+
+    let err: CwOrchError = abstr_origin
+        .ibc
+        .host
+        .call_as(&chain.addr_make("user"))
+        .update_dispatch_gas_budget(Some(1_000_000))
+        .unwrap_err();
+
+    assert_eq!(
+        HostError::OwnershipError(OwnershipError::NotOwner),
+        err.downcast()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn account_action_with_dispatch_gas_budget() -> anyhow::Result<()> {
+    let mock = MockBech32::new("mock");
+    let sender = mock.sender();
+
+    let admin = mock.addr_make("admin");
+    let mut origin_chain = mock.clone();
+    origin_chain.set_sender(admin.clone());
+
+    let abstr_origin = Abstract::deploy_on(origin_chain.clone(), admin.to_string())?;
+    let abstr_remote = Abstract::load_from(mock.clone())?;
+
+    let account_sequence = 1;
+    let chain = "juno";
+
+    abstr_origin
+        .ibc
+        .host
+        .update_dispatch_gas_budget(Some(1_000_000))?;
+    assert_eq!(
+        abstr_origin.ibc.host.config()?.dispatch_gas_budget,
+        Some(1_000_000)
+    );
+
+    abstr_origin
+        .ibc
+        .host
+        .register_chain_proxy(chain.parse().unwrap(), sender.to_string())?;
+
+    let proxy_addr = mock.addr_make("proxy_address");
+    abstr_remote.ibc.host.ibc_execute(
+        AccountId::local(account_sequence),
+        HostAction::Internal(InternalAction::Register {
+            name: "Abstract remote account 1".to_string(),
+            description: None,
+            link: None,
+            base_asset: None,
+            namespace: None,
+            install_modules: vec![],
+        }),
+        proxy_addr.to_string(),
+    )?;
+
+    // Dispatched manager messages are still executed within the budget
+    let account_action_response = abstr_remote.ibc.host.ibc_execute(
+        AccountId::local(account_sequence),
+        HostAction::Dispatch {
+            manager_msgs: vec![abstract_std::manager::ExecuteMsg::ProposeOwner {
+                owner: GovernanceDetails::Monarchy {
+                    monarch: mock.addr_make("new_owner").to_string(),
+                },
+            }],
+        },
+        proxy_addr.to_string(),
+    )?;
+
+    assert!(account_action_response.has_event(
+        &Event::new("wasm-abstract")
+            .add_attribute("contract", MANAGER)
+            .add_attribute("action", "update_owner")
+            .add_attribute("governance_type", "monarch")
+    ));
+
+    // The budget can be removed again
+    abstr_origin.ibc.host.update_dispatch_gas_budget(None)?;
+    assert_eq!(abstr_origin.ibc.host.config()?.dispatch_gas_budget, None);
+
+    Ok(())
+}
+
+#[test]
+fn failed_dispatch_is_acknowledged_with_error() -> anyhow::Result<()> {
+    let mock = MockBech32::new("mock");
+    let sender = mock.sender();
+
+    let admin = mock.addr_make("admin");
+    let mut origin_chain = mock.clone();
+    origin_chain.set_sender(admin.clone());
+
+    let abstr_origin = Abstract::deploy_on(origin_chain.clone(), admin.to_string())?;
+    let abstr_remote = Abstract::load_from(mock.clone())?;
+
+    let account_sequence = 1;
+    let chain = "juno";
+
+    abstr_origin
+        .ibc
+        .host
+        .register_chain_proxy(chain.parse().unwrap(), sender.to_string())?;
+
+    let proxy_addr = mock.addr_make("proxy_address");
+    abstr_remote.ibc.host.ibc_execute(
+        AccountId::local(account_sequence),
+        HostAction::Internal(InternalAction::Register {
+            name: "Abstract remote account 1".to_string(),
+            description: None,
+            link: None,
+            base_asset: None,
+            namespace: None,
+            install_modules: vec![],
+        }),
+        proxy_addr.to_string(),
+    )?;
+    let propose_owner = abstract_std::manager::ExecuteMsg::ProposeOwner {
+        owner: GovernanceDetails::Monarchy {
+            monarch: mock.addr_make("new_owner").to_string(),
+        },
+    };
+
+    // The second message fails, the packet is still processed and acknowledged with the error
+    let account_action_response = abstr_remote.ibc.host.ibc_execute(
+        AccountId::local(account_sequence),
+        HostAction::Dispatch {
+            manager_msgs: vec![
+                propose_owner.clone(),
+                abstract_std::manager::ExecuteMsg::ExecOnModule {
+                    module_id: "abstract:unknown".to_owned(),
+                    exec_msg: to_json_binary(&Empty {})?,
+                },
+            ],
+        },
+        proxy_addr.to_string(),
+    )?;
+    let ack: DispatchAck = from_json(account_action_response.data.unwrap())?;
+    let DispatchAck::Error(error) = ack else {
+        panic!("expected an error ack, got {ack:?}");
+    };
+    assert!(error.contains("The provided module abstract:unknown was not found"));
+    // None of the messages got executed
+    assert!(!account_action_response.has_event(
+        &Event::new("wasm-abstract")
+            .add_attribute("contract", MANAGER)
+            .add_attribute("action", "update_owner")
+    ));
+
+    // Successful dispatches wrap the data of the manager response
+    let account_action_response = abstr_remote.ibc.host.ibc_execute(
+        AccountId::local(account_sequence),
+        HostAction::Dispatch {
+            manager_msgs: vec![propose_owner],
+        },
+        proxy_addr.to_string(),
+    )?;
+    let ack: DispatchAck = from_json(account_action_response.data.unwrap())?;
+    assert_eq!(ack, DispatchAck::Ok(Binary::default()));
+
+    Ok(())
+}
+
 #[test]
 fn execute_action_with_account_creation() -> anyhow::Result<()> {
     let mock = MockBech32::new("mock");
@@ -365,6 +604,66 @@ fn execute_action_with_account_creation() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn execute_action_with_account_creation_and_modules() -> anyhow::Result<()> {
+    let mock = MockBech32::new("mock");
+    let abstr = setup_standalone(&mock)?;
+
+    // The remote account doesn't exist yet, the standalone is installed when creating it
+    let account_action_response = abstr.ibc.host.ibc_execute(
+        AccountId::local(1),
+        HostAction::Dispatch {
+            manager_msgs: vec![
+                abstract_std::manager::ExecuteMsg::InstallModules {
+                    modules: vec![ModuleInstallConfig::new(
+                        ModuleInfo::from_id_latest(MOCK_STANDALONE_ID)?,
+                        Some(to_json_binary(&MockMsg {})?),
+                    )],
+                },
+                abstract_std::manager::ExecuteMsg::ProposeOwner {
+                    owner: GovernanceDetails::Monarchy {
+                        monarch: mock.addr_make("new_owner").to_string(),
+                    },
+                },
+            ],
+        },
+        mock.addr_make("proxy_address").to_string(),
+    )?;
+
+    assert!(account_action_response.has_event(
+        &Event::new("wasm-abstract")
+            .add_attribute("contract", ACCOUNT_FACTORY)
+            .add_attribute("action", "create_account")
+    ));
+    // The rest of the dispatch is still executed after the account creation
+    assert!(account_action_response.has_event(
+        &Event::new("wasm-abstract")
+            .add_attribute("contract", MANAGER)
+            .add_attribute("action", "update_owner")
+            .add_attribute("governance_type", "monarch")
+    ));
+
+    let mut remote_account_id = AccountId::local(1);
+    remote_account_id.push_chain(TruncatedChainId::from_str("juno")?);
+    let remote_account = AbstractAccount::new(&abstr, remote_account_id);
+    let module_infos = remote_account
+        .manager
+        .module_infos(None, None)?
+        .module_infos;
+    assert!(module_infos
+        .iter()
+        .any(|module| module.id == MOCK_STANDALONE_ID));
+
+    // The standalone was installed exactly once, so module-to-module messages reach it
+    let response = abstr
+        .ibc
+        .host
+        .execute(&standalone_module_execute_msg()?, None)?;
+    assert!(response.has_event(&Event::new("wasm").add_attribute("action", "module-ibc-call")));
+
+    Ok(())
+}
+
 #[test]
 fn execute_send_all_back_action() -> anyhow::Result<()> {
     let mock = MockBech32::new("mock");
@@ -427,3 +726,164 @@ fn execute_send_all_back_action() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Registers the mock standalone and creates the remote account for `juno` account 1.
+/// Deploys Abstract with the sender as juno proxy and registers the mock standalone.
+fn setup_standalone(mock: &MockBech32) -> anyhow::Result<Abstract<MockBech32>> {
+    let admin = mock.sender();
+    let abstr = Abstract::deploy_on(mock.clone(), admin.to_string())?;
+
+    // We need to set the sender as the proxy for juno chain
+    abstr
+        .ibc
+        .host
+        .register_chain_proxy("juno".parse()?, admin.to_string())?;
+
+    // Claim the namespace and register the standalone
+    abstr.account_factory.create_new_account(
+        AccountDetails {
+            name: "namespace owner".to_string(),
+            namespace: Some("tester".to_string()),
+            ..Default::default()
+        },
+        GovernanceDetails::Monarchy {
+            monarch: admin.to_string(),
+        },
+        None,
+    )?;
+    let standalone = MockStandalone::new(MOCK_STANDALONE_ID, mock.clone());
+    standalone.upload()?;
+    abstr.version_control.propose_modules(vec![(
+        ModuleInfo::from_id(
+            MOCK_STANDALONE_ID,
+            ModuleVersion::Version("1.0.0".to_owned()),
+        )?,
+        ModuleReference::Standalone(standalone.code_id()?),
+    )])?;
+
+    Ok(abstr)
+}
+
+fn setup_module_execute(mock: &MockBech32) -> anyhow::Result<Abstract<MockBech32>> {
+    let abstr = setup_standalone(mock)?;
+
+    // We create the remote account
+    abstr.ibc.host.ibc_execute(
+        AccountId::local(1),
+        HostAction::Internal(InternalAction::Register {
+            name: "Abstract remote account 1".to_string(),
+            description: None,
+            link: None,
+            base_asset: None,
+            namespace: None,
+            install_modules: vec![],
+        }),
+        mock.addr_make("proxy_address").to_string(),
+    )?;
+
+    Ok(abstr)
+}
+
+/// Module-to-module message from the standalone on juno account 1 to its counterpart on the remote account.
+fn standalone_module_execute_msg() -> anyhow::Result<abstract_std::ibc_host::ExecuteMsg> {
+    Ok(abstract_std::ibc_host::ExecuteMsg::ModuleExecute {
+        source_module: InstalledModuleIdentification {
+            module_info: ModuleInfo::from_id_latest(MOCK_STANDALONE_ID)?,
+            account_id: Some(AccountId::local(1)),
+        },
+        target_module: ModuleInfo::from_id_latest(MOCK_STANDALONE_ID)?,
+        msg: to_json_binary(&MockMsg {})?,
+        nonce: None,
+    })
+}
+
+#[test]
+fn module_execute_to_standalone() -> anyhow::Result<()> {
+    let mock = MockBech32::new("mock");
+    let abstr = setup_module_execute(&mock)?;
+
+    // Install the standalone on the remote account
+    abstr.ibc.host.ibc_execute(
+        AccountId::local(1),
+        HostAction::Dispatch {
+            manager_msgs: vec![abstract_std::manager::ExecuteMsg::InstallModules {
+                modules: vec![ModuleInstallConfig::new(
+                    ModuleInfo::from_id_latest(MOCK_STANDALONE_ID)?,
+                    Some(to_json_binary(&MockMsg {})?),
+                )],
+            }],
+        },
+        mock.addr_make("proxy_address").to_string(),
+    )?;
+
+    let response = abstr
+        .ibc
+        .host
+        .execute(&standalone_module_execute_msg()?, None)?;
+
+    assert!(response.has_event(&Event::new("wasm").add_attribute("action", "module-ibc-call")));
+    assert!(response.has_event(&Event::new("wasm").add_attribute(
+        "module_ibc_source",
+        ModuleInfo::from_id_latest(MOCK_STANDALONE_ID)?.to_string()
+    )));
+
+    Ok(())
+}
+
+#[test]
+fn module_execute_to_missing_standalone() -> anyhow::Result<()> {
+    let mock = MockBech32::new("mock");
+    let abstr = setup_module_execute(&mock)?;
+
+    // The standalone is registered but not installed on the remote account
+    let err = abstr
+        .ibc
+        .host
+        .execute(&standalone_module_execute_msg()?, None)
+        .unwrap_err();
+
+    let mut remote_account_id = AccountId::local(1);
+    remote_account_id.push_chain(TruncatedChainId::from_str("juno")?);
+    assert_eq!(
+        HostError::Abstract(AbstractError::StandaloneNotInstalled {
+            module: ModuleInfo::from_id_latest(MOCK_STANDALONE_ID)?.to_string(),
+            account_id: remote_account_id,
+        }),
+        err.downcast()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn module_execute_to_account_base() -> anyhow::Result<()> {
+    let mock = MockBech32::new("mock");
+    let abstr = setup_module_execute(&mock)?;
+
+    // The manager of the remote account is an account base, not a module
+    let err = abstr
+        .ibc
+        .host
+        .execute(
+            &abstract_std::ibc_host::ExecuteMsg::ModuleExecute {
+                source_module: InstalledModuleIdentification {
+                    module_info: ModuleInfo::from_id_latest(MOCK_STANDALONE_ID)?,
+                    account_id: Some(AccountId::local(1)),
+                },
+                target_module: ModuleInfo::from_id_latest(MANAGER)?,
+                msg: to_json_binary(&MockMsg {})?,
+                nonce: None,
+            },
+            None,
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        HostError::WrongModuleAction(
+            "Can't send module-to-module message to an account or a native module".to_string()
+        ),
+        err.downcast()?
+    );
+
+    Ok(())
+}
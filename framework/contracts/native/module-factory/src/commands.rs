@@ -40,8 +40,10 @@ pub fn execute_create_modules(
     let account_base = version_control.assert_manager(&info.sender, &deps.querier)?;
 
     // get module info and module config for further use
-    let (infos, init_msgs): (Vec<ModuleInfo>, Vec<Option<Binary>>) =
-        modules.into_iter().map(|m| (m.module, m.init_msg)).unzip();
+    let (infos, init_msgs): (Vec<ModuleInfo>, Vec<(Option<Binary>, Vec<Coin>)>) = modules
+        .into_iter()
+        .map(|m| (m.module, (m.init_msg, m.funds)))
+        .unzip();
 
     let modules_responses = version_control.query_modules_configs(infos, &deps.querier)?;
 
@@ -61,12 +63,18 @@ pub fn execute_create_modules(
     let mut at_least_one_standalone = false;
 
     let canonical_contract_addr = deps.api.addr_canonicalize(env.contract.address.as_str())?;
-    for (owner_init_msg, module_response) in
+    for ((owner_init_msg, owner_funds), module_response) in
         init_msgs.into_iter().zip(modules_responses.into_iter())
     {
         let new_module = module_response.module;
         let new_module_monetization = module_response.config.monetization;
-        let new_module_init_funds = module_response.config.instantiation_funds;
+        // Funds requested by the module, topped up with the funds the owner sends along
+        let mut new_module_init_funds =
+            Coins::try_from(module_response.config.instantiation_funds)?;
+        for coin in owner_funds {
+            new_module_init_funds.add(coin)?;
+        }
+        let new_module_init_funds = new_module_init_funds.into_vec();
         module_ids.push(new_module.info.id_with_version());
 
         // We validate the fee if it was required by the version control to install this module
@@ -126,6 +134,9 @@ pub fn execute_create_modules(
             }
             // Adapter is not installed but registered instead, so we don't push to the `installed_modules`
             ModuleReference::Adapter(addr) => {
+                if !new_module_init_funds.is_empty() {
+                    return Err(ModuleFactoryError::UnexpectedModuleFunds {});
+                }
                 modules_to_register.push(addr.clone());
             }
             ModuleReference::Standalone(code_id) => {
@@ -147,6 +158,9 @@ pub fn execute_create_modules(
             }
             ModuleReference::Native(native_address) => {
                 if new_module.info.id() == IBC_CLIENT {
+                    if !new_module_init_funds.is_empty() {
+                        return Err(ModuleFactoryError::UnexpectedModuleFunds {});
+                    }
                     modules_to_register.push(native_address.clone());
                     continue;
                 }
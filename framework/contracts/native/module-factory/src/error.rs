@@ -36,4 +36,7 @@ pub enum ModuleFactoryError {
 
     #[error("This module type can not be installed on your Account")]
     ModuleNotInstallable {},
+
+    #[error("Funds can only be sent to modules that get instantiated on install")]
+    UnexpectedModuleFunds {},
 }
@@ -11,6 +11,7 @@ use abstract_sdk::{
     },
 };
 use abstract_std::{
+    ibc_host,
     objects::{
         fee::FixedFee,
         module::{self, Module},
@@ -18,10 +19,11 @@ use abstract_std::{
         ABSTRACT_ACCOUNT_ID,
     },
     version_control::{ModuleDefaultConfiguration, UpdateModule},
+    IBC_HOST,
 };
 use cosmwasm_std::{
-    ensure, Addr, Attribute, BankMsg, Coin, CosmosMsg, Deps, DepsMut, MessageInfo, Order,
-    QuerierWrapper, StdResult, Storage,
+    ensure, wasm_execute, Addr, Attribute, BankMsg, Coin, CosmosMsg, Deps, DepsMut, MessageInfo,
+    Order, QuerierWrapper, StdResult, Storage, WasmMsg,
 };
 
 use crate::{
@@ -221,6 +223,8 @@ pub fn remove_module(deps: DepsMut, msg_info: MessageInfo, module: ModuleInfo) -
         VCError::ModuleNotFound(module)
     );
 
+    let invalidate_msg = invalidate_ibc_host_reference(deps.as_ref(), &module)?;
+
     REGISTERED_MODULES.remove(deps.storage, &module);
     YANKED_MODULES.remove(deps.storage, &module);
     MODULE_CONFIG.remove(deps.storage, &module);
@@ -239,10 +243,10 @@ pub fn remove_module(deps: DepsMut, msg_info: MessageInfo, module: ModuleInfo) -
     {
         MODULE_DEFAULT_CONFIG.remove(deps.storage, (&module.namespace, &module.name));
     }
-    Ok(VcResponse::new(
-        "remove_module",
-        vec![("module", &module.to_string())],
-    ))
+    Ok(
+        VcResponse::new("remove_module", vec![("module", &module.to_string())])
+            .add_messages(invalidate_msg),
+    )
 }
 
 /// Yank a module, preventing it from being used.
@@ -259,10 +263,33 @@ pub fn yank_module(deps: DepsMut, msg_info: MessageInfo, module: ModuleInfo) ->
     YANKED_MODULES.save(deps.storage, &module, &mod_ref)?;
     REGISTERED_MODULES.remove(deps.storage, &module);
 
-    Ok(VcResponse::new(
-        "yank_module",
-        vec![("module", &module.to_string())],
-    ))
+    let invalidate_msg = invalidate_ibc_host_reference(deps.as_ref(), &module)?;
+    Ok(
+        VcResponse::new("yank_module", vec![("module", &module.to_string())])
+            .add_messages(invalidate_msg),
+    )
+}
+
+/// Message removing `module` from the cached module references of the IBC host, if one is registered.
+/// The IBC host is resolved like its `latest` version.
+fn invalidate_ibc_host_reference(deps: Deps, module: &ModuleInfo) -> VCResult<Option<WasmMsg>> {
+    let ibc_host = ModuleInfo::from_id(IBC_HOST, ModuleVersion::Latest)?;
+    let latest_host = REGISTERED_MODULES
+        .prefix((ibc_host.namespace, ibc_host.name))
+        .range(deps.storage, None, None, Order::Descending)
+        .next()
+        .transpose()?;
+    let Some((_, ModuleReference::Native(host_addr))) = latest_host else {
+        return Ok(None);
+    };
+    let msg = wasm_execute(
+        host_addr,
+        &ibc_host::ExecuteMsg::InvalidateModuleReferences {
+            modules: vec![module.clone()],
+        },
+        vec![],
+    )?;
+    Ok(Some(msg))
 }
 
 /// Updates module configuration
@@ -424,20 +451,7 @@ fn claim_namespace_internal(
     namespace_to_claim: &str,
 ) -> VCResult<Option<CosmosMsg>> {
     // check if the account already has a namespace
-    let has_namespace = NAMESPACES_INFO
-        .idx
-        .account_id
-        .prefix(account_id.clone())
-        .range(storage, None, None, Order::Ascending)
-        .take(1)
-        .count()
-        == 1;
-    if has_namespace {
-        return Err(VCError::ExceedsNamespaceLimit {
-            limit: 1,
-            current: 1,
-        });
-    }
+    assert_no_namespace(storage, &account_id)?;
 
     let fee_msg = if let Some(fee) = fee {
         // assert it is paid
@@ -465,6 +479,54 @@ fn claim_namespace_internal(
     Ok(fee_msg)
 }
 
+/// Errors if the account already claimed a namespace
+fn assert_no_namespace(storage: &dyn Storage, account_id: &AccountId) -> VCResult<()> {
+    let has_namespace = NAMESPACES_INFO
+        .idx
+        .account_id
+        .prefix(account_id.clone())
+        .range(storage, None, None, Order::Ascending)
+        .take(1)
+        .count()
+        == 1;
+    if has_namespace {
+        return Err(VCError::ExceedsNamespaceLimit {
+            limit: 1,
+            current: 1,
+        });
+    }
+    Ok(())
+}
+
+/// Transfer a namespace to another account
+/// Only the owner of the account that claimed the namespace can do this
+pub fn transfer_namespace(
+    deps: DepsMut,
+    msg_info: MessageInfo,
+    namespace: String,
+    to_account: AccountId,
+) -> VCResult {
+    let namespace = Namespace::try_from(&namespace)?;
+    validate_account_owner(deps.as_ref(), &namespace, &msg_info.sender)?;
+
+    if !ACCOUNT_ADDRESSES.has(deps.storage, &to_account) {
+        return Err(VCError::UnknownAccountId { id: to_account });
+    }
+    assert_no_namespace(deps.storage, &to_account)?;
+
+    let from_account = NAMESPACES_INFO.load(deps.storage, &namespace)?;
+    NAMESPACES_INFO.save(deps.storage, &namespace, &to_account)?;
+
+    Ok(VcResponse::new(
+        "transfer_namespace",
+        vec![
+            ("namespace", namespace.to_string()),
+            ("from_account_id", from_account.to_string()),
+            ("to_account_id", to_account.to_string()),
+        ],
+    ))
+}
+
 /// Remove namespaces
 /// Only admin or the account owner can do this
 pub fn remove_namespaces(
@@ -619,7 +681,7 @@ mod test {
     use cosmwasm_std::{
         from_json,
         testing::{mock_dependencies, mock_env, mock_info},
-        to_json_binary, Addr, Coin,
+        to_json_binary, Addr, Coin, SubMsg,
     };
     use cw_ownable::OwnershipError;
     use speculoos::prelude::*;
@@ -1835,6 +1897,46 @@ mod test {
             Ok(())
         }
 
+        #[test]
+        fn yank_module_invalidates_ibc_host_reference() -> VersionControlTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = mock_manager_querier().build();
+            mock_init_with_account(deps.as_mut(), true)?;
+            let rm_module = test_module();
+            let ibc_host = ModuleInfo::from_id(IBC_HOST, ModuleVersion::Version("1.0.0".into()))?;
+            REGISTERED_MODULES.save(
+                deps.as_mut().storage,
+                &ibc_host,
+                &ModuleReference::Native(Addr::unchecked("ibc_host")),
+            )?;
+
+            let msg = ExecuteMsg::ClaimNamespace {
+                account_id: TEST_ACCOUNT_ID,
+                namespace: rm_module.namespace.to_string(),
+            };
+            execute_as(deps.as_mut(), OWNER, msg)?;
+            let add_modules_msg = ExecuteMsg::ProposeModules {
+                modules: vec![(rm_module.clone(), ModuleReference::App(0))],
+            };
+            execute_as(deps.as_mut(), OWNER, add_modules_msg)?;
+
+            let msg = ExecuteMsg::YankModule {
+                module: rm_module.clone(),
+            };
+            let res = execute_as(deps.as_mut(), OWNER, msg)?;
+
+            let expected_msg: CosmosMsg = wasm_execute(
+                "ibc_host",
+                &ibc_host::ExecuteMsg::InvalidateModuleReferences {
+                    modules: vec![rm_module],
+                },
+                vec![],
+            )?
+            .into();
+            assert_that!(res.messages).is_equal_to(vec![SubMsg::new(expected_msg)]);
+            Ok(())
+        }
+
         #[test]
         fn bad_version() -> VersionControlTestResult {
             let mut deps = mock_dependencies();
@@ -2096,6 +2198,82 @@ mod test {
         }
     }
 
+    mod transfer_namespace {
+        use super::*;
+
+        #[test]
+        fn transfer_to_other_account() -> VersionControlTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = mock_manager_querier().build();
+            mock_init_with_account(deps.as_mut(), true)?;
+            create_second_account(deps.as_mut());
+            claim_test_namespace_as_owner(deps.as_mut())?;
+            let namespace = Namespace::new(TEST_NAMESPACE)?;
+
+            let msg = ExecuteMsg::TransferNamespace {
+                namespace: TEST_NAMESPACE.to_string(),
+                to_account: SECOND_TEST_ACCOUNT_ID,
+            };
+            execute_as(deps.as_mut(), OWNER, msg)?;
+
+            let owner = NAMESPACES_INFO.load(&deps.storage, &namespace)?;
+            assert_that!(owner).is_equal_to(SECOND_TEST_ACCOUNT_ID);
+            // Previous account doesn't own any namespace anymore
+            let first_account_namespaces = NAMESPACES_INFO
+                .idx
+                .account_id
+                .prefix(TEST_ACCOUNT_ID)
+                .keys(&deps.storage, None, None, Order::Ascending)
+                .count();
+            assert_that!(first_account_namespaces).is_equal_to(0);
+            Ok(())
+        }
+
+        #[test]
+        fn transfer_as_other() -> VersionControlTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = mock_manager_querier().build();
+            mock_init_with_account(deps.as_mut(), true)?;
+            create_second_account(deps.as_mut());
+            claim_test_namespace_as_owner(deps.as_mut())?;
+
+            let msg = ExecuteMsg::TransferNamespace {
+                namespace: TEST_NAMESPACE.to_string(),
+                to_account: SECOND_TEST_ACCOUNT_ID,
+            };
+            let res = execute_as(deps.as_mut(), TEST_OTHER, msg);
+            assert_that!(&res)
+                .is_err()
+                .is_equal_to(&VCError::AccountOwnerMismatch {
+                    sender: Addr::unchecked(TEST_OTHER),
+                    owner: Addr::unchecked(OWNER),
+                });
+            Ok(())
+        }
+
+        #[test]
+        fn transfer_to_unknown_account() -> VersionControlTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = mock_manager_querier().build();
+            mock_init_with_account(deps.as_mut(), true)?;
+            claim_test_namespace_as_owner(deps.as_mut())?;
+
+            let msg = ExecuteMsg::TransferNamespace {
+                namespace: TEST_NAMESPACE.to_string(),
+                to_account: SECOND_TEST_ACCOUNT_ID,
+            };
+            let res = execute_as(deps.as_mut(), OWNER, msg);
+            assert_that!(&res)
+                .is_err()
+                .is_equal_to(&VCError::UnknownAccountId {
+                    id: SECOND_TEST_ACCOUNT_ID,
+                });
+            let owner = NAMESPACES_INFO.load(&deps.storage, &Namespace::new(TEST_NAMESPACE)?)?;
+            assert_that!(owner).is_equal_to(TEST_ACCOUNT_ID);
+            Ok(())
+        }
+    }
+
     fn claim_test_namespace_as_owner(deps: DepsMut) -> VersionControlTestResult {
         let msg = ExecuteMsg::ClaimNamespace {
             account_id: TEST_ACCOUNT_ID,
@@ -73,6 +73,10 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> V
             account_id,
         } => claim_namespace(deps, info, account_id, namespace),
         ExecuteMsg::RemoveNamespaces { namespaces } => remove_namespaces(deps, info, namespaces),
+        ExecuteMsg::TransferNamespace {
+            namespace,
+            to_account,
+        } => transfer_namespace(deps, info, namespace, to_account),
         ExecuteMsg::AddAccount {
             account_id,
             account_base: base,
@@ -134,6 +138,16 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> VCResult<Binary> {
                 limit,
             )?)
         }
+        QueryMsg::ModuleVersions {
+            module_id,
+            start_after,
+            limit,
+        } => to_json_binary(&queries::handle_module_versions_query(
+            deps,
+            module_id,
+            start_after,
+            limit,
+        )?),
         QueryMsg::Ownership {} => query_ownership!(deps),
     }
     .map_err(Into::into)
@@ -7,8 +7,8 @@ use abstract_sdk::std::{
     },
     version_control::{
         state::{ACCOUNT_ADDRESSES, REGISTERED_MODULES, YANKED_MODULES},
-        AccountBaseResponse, ModuleFilter, ModuleResponse, ModulesListResponse, ModulesResponse,
-        NamespaceListResponse,
+        AccountBaseResponse, ModuleFilter, ModuleResponse, ModuleVersionsResponse,
+        ModulesListResponse, ModulesResponse, NamespaceListResponse,
     },
 };
 use abstract_std::{
@@ -19,6 +19,7 @@ use abstract_std::{
     },
 };
 use cosmwasm_std::{Deps, Order, StdError, StdResult};
+use cw_semver::Version;
 use cw_storage_plus::{Bound, Map};
 
 use crate::{contract::VCResult, error::VCError};
@@ -151,6 +152,51 @@ pub fn handle_module_list_query(
     Ok(ModulesListResponse { modules })
 }
 
+/// Versions of the module across all statuses, paginated in semver order.
+///
+/// Storage orders versions as strings (`0.10.0` before `0.9.0`), so the range reads can't be bounded
+/// by `start_after`. Every page loads and sorts all versions of the module, which is fine for the
+/// handful of versions a module publishes but makes the cost grow with that number, not with `limit`.
+pub fn handle_module_versions_query(
+    deps: Deps,
+    module_id: String,
+    start_after: Option<String>,
+    limit: Option<u8>,
+) -> VCResult<ModuleVersionsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let ModuleInfo {
+        namespace, name, ..
+    } = ModuleInfo::from_id_latest(&module_id)?;
+    let start_after = start_after.map(|v| Version::parse(&v)).transpose()?;
+
+    let mut versions: Vec<(Version, ModuleStatus)> = vec![];
+    for (mod_lib, status) in [
+        (&REGISTERED_MODULES, ModuleStatus::Registered),
+        (&PENDING_MODULES, ModuleStatus::Pending),
+        (&YANKED_MODULES, ModuleStatus::Yanked),
+    ] {
+        for version in mod_lib.prefix((namespace.clone(), name.clone())).keys(
+            deps.storage,
+            None,
+            None,
+            Order::Ascending,
+        ) {
+            versions.push((Version::parse(&version?)?, status.clone()));
+        }
+    }
+    // Storage keys are ordered as strings, so sort by semver precedence instead
+    versions.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let versions = versions
+        .into_iter()
+        .filter(|(version, _)| start_after.as_ref().map_or(true, |start| version > start))
+        .take(limit)
+        .map(|(version, status)| (version.to_string(), status))
+        .collect();
+
+    Ok(ModuleVersionsResponse { versions })
+}
+
 pub fn handle_namespaces_query(
     deps: Deps,
     accounts: Vec<AccountId>,
@@ -988,6 +1034,57 @@ mod test {
         }
     }
 
+    mod module_versions {
+        use super::*;
+
+        fn versions_msg(start_after: Option<&str>, limit: Option<u8>) -> QueryMsg {
+            QueryMsg::ModuleVersions {
+                module_id: "cw-plus:module1".to_string(),
+                start_after: start_after.map(str::to_string),
+                limit,
+            }
+        }
+
+        #[test]
+        fn paginates_history_with_status() -> VersionControlTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = mock_manager_querier().build();
+            init_with_mods(deps.as_mut());
+
+            // 0.1.2 is registered by `init_with_mods`
+            let version = |v: &str| {
+                ModuleInfo::from_id("cw-plus:module1", ModuleVersion::Version(v.into())).unwrap()
+            };
+            propose_modules(deps.as_mut(), vec![version("0.2.0")], OWNER);
+            yank_module(deps.as_mut(), version("0.2.0"));
+
+            // enable security so the next proposal stays pending
+            execute_as_admin(
+                deps.as_mut(),
+                ExecuteMsg::UpdateConfig {
+                    account_factory_address: None,
+                    security_disabled: Some(false),
+                    namespace_registration_fee: None,
+                },
+            )?;
+            propose_modules(deps.as_mut(), vec![version("0.10.0")], OWNER);
+
+            let ModuleVersionsResponse { versions } =
+                from_json(query_helper(deps.as_ref(), versions_msg(None, Some(2)))?)?;
+            assert_that!(versions).is_equal_to(vec![
+                ("0.1.2".to_string(), ModuleStatus::Registered),
+                ("0.2.0".to_string(), ModuleStatus::Yanked),
+            ]);
+
+            let ModuleVersionsResponse { versions } = from_json(query_helper(
+                deps.as_ref(),
+                versions_msg(Some("0.2.0"), Some(2)),
+            )?)?;
+            assert_that!(versions).is_equal_to(vec![("0.10.0".to_string(), ModuleStatus::Pending)]);
+            Ok(())
+        }
+    }
+
     mod query_namespaces {
         use super::*;
 
@@ -61,10 +61,11 @@ macro_rules! export_endpoints {
         pub fn sudo(
             deps: ::cosmwasm_std::DepsMut,
             env: ::cosmwasm_std::Env,
-            msg: <$api_type as $crate::sdk::base::Handler>::SudoMsg,
+            msg: $crate::std::adapter::AdapterSudoMsg<
+                <$api_type as $crate::sdk::base::Handler>::SudoMsg,
+            >,
         ) -> Result<::cosmwasm_std::Response, <$api_type as $crate::sdk::base::Handler>::Error> {
-            use $crate::sdk::base::SudoEndpoint;
-            $api_const.sudo(deps, env, msg)
+            $api_const.adapter_sudo(deps, env, msg)
         }
     };
 }
@@ -128,7 +129,11 @@ mod test {
 
         // sudo
         let sudo_msg = MockSudoMsg {};
-        let actual_sudo = sudo(deps.as_mut(), mock_env(), sudo_msg.clone());
+        let actual_sudo = sudo(
+            deps.as_mut(),
+            mock_env(),
+            adapter::AdapterSudoMsg::Module(sudo_msg.clone()),
+        );
         let expected_sudo = MOCK_ADAPTER.sudo(deps.as_mut(), mock_env(), sudo_msg);
         assert_that!(actual_sudo).is_equal_to(expected_sudo);
 
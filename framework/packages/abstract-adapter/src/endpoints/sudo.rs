@@ -1,6 +1,8 @@
-use abstract_sdk::base::SudoEndpoint;
+use abstract_sdk::{base::SudoEndpoint, feature_objects::VersionControlContract, AbstractResponse};
+use abstract_std::adapter::AdapterSudoMsg;
+use cosmwasm_std::{DepsMut, Env, Response, StdResult};
 
-use crate::{state::ContractError, AdapterContract};
+use crate::{state::ContractError, AdapterContract, AdapterResult};
 
 impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, ReceiveMsg, SudoMsg>
     SudoEndpoint
@@ -8,19 +10,60 @@ impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, Receive
 {
 }
 
+impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, ReceiveMsg, SudoMsg>
+    AdapterContract<Error, CustomInitMsg, CustomExecMsg, CustomQueryMsg, ReceiveMsg, SudoMsg>
+{
+    /// Handler for the adapter's Sudo entry point.
+    /// Module messages are forwarded to the custom sudo handler.
+    pub fn adapter_sudo(
+        self,
+        deps: DepsMut,
+        env: Env,
+        msg: AdapterSudoMsg<SudoMsg>,
+    ) -> Result<Response, Error> {
+        match msg {
+            AdapterSudoMsg::UpdateRegistry { version_control } => self
+                .update_registry(deps, version_control)
+                .map_err(From::from),
+            AdapterSudoMsg::Module(msg) => self.sudo(deps, env, msg),
+        }
+    }
+
+    fn update_registry(&self, deps: DepsMut, version_control: String) -> AdapterResult {
+        let version_control = VersionControlContract {
+            address: deps.api.addr_validate(&version_control)?,
+        };
+        self.base_state
+            .update(deps.storage, |mut state| -> StdResult<_> {
+                state.version_control = version_control.clone();
+                Ok(state)
+            })?;
+
+        Ok(self.custom_response(
+            "update_registry",
+            vec![("version_control", version_control.address)],
+        ))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use abstract_std::adapter::AdapterSudoMsg;
+    use abstract_testing::prelude::*;
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env},
+        Addr,
+    };
     use speculoos::prelude::*;
 
-    use crate::mock::{sudo, AdapterMockResult};
+    use crate::mock::{mock_init, sudo, AdapterMockResult, MOCK_ADAPTER};
 
     #[test]
     fn endpoint() -> AdapterMockResult {
         let env = mock_env();
         let mut deps = mock_dependencies();
         deps.querier = abstract_testing::mock_querier();
-        let sudo_msg = crate::mock::MockSudoMsg {};
+        let sudo_msg = AdapterSudoMsg::Module(crate::mock::MockSudoMsg {});
         let res = sudo(deps.as_mut(), env, sudo_msg)?;
         assert_that!(&res.messages.len()).is_equal_to(0);
         // confirm data is set
@@ -28,4 +71,28 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn update_registry() -> AdapterMockResult {
+        let mut deps = mock_dependencies();
+        deps.querier = abstract_testing::mock_querier();
+        mock_init(deps.as_mut())?;
+
+        let new_version_control = "new_version_control";
+        sudo(
+            deps.as_mut(),
+            mock_env(),
+            AdapterSudoMsg::UpdateRegistry {
+                version_control: new_version_control.to_string(),
+            },
+        )?;
+
+        let state = MOCK_ADAPTER.state(&deps.storage)?;
+        assert_that!(state.version_control.address)
+            .is_equal_to(Addr::unchecked(new_version_control));
+        // ans host is untouched
+        assert_that!(state.ans_host.address).is_equal_to(Addr::unchecked(TEST_ANS_HOST));
+
+        Ok(())
+    }
 }
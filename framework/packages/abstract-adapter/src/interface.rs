@@ -103,13 +103,14 @@ macro_rules! cw_orch_interface {
             pub fn sudo(
                 deps: ::cosmwasm_std::DepsMut,
                 env: ::cosmwasm_std::Env,
-                msg: <$adapter_type as $crate::sdk::base::Handler>::SudoMsg,
+                msg: $crate::std::adapter::AdapterSudoMsg<
+                    <$adapter_type as $crate::sdk::base::Handler>::SudoMsg,
+                >,
             ) -> Result<
                 ::cosmwasm_std::Response,
                 <$adapter_type as $crate::sdk::base::Handler>::Error,
             > {
-                use $crate::sdk::base::SudoEndpoint;
-                $adapter_const.sudo(deps, env, msg)
+                $adapter_const.adapter_sudo(deps, env, msg)
             }
 
             pub type InstantiateMsg =
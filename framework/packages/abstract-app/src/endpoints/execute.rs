@@ -4,6 +4,7 @@ use abstract_sdk::{
 };
 use abstract_std::app::{AppExecuteMsg, BaseExecuteMsg, ExecuteMsg};
 use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use cw_controllers::AdminError;
 use schemars::JsonSchema;
 use serde::Serialize;
 
@@ -46,9 +47,7 @@ impl<
     ) -> Result<Response, Error> {
         match msg {
             ExecuteMsg::Module(request) => self.execute_handler()?(deps, env, info, self, request),
-            ExecuteMsg::Base(exec_msg) => self
-                .base_execute(deps, env, info, exec_msg)
-                .map_err(From::from),
+            ExecuteMsg::Base(exec_msg) => self.base_execute(deps, env, info, exec_msg),
             ExecuteMsg::IbcCallback(msg) => self.ibc_callback(deps, env, info, msg),
             ExecuteMsg::Receive(msg) => self.receive(deps, env, info, msg),
             ExecuteMsg::ModuleIbc(msg) => self.module_ibc(deps, env, info, msg),
@@ -76,17 +75,33 @@ impl<
     >
 {
     fn base_execute(
-        &self,
+        self,
         deps: DepsMut,
-        _env: Env,
+        env: Env,
         info: MessageInfo,
         message: BaseExecuteMsg,
-    ) -> AppResult {
+    ) -> Result<Response, Error> {
         match message {
             BaseExecuteMsg::UpdateConfig {
                 ans_host_address,
                 version_control_address,
-            } => self.update_config(deps, info, ans_host_address, version_control_address),
+            } => self
+                .update_config(deps, info, ans_host_address, version_control_address)
+                .map_err(From::from),
+            BaseExecuteMsg::Uninstall {} => self.uninstall(deps, env, info),
+        }
+    }
+
+    /// Run the uninstall handler, only the manager can call this
+    fn uninstall(self, deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, Error> {
+        let manager = self.load_state(deps.storage)?.account_base.manager;
+        if info.sender != manager {
+            return Err(AppError::Admin(AdminError::NotAdmin {}).into());
+        }
+
+        match self.maybe_uninstall_handler() {
+            Some(handler) => handler(deps, env, self),
+            None => Ok(self.response("uninstall")),
         }
     }
 
@@ -221,5 +236,176 @@ mod test {
 
             Ok(())
         }
+
+        #[test]
+        fn uninstall_runs_handler() -> AppTestResult {
+            let mut deps = mock_init();
+
+            let res = execute_as_manager(
+                deps.as_mut(),
+                AppExecuteMsg::Base(BaseExecuteMsg::Uninstall {}),
+            )?;
+            assert_that!(res.data).is_equal_to(Some("mock_uninstall".as_bytes().into()));
+            Ok(())
+        }
+
+        #[test]
+        fn uninstall_only_manager() -> AppTestResult {
+            let mut deps = mock_init();
+
+            let res = execute_as(
+                deps.as_mut(),
+                "not_manager",
+                AppExecuteMsg::Base(BaseExecuteMsg::Uninstall {}),
+            );
+            assert_that!(res).is_err().matches(|e| {
+                matches!(
+                    e,
+                    MockError::DappError(AppError::Admin(AdminError::NotAdmin {}))
+                )
+            });
+            Ok(())
+        }
+    }
+
+    mod module_ibc {
+        use super::*;
+        use abstract_sdk::{base::MODULE_IBC_NONCE_WINDOW, AbstractSdkError};
+        use abstract_std::{
+            ibc::{ModuleIbcInfo, ModuleIbcMsg},
+            objects::{
+                module::{Module, ModuleInfo},
+                module_reference::ModuleReference,
+                AccountId, TruncatedChainId,
+            },
+            version_control::{ModuleConfiguration, ModuleResponse, ModulesResponse},
+            IBC_HOST,
+        };
+        use cosmwasm_std::{to_json_binary, Binary};
+
+        const TEST_IBC_HOST: &str = "ibc_host";
+
+        fn mock_init_with_ibc_host() -> MockDeps {
+            let mut deps = mock_init();
+            deps.querier = app_base_mock_querier()
+                .with_smart_handler(TEST_VERSION_CONTROL, |_| {
+                    to_json_binary(&ModulesResponse {
+                        modules: vec![ModuleResponse {
+                            module: Module {
+                                info: ModuleInfo::from_id_latest(IBC_HOST).unwrap(),
+                                reference: ModuleReference::Native(Addr::unchecked(TEST_IBC_HOST)),
+                            },
+                            config: ModuleConfiguration::default(),
+                        }],
+                    })
+                    .map_err(|e| e.to_string())
+                })
+                .build();
+            deps
+        }
+
+        fn module_ibc_msg(nonce: Option<u64>) -> AppExecuteMsg {
+            module_ibc_msg_from(TEST_ACCOUNT_ID, nonce)
+        }
+
+        fn module_ibc_msg_from(src_account_id: AccountId, nonce: Option<u64>) -> AppExecuteMsg {
+            AppExecuteMsg::ModuleIbc(ModuleIbcMsg {
+                src_module_info: ModuleIbcInfo {
+                    chain: TruncatedChainId::from_chain_id("juno-1"),
+                    module: ModuleInfo::from_id_latest(TEST_WITH_DEP_MODULE_ID).unwrap(),
+                },
+                msg: Binary::default(),
+                src_account_id: Some(src_account_id),
+                nonce,
+            })
+        }
+
+        #[test]
+        fn replayed_nonce_is_rejected() -> AppTestResult {
+            let mut deps = mock_init_with_ibc_host();
+
+            let res = execute_as(deps.as_mut(), TEST_IBC_HOST, module_ibc_msg(Some(1)))?;
+            assert_that!(res.data).is_equal_to(Some("mock_module_ibc".as_bytes().into()));
+
+            // Same nonce delivered a second time
+            let res = execute_as(deps.as_mut(), TEST_IBC_HOST, module_ibc_msg(Some(1)));
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(MockError::AbstractSdk(
+                    AbstractSdkError::ModuleIbcNonceReplayed {
+                        chain: "juno".to_owned(),
+                        sender: format!("{TEST_ACCOUNT_ID}:{TEST_WITH_DEP_MODULE_ID}:latest"),
+                        nonce: 1,
+                    },
+                ));
+
+            // Next nonce is accepted
+            execute_as(deps.as_mut(), TEST_IBC_HOST, module_ibc_msg(Some(2)))?;
+            Ok(())
+        }
+
+        #[test]
+        fn nonces_are_tracked_per_sender() -> AppTestResult {
+            let mut deps = mock_init_with_ibc_host();
+            let other_account = AccountId::local(TEST_ACCOUNT_ID.seq() + 1);
+
+            // Same module installed on two accounts, both start at nonce 1
+            execute_as(
+                deps.as_mut(),
+                TEST_IBC_HOST,
+                module_ibc_msg_from(TEST_ACCOUNT_ID, Some(1)),
+            )?;
+            execute_as(
+                deps.as_mut(),
+                TEST_IBC_HOST,
+                module_ibc_msg_from(other_account.clone(), Some(1)),
+            )?;
+
+            let res = execute_as(
+                deps.as_mut(),
+                TEST_IBC_HOST,
+                module_ibc_msg_from(other_account, Some(1)),
+            );
+            assert_that!(res).is_err();
+            Ok(())
+        }
+
+        #[test]
+        fn out_of_order_nonces_are_accepted_once() -> AppTestResult {
+            let mut deps = mock_init_with_ibc_host();
+
+            execute_as(deps.as_mut(), TEST_IBC_HOST, module_ibc_msg(Some(3)))?;
+            execute_as(deps.as_mut(), TEST_IBC_HOST, module_ibc_msg(Some(1)))?;
+            execute_as(deps.as_mut(), TEST_IBC_HOST, module_ibc_msg(Some(2)))?;
+
+            for nonce in 1..=3 {
+                let res = execute_as(deps.as_mut(), TEST_IBC_HOST, module_ibc_msg(Some(nonce)));
+                assert_that!(res).is_err();
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn nonces_older_than_the_window_are_rejected() -> AppTestResult {
+            let mut deps = mock_init_with_ibc_host();
+
+            let highest = MODULE_IBC_NONCE_WINDOW + 1;
+            execute_as(deps.as_mut(), TEST_IBC_HOST, module_ibc_msg(Some(highest)))?;
+
+            let res = execute_as(deps.as_mut(), TEST_IBC_HOST, module_ibc_msg(Some(1)));
+            assert_that!(res).is_err();
+            // Still inside the window
+            execute_as(deps.as_mut(), TEST_IBC_HOST, module_ibc_msg(Some(2)))?;
+            Ok(())
+        }
+
+        #[test]
+        fn messages_without_nonce_are_not_checked() -> AppTestResult {
+            let mut deps = mock_init_with_ibc_host();
+
+            execute_as(deps.as_mut(), TEST_IBC_HOST, module_ibc_msg(None))?;
+            execute_as(deps.as_mut(), TEST_IBC_HOST, module_ibc_msg(None))?;
+            Ok(())
+        }
     }
 }
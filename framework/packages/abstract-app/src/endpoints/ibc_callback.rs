@@ -28,3 +28,64 @@ impl<
         Ok(ibc_client)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::mock::*;
+    use abstract_sdk::mock_module::mock_ibc_callback;
+    use abstract_std::{
+        ibc::{Callback, IbcResult},
+        manager::state::ACCOUNT_MODULES,
+        objects::dependency::StaticDependency,
+        IBC_CLIENT,
+    };
+    use abstract_testing::prelude::*;
+    use cosmwasm_std::{from_json, to_json_binary, Addr, Attribute, Response, StdError};
+    use speculoos::prelude::*;
+
+    const IBC_CLIENT_ADDR: &str = "ibc_client";
+
+    const CALLBACK_APP: MockAppContract = MockAppContract::new(TEST_MODULE_ID, TEST_VERSION, None)
+        .with_dependencies(&[StaticDependency::new(
+            IBC_CLIENT,
+            &[abstract_std::registry::ABSTRACT_VERSION],
+        )])
+        .with_ibc_callback(|_, _, _, callback, result| {
+            let tag: String = from_json(callback.msg)?;
+            let IbcResult::Query {
+                results: Ok(results),
+                ..
+            } = result
+            else {
+                return Err(StdError::generic_err("unexpected ibc result").into());
+            };
+            let balance: String = from_json(&results[0])?;
+            Ok(Response::new().add_attribute(tag, balance))
+        });
+
+    #[test]
+    fn handler_decodes_success_ack() -> AppTestResult {
+        let mut deps = mock_init();
+        deps.querier = app_base_mock_querier()
+            .with_contract_map_entry(
+                TEST_MANAGER,
+                ACCOUNT_MODULES,
+                (IBC_CLIENT, Addr::unchecked(IBC_CLIENT_ADDR)),
+            )
+            .build();
+
+        let res = mock_ibc_callback(
+            CALLBACK_APP,
+            deps.as_mut(),
+            mock_env(),
+            Callback::new(&"balance")?,
+            IbcResult::Query {
+                queries: vec![],
+                results: Ok(vec![to_json_binary("100uatom")?]),
+            },
+        )?;
+
+        assert_that!(res.attributes).is_equal_to(vec![Attribute::new("balance", "100uatom")]);
+        Ok(())
+    }
+}
@@ -1,5 +1,9 @@
 use abstract_std::{
-    app::{AppConfigResponse, AppQueryMsg, BaseQueryMsg, QueryMsg},
+    app::{
+        AppConfigResponse, AppQueryMsg, BaseQueryMsg, DependencyStatus, DependencyStatusResponse,
+        HandlersResponse, QueryMsg,
+    },
+    manager::{self, state::ACCOUNT_MODULES, ModuleVersionsResponse},
     objects::{
         module_version::{ModuleDataResponse, MODULE},
         nested_admin::{query_top_level_owner, TopLevelOwnerResponse},
@@ -7,6 +11,7 @@ use abstract_std::{
 };
 use cosmwasm_std::{to_json_binary, Binary, Deps, Env, StdResult};
 use cw_controllers::AdminResponse;
+use cw_semver::Version;
 
 use crate::{
     state::{AppContract, ContractError},
@@ -68,6 +73,8 @@ impl<
             BaseQueryMsg::BaseAdmin {} => to_json_binary(&self.admin(deps)?),
             BaseQueryMsg::ModuleData {} => to_json_binary(&self.module_data(deps)?),
             BaseQueryMsg::TopLevelOwner {} => to_json_binary(&self.top_level_owner(deps)?),
+            BaseQueryMsg::Handlers {} => to_json_binary(&self.handlers()),
+            BaseQueryMsg::DependencyStatus {} => to_json_binary(&self.dependency_status(deps)?),
         }
     }
 
@@ -104,6 +111,53 @@ impl<
         let addr = query_top_level_owner(&deps.querier, manager)?;
         Ok(TopLevelOwnerResponse { address: addr })
     }
+
+    fn handlers(&self) -> HandlersResponse {
+        let contract = self.contract();
+        HandlersResponse {
+            reply_ids: contract.reply_ids(),
+            ibc_callback: contract.has_ibc_callback(),
+        }
+    }
+
+    fn dependency_status(&self, deps: Deps) -> StdResult<DependencyStatusResponse> {
+        let manager = self.admin.get(deps)?.unwrap();
+        let dependencies = self
+            .dependencies()
+            .iter()
+            .map(|dependency| {
+                let installed_version =
+                    match ACCOUNT_MODULES.query(&deps.querier, manager.clone(), dependency.id)? {
+                        Some(_) => {
+                            let ModuleVersionsResponse { mut versions } =
+                                deps.querier.query_wasm_smart(
+                                    &manager,
+                                    &manager::QueryMsg::ModuleVersions {
+                                        ids: vec![dependency.id.to_owned()],
+                                    },
+                                )?;
+                            versions.pop().map(|version| version.version)
+                        }
+                        None => None,
+                    };
+                let satisfied = installed_version
+                    .as_deref()
+                    .and_then(|version| Version::parse(version).ok())
+                    .is_some_and(|version| dependency.matches(&version));
+                Ok(DependencyStatus {
+                    id: dependency.id.to_owned(),
+                    version_req: dependency
+                        .version_req
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect(),
+                    installed_version,
+                    satisfied,
+                })
+            })
+            .collect::<StdResult<_>>()?;
+        Ok(DependencyStatusResponse { dependencies })
+    }
 }
 
 #[cfg(test)]
@@ -172,9 +226,16 @@ mod test {
     mod base_query {
         use super::*;
 
-        use abstract_std::app::{AppConfigResponse, BaseQueryMsg};
+        use abstract_std::{
+            app::{
+                AppConfigResponse, BaseQueryMsg, DependencyStatus, DependencyStatusResponse,
+                HandlersResponse,
+            },
+            manager::{self, state::ACCOUNT_MODULES, ModuleVersionsResponse},
+            IBC_CLIENT,
+        };
         use abstract_testing::prelude::*;
-        use cosmwasm_std::Addr;
+        use cosmwasm_std::{to_json_binary, Addr};
         use cw_controllers::AdminResponse;
 
         #[test]
@@ -206,5 +267,79 @@ mod test {
 
             Ok(())
         }
+
+        #[test]
+        fn handlers() -> AppTestResult {
+            let deps = mock_init();
+
+            let handlers_query = QueryMsg::Base(BaseQueryMsg::Handlers {});
+            let res = query_helper(deps.as_ref(), handlers_query.clone())?;
+
+            assert_that!(from_json(res).unwrap()).is_equal_to(HandlersResponse {
+                reply_ids: vec![],
+                ibc_callback: false,
+            });
+
+            let res = MOCK_APP_WITH_DEP.query(deps.as_ref(), mock_env(), handlers_query)?;
+
+            assert_that!(from_json(res).unwrap()).is_equal_to(HandlersResponse {
+                reply_ids: vec![1],
+                ibc_callback: true,
+            });
+
+            Ok(())
+        }
+
+        #[test]
+        fn dependency_status() -> AppTestResult {
+            let mut deps = mock_init();
+            deps.querier = app_base_mock_querier()
+                .with_contract_map_entry(
+                    TEST_MANAGER,
+                    ACCOUNT_MODULES,
+                    (TEST_MODULE_ID, Addr::unchecked(TEST_MODULE_ADDRESS)),
+                )
+                // Other modules aren't installed
+                .with_raw_handler(TEST_MANAGER, |_| Ok(Binary::default()))
+                .with_smart_handler(TEST_MANAGER, |msg| match from_json(msg).unwrap() {
+                    manager::QueryMsg::ModuleVersions { ids } => {
+                        assert_eq!(ids, vec![TEST_MODULE_ID.to_owned()]);
+                        to_json_binary(&ModuleVersionsResponse {
+                            versions: vec![cw2::ContractVersion {
+                                contract: TEST_MODULE_ID.to_owned(),
+                                version: TEST_VERSION.to_owned(),
+                            }],
+                        })
+                        .map_err(|e| e.to_string())
+                    }
+                    _ => panic!("unexpected query"),
+                })
+                .build();
+
+            let res = MOCK_APP_WITH_DEP.query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::Base(BaseQueryMsg::DependencyStatus {}),
+            )?;
+
+            assert_that!(from_json(res).unwrap()).is_equal_to(DependencyStatusResponse {
+                dependencies: vec![
+                    DependencyStatus {
+                        id: TEST_MODULE_ID.to_owned(),
+                        version_req: vec![TEST_VERSION.to_owned()],
+                        installed_version: Some(TEST_VERSION.to_owned()),
+                        satisfied: true,
+                    },
+                    DependencyStatus {
+                        id: IBC_CLIENT.to_owned(),
+                        version_req: vec![abstract_std::registry::ABSTRACT_VERSION.to_owned()],
+                        installed_version: None,
+                        satisfied: false,
+                    },
+                ],
+            });
+
+            Ok(())
+        }
     }
 }
@@ -157,6 +157,9 @@ pub mod mock {
 
                 Ok(Response::new().add_attribute("mock_callback", "executed"))
             })
+            .with_module_ibc(|_, _, _, _, _| {
+                Ok(Response::new().set_data("mock_module_ibc".as_bytes()))
+            })
             .with_dependencies(&[
                 StaticDependency::new(TEST_MODULE_ID, &[TEST_VERSION]),
                 StaticDependency::new(IBC_CLIENT, &[abstract_std::registry::ABSTRACT_VERSION]),
@@ -164,7 +167,8 @@ pub mod mock {
             .with_replies(&[(1u64, |_, _, _, msg| {
                 Ok(Response::new().set_data(msg.result.unwrap().data.unwrap()))
             })])
-            .with_migrate(|_, _, _, _| Ok(Response::new().set_data("mock_migrate".as_bytes())));
+            .with_migrate(|_, _, _, _| Ok(Response::new().set_data("mock_migrate".as_bytes())))
+            .with_uninstall(|_, _, _| Ok(Response::new().set_data("mock_uninstall".as_bytes())));
 
     crate::cw_orch_interface!(MOCK_APP_WITH_DEP, MockAppContract, MockAppWithDepI);
 
@@ -311,7 +315,20 @@ pub mod mock {
                 Ok(response)}
             else {
                 Ok(response)}
-            });
+            })
+        // See test `uninstall_hook_refunds_proxy` where the app returns its escrow.
+        .with_uninstall(|deps, env, module| {
+            let proxy_addr = module.proxy_address(deps.as_ref())?;
+            let escrow = deps.querier.query_balance(env.contract.address, "TEST")?;
+            let mut response = ::cosmwasm_std::Response::new();
+            if !escrow.amount.is_zero() {
+                response = response.add_message(::cosmwasm_std::BankMsg::Send {
+                    to_address: proxy_addr.into_string(),
+                    amount: vec![escrow],
+                });
+            }
+            Ok(response)
+        });
 
         fn mock_instantiate(
             deps: ::cosmwasm_std::DepsMut,
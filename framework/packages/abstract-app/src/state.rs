@@ -1,5 +1,5 @@
 use abstract_sdk::{
-    base::{ModuleIbcHandlerFn, SudoHandlerFn},
+    base::{ModuleIbcHandlerFn, SudoHandlerFn, UninstallHandlerFn},
     namespaces::{ADMIN_NAMESPACE, BASE_STATE},
     AbstractSdkError,
 };
@@ -173,6 +173,15 @@ impl<
         self.contract = self.contract.with_module_ibc(module_handler);
         self
     }
+
+    /// add uninstall handler to contract, e.g. to release escrowed funds back to the Account
+    pub const fn with_uninstall(
+        mut self,
+        uninstall_handler: UninstallHandlerFn<Self, Error>,
+    ) -> Self {
+        self.contract = self.contract.with_uninstall(uninstall_handler);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -196,7 +205,8 @@ mod tests {
             .with_replies(&[(1u64, |_, _, _, msg| {
                 Ok(Response::new().set_data(msg.result.unwrap().data.unwrap()))
             })])
-            .with_migrate(|_, _, _, _| Ok(Response::new().set_data("mock_migrate".as_bytes())));
+            .with_migrate(|_, _, _, _| Ok(Response::new().set_data("mock_migrate".as_bytes())))
+            .with_uninstall(|_, _, _| Ok(Response::new().set_data("mock_uninstall".as_bytes())));
 
         assert_eq!(app.module_id(), TEST_MODULE_ID);
         assert_eq!(app.version(), TEST_VERSION);
@@ -39,10 +39,11 @@ use abstract_std::{
         AccountId, AssetEntry,
     },
     version_control::NamespaceResponse,
-    PROXY,
+    AbstractError, PROXY,
 };
 use cosmwasm_std::{to_json_binary, Attribute, Coins, CosmosMsg, Uint128};
 use cw_orch::{contract::Contract, environment::MutCwEnv, prelude::*};
+use serde::Serialize;
 
 use crate::{
     client::AbstractClientResult,
@@ -81,6 +82,7 @@ pub struct AccountBuilder<'a, Chain: CwEnv> {
     owner_account: Option<&'a Account<Chain>>,
     install_modules: Vec<ModuleInstallConfig>,
     funds: AccountCreationFunds,
+    proxy_funds: Vec<Coin>,
     fetch_if_namespace_claimed: bool,
     install_on_sub_account: bool,
     expected_local_account_id: Option<u32>,
@@ -106,6 +108,7 @@ impl<'a, Chain: CwEnv> AccountBuilder<'a, Chain> {
             owner_account: None,
             install_modules: vec![],
             funds: AccountCreationFunds::Coins(Coins::default()),
+            proxy_funds: vec![],
             fetch_if_namespace_claimed: true,
             install_on_sub_account: true,
             expected_local_account_id: None,
@@ -247,9 +250,24 @@ impl<'a, Chain: CwEnv> AccountBuilder<'a, Chain> {
         Ok(self)
     }
 
+    /// Send funds from the sender to the proxy of the account once it's created.
+    /// Unlike [`AccountBuilder::funds`], these funds are not used for the account creation.
+    /// If the account is fetched from its claimed namespace instead, that account is funded.
+    ///
+    /// The funds are sent in a separate tx after the account is created, so creation and funding are not atomic.
+    /// The build fails if the transfer fails, the account will already be created in that case.
+    /// Can't be combined with an [`AccountBuilder::ownership`] other than the sender, the build errors before creating the account.
+    pub fn with_funds(&mut self, coins: Vec<Coin>) -> &mut Self {
+        self.proxy_funds = coins;
+        self
+    }
+
     /// Assign expected local account_id on creation.
     /// It's designed to be used in pair with [`crate::AbstractClient::random_account_id`].
     /// The tx will error if this account id already claimed or it's less than 2147483648. Useful for instantiate2 address prediction.
+    ///
+    /// The account id is the instantiate2 salt of the account contracts, for sub-accounts too.
+    /// Predict the proxy address with [`crate::AbstractClient::module_instantiate2_address_raw`], e.g. to fund it before creation.
     pub fn expected_account_id(&mut self, local_account_id: u32) -> &mut Self {
         self.expected_local_account_id = Some(local_account_id);
         self
@@ -269,6 +287,7 @@ impl<'a, Chain: CwEnv> AccountBuilder<'a, Chain> {
 
                 // Only return if the account can be retrieved without errors.
                 if let Some(account_from_namespace) = account_from_namespace_result {
+                    self.fund_proxy(&account_from_namespace)?;
                     return Ok(account_from_namespace);
                 }
             }
@@ -283,7 +302,21 @@ impl<'a, Chain: CwEnv> AccountBuilder<'a, Chain> {
         let ownership = self
             .ownership
             .clone()
-            .unwrap_or(GovernanceDetails::Monarchy { monarch: sender });
+            .unwrap_or(GovernanceDetails::Monarchy {
+                monarch: sender.clone(),
+            });
+
+        // The proxy is funded through the manager, which only the owner can execute on.
+        if !self.proxy_funds.is_empty() && self.owner_account.is_none() {
+            match &ownership {
+                GovernanceDetails::Monarchy { monarch } if *monarch == sender => (),
+                _ => {
+                    return Err(AbstractClientError::FundsWithForeignOwner {
+                        owner: ownership.to_string(),
+                    })
+                }
+            }
+        }
 
         // Validate everything before sending tx
         verifiers::validate_name(&name)?;
@@ -342,8 +375,46 @@ impl<'a, Chain: CwEnv> AccountBuilder<'a, Chain> {
                 .abstr_account
                 .create_sub_account(account_details, Some(&funds))?,
         };
-        Ok(Account::new(abstract_account, self.install_on_sub_account))
+        let account = Account::new(abstract_account, self.install_on_sub_account);
+        self.fund_proxy(&account)?;
+        Ok(account)
     }
+
+    /// Send the [`AccountBuilder::with_funds`] funds to the proxy of the account, if any.
+    fn fund_proxy(&self, account: &Account<Chain>) -> AbstractClientResult<()> {
+        if !self.proxy_funds.is_empty() {
+            // Funds sent along with an empty proxy action end up on the proxy.
+            account.execute(Vec::<CosmosMsg>::new(), &self.proxy_funds)?;
+        }
+        Ok(())
+    }
+
+    /// Builds `n` [`Account`]s with the same configuration, each with its own auto-assigned id.
+    /// Errors if a namespace or an expected account id is set, as these can only be claimed once.
+    pub fn build_many(&self, n: u32) -> AbstractClientResult<Vec<Account<Chain>>> {
+        if n > 1 {
+            if self.namespace.is_some() {
+                return Err(AbstractClientError::NotRepeatable {
+                    setting: "namespace".to_owned(),
+                });
+            }
+            if self.expected_local_account_id.is_some() {
+                return Err(AbstractClientError::NotRepeatable {
+                    setting: "expected account id".to_owned(),
+                });
+            }
+        }
+        (0..n).map(|_| self.build()).collect()
+    }
+}
+
+/// Outcome of [`Account::claim_incentives`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IncentivesClaim {
+    /// Native coins credited to the proxy by the claim
+    Claimed(Vec<Coin>),
+    /// The module paid out no native coins
+    NothingToClaim,
 }
 
 /// Represents an existing Abstract account.
@@ -399,6 +470,13 @@ impl<Chain: CwEnv> Account<Chain> {
         self.abstr_account.id().map_err(Into::into)
     }
 
+    /// Portable descriptor of the Account, formatted as `<chain-id>:<account-id>`.
+    /// Use [`AbstractClient::account_from_descriptor`](crate::AbstractClient::account_from_descriptor) to get the Account back.
+    pub fn descriptor(&self) -> AbstractClientResult<String> {
+        let chain_id = self.environment().env_info().chain_id;
+        Ok(format!("{chain_id}:{}", self.id()?))
+    }
+
     /// Wether this account installs his applications on a sub account
     pub fn install_on_sub_account(&self) -> bool {
         self.install_on_sub_account
@@ -434,6 +512,9 @@ impl<Chain: CwEnv> Account<Chain> {
 
     /// Install an application on the account.
     /// if `install_on_sub_account` is `true`, the application will be installed on new a sub-account. (default)
+    ///
+    /// The returned [`Application`] already points to the address of the installed module,
+    /// so no follow-up `module_addresses` query is required to interact with it.
     pub fn install_app<M: InstallConfig + From<Contract<Chain>>>(
         &self,
         configuration: &M::InitMsg,
@@ -447,6 +528,30 @@ impl<Chain: CwEnv> Account<Chain> {
         }
     }
 
+    /// Install an application on the account and fund it with `app_funds`.
+    /// if `install_on_sub_account` is `true`, the application will be installed on new a sub-account. (default)
+    ///
+    /// `app_funds` are sent along with `funds` and passed to the app as instantiation funds,
+    /// so the app is installed and funded in a single transaction.
+    pub fn install_app_with_funds<M: InstallConfig + From<Contract<Chain>>>(
+        &self,
+        configuration: &M::InitMsg,
+        funds: &[Coin],
+        app_funds: &[Coin],
+    ) -> AbstractClientResult<Application<Chain, M>> {
+        let modules = vec![M::install_config(configuration)?.with_funds(app_funds.to_vec())];
+        let mut total_funds = Coins::try_from(funds.to_vec()).map_err(AbstractError::from)?;
+        for coin in app_funds {
+            total_funds.add(coin.clone()).map_err(AbstractError::from)?;
+        }
+        let total_funds = total_funds.into_vec();
+
+        match self.install_on_sub_account {
+            true => self.install_module_sub_internal(modules, &total_funds),
+            false => self.install_module_current_internal(modules, &total_funds),
+        }
+    }
+
     /// Install an standalone on the account.
     /// if `install_on_sub_account` is `true`, the application will be installed on new a sub-account. (default)
     pub fn install_standalone<M: InstallConfig + From<Contract<Chain>>>(
@@ -463,6 +568,8 @@ impl<Chain: CwEnv> Account<Chain> {
     }
 
     /// Install an adapter on current account.
+    ///
+    /// The returned [`Application`] already points to the address of the installed adapter.
     pub fn install_adapter<M: InstallConfig<InitMsg = Empty> + From<Contract<Chain>>>(
         &self,
         funds: &[Coin],
@@ -575,6 +682,47 @@ impl<Chain: CwEnv> Account<Chain> {
             .ok_or(AbstractClientError::RenouncedAccount {})
     }
 
+    /// Hands this sub-account over to new governance, e.g. another parent account or a timelock contract.
+    ///
+    /// The new governance is proposed, which requires the signer to be the top-level owner of this sub-account.
+    /// It's claimed in the same call if the signer can claim it: for a [`GovernanceDetails::SubAccount`] when the signer is
+    /// the top-level owner of the new parent account, otherwise when the signer is the new owner itself.
+    /// Any other new owner has to accept the ownership on the manager.
+    pub fn set_sub_account_gov(
+        &self,
+        governance: GovernanceDetails<String>,
+    ) -> AbstractClientResult<()> {
+        if !matches!(
+            self.info()?.governance_details,
+            GovernanceDetails::SubAccount { .. }
+        ) {
+            return Err(AbstractClientError::NotSubAccount {
+                account_id: self.id()?,
+            });
+        }
+        let sender = self.environment().sender().to_string();
+        let signer_can_claim = match &governance {
+            GovernanceDetails::SubAccount { .. } => true,
+            GovernanceDetails::Monarchy { monarch } => *monarch == sender,
+            GovernanceDetails::External {
+                governance_address, ..
+            } => *governance_address == sender,
+            GovernanceDetails::Renounced {} => false,
+        };
+
+        self.execute_on_manager(
+            &manager::ExecuteMsg::ProposeOwner { owner: governance },
+            &[],
+        )?;
+        if signer_can_claim {
+            self.execute_on_manager(
+                &manager::ExecuteMsg::UpdateOwnership(cw_ownable::Action::AcceptOwnership),
+                &[],
+            )?;
+        }
+        Ok(())
+    }
+
     /// Executes a [`CosmosMsg`] on the proxy of the account.
     pub fn execute(
         &self,
@@ -604,6 +752,54 @@ impl<Chain: CwEnv> Account<Chain> {
             .map_err(Into::into)
     }
 
+    /// Claims the incentives held by an installed module by executing `claim_msg` on it through the manager.
+    /// The module is expected to pay out the claimed incentives to the proxy.
+    ///
+    /// Returns the coins credited to the proxy by the claim, or [`IncentivesClaim::NothingToClaim`] when its balances didn't grow.
+    ///
+    /// Only native balances are compared. CW20 rewards still reach the proxy but aren't reported,
+    /// so a claim that only pays out CW20 tokens returns [`IncentivesClaim::NothingToClaim`].
+    /// Query the token contracts directly to track those.
+    pub fn claim_incentives(
+        &self,
+        incentive_module_id: &str,
+        claim_msg: &impl Serialize,
+    ) -> AbstractClientResult<IncentivesClaim> {
+        let modules = self
+            .module_addresses(vec![incentive_module_id.to_owned()])?
+            .modules;
+        if modules.is_empty() {
+            return Err(AbstractClientError::ModuleNotInstalled {});
+        }
+
+        let before = self.query_balances()?;
+        self.execute_on_manager(
+            &manager::ExecuteMsg::ExecOnModule {
+                module_id: incentive_module_id.to_owned(),
+                exec_msg: to_json_binary(claim_msg).map_err(AbstractInterfaceError::from)?,
+            },
+            &[],
+        )?;
+        let after = self.query_balances()?;
+
+        let claimed: Vec<Coin> = after
+            .into_iter()
+            .filter_map(|coin| {
+                let previous = before
+                    .iter()
+                    .find(|c| c.denom == coin.denom)
+                    .map(|c| c.amount)
+                    .unwrap_or_default();
+                (coin.amount > previous)
+                    .then(|| Coin::new((coin.amount - previous).u128(), coin.denom))
+            })
+            .collect();
+        if claimed.is_empty() {
+            return Ok(IncentivesClaim::NothingToClaim);
+        }
+        Ok(IncentivesClaim::Claimed(claimed))
+    }
+
     /// Set IBC status on an Account.
     pub fn set_ibc_status(&self, enabled: bool) -> AbstractClientResult<Chain::Response> {
         self.abstr_account
@@ -142,7 +142,7 @@ impl<Chain: CwEnv> AbstractClientBuilder<Chain> {
             .update_contract_addresses(self.contracts.clone(), vec![])?;
         abstr
             .ans_host
-            .update_asset_addresses(self.assets.clone(), vec![])?;
+            .update_asset_addresses(false, self.assets.clone(), vec![])?;
         abstr
             .ans_host
             .update_channels(self.channels.clone(), vec![])?;
@@ -29,19 +29,23 @@
 //! ```
 
 use abstract_interface::{
-    Abstract, AbstractAccount, AnsHost, IbcClient, ManagerQueryFns, RegisteredModule, VCQueryFns,
-    VersionControl,
+    Abstract, AbstractAccount, AnsHost, IbcClient, IbcHost, ManagerQueryFns, RegisteredModule,
+    VCQueryFns, VersionControl,
 };
 use abstract_std::objects::{
+    account::AccountTrace,
+    common_namespace::ADMIN_NAMESPACE,
     module::{ModuleInfo, ModuleVersion},
     module_reference::ModuleReference,
     namespace::Namespace,
     salt::generate_instantiate_salt,
     AccountId,
 };
-use cosmwasm_std::{BlockInfo, Uint128};
+use cosmwasm_std::{from_json, BlockInfo, Uint128};
+use cw2::ContractVersion;
 use cw_orch::prelude::*;
 use rand::Rng;
+use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
     account::{Account, AccountBuilder},
@@ -57,6 +61,23 @@ pub struct AbstractClient<Chain: CwEnv> {
 /// The result type for the Abstract Client.
 pub type AbstractClientResult<T> = Result<T, AbstractClientError>;
 
+/// The `cw2` versions of the deployed Abstract core contracts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeployedVersions {
+    pub version_control: ContractVersion,
+    pub ans_host: ContractVersion,
+    pub account_factory: ContractVersion,
+    pub module_factory: ContractVersion,
+    /// Version of the manager of the root Abstract Account
+    pub manager: ContractVersion,
+    /// Version of the proxy of the root Abstract Account
+    pub proxy: ContractVersion,
+    /// `None` if the IBC client is not deployed
+    pub ibc_client: Option<ContractVersion>,
+    /// `None` if the IBC host is not deployed
+    pub ibc_host: Option<ContractVersion>,
+}
+
 impl<Chain: CwEnv> AbstractClient<Chain> {
     /// Get [`AbstractClient`] from a chosen environment. [`Abstract`] should
     /// already be deployed to this environment.
@@ -130,6 +151,56 @@ impl<Chain: CwEnv> AbstractClient<Chain> {
         &self.abstr.ibc.client
     }
 
+    /// Abstract Ibc Host contract API
+    ///
+    /// The Abstract Ibc Host contract executes actions on this chain on behalf of Interchain Abstract Accounts
+    pub fn ibc_host(&self) -> &IbcHost<Chain> {
+        &self.abstr.ibc.host
+    }
+
+    /// Query the `cw2` versions of the deployed Abstract core contracts.
+    /// The manager and proxy versions are the ones of the root Abstract Account.
+    /// IBC contracts that aren't deployed are reported as `None`.
+    pub fn deployed_versions(&self) -> AbstractClientResult<DeployedVersions> {
+        let root_account = AbstractAccount::new(&self.abstr, AccountId::local(0));
+
+        Ok(DeployedVersions {
+            version_control: self.contract_version(&self.abstr.version_control.address()?)?,
+            ans_host: self.contract_version(&self.abstr.ans_host.address()?)?,
+            account_factory: self.contract_version(&self.abstr.account_factory.address()?)?,
+            module_factory: self.contract_version(&self.abstr.module_factory.address()?)?,
+            manager: self.contract_version(&root_account.manager.address()?)?,
+            proxy: self.contract_version(&root_account.proxy.address()?)?,
+            ibc_client: self
+                .abstr
+                .ibc
+                .client
+                .address()
+                .ok()
+                .map(|addr| self.contract_version(&addr))
+                .transpose()?,
+            ibc_host: self
+                .abstr
+                .ibc
+                .host
+                .address()
+                .ok()
+                .map(|addr| self.contract_version(&addr))
+                .transpose()?,
+        })
+    }
+
+    fn contract_version(&self, address: &Addr) -> AbstractClientResult<ContractVersion> {
+        let raw_version = self
+            .environment()
+            .wasm_querier()
+            .raw_query(address.to_string(), cw2::CONTRACT.as_slice().to_vec())
+            .map_err(Into::into)?;
+        from_json(raw_version)
+            .map_err(abstract_std::AbstractError::from)
+            .map_err(Into::into)
+    }
+
     /// Return current block info see [`BlockInfo`].
     pub fn block_info(&self) -> AbstractClientResult<BlockInfo> {
         self.environment()
@@ -161,6 +232,8 @@ impl<Chain: CwEnv> AbstractClient<Chain> {
     /// - [`Namespace`]: Will retrieve the account from the namespace if it is already claimed.
     /// - [`AccountId`]: Will retrieve the account from the account id.
     /// - App [`Addr`]: Will retrieve the account from an app that is installed on it.
+    /// - Proxy [`Addr`]: Will retrieve the account from its proxy.
+    /// - Adapter and proxy [`Addr`]: Will retrieve the account from its proxy, after checking the adapter is installed on it.
     pub fn account_from<T: Into<AccountSource>>(
         &self,
         source: T,
@@ -191,14 +264,7 @@ impl<Chain: CwEnv> AbstractClient<Chain> {
             }
             AccountSource::App(app) => {
                 // Query app for manager address and get AccountId from it.
-                let app_config: abstract_std::app::AppConfigResponse = chain
-                    .query(
-                        &abstract_std::app::QueryMsg::<Empty>::Base(
-                            abstract_std::app::BaseQueryMsg::BaseConfig {},
-                        ),
-                        &app,
-                    )
-                    .map_err(Into::into)?;
+                let app_config = abstract_interface::query_app_base_config(&chain, &app)?;
 
                 let manager_config: abstract_std::manager::ConfigResponse = chain
                     .query(
@@ -211,7 +277,97 @@ impl<Chain: CwEnv> AbstractClient<Chain> {
                     AbstractAccount::new(&self.abstr, manager_config.account_id);
                 Ok(Account::new(abstract_account, true))
             }
+            AccountSource::Proxy(proxy) => {
+                let not_a_proxy = || AbstractClientError::NotAProxy {
+                    address: proxy.clone(),
+                };
+                // The manager is the admin of the proxy.
+                let raw_admin = chain
+                    .wasm_querier()
+                    .raw_query(proxy.to_string(), ADMIN_NAMESPACE.as_bytes().to_vec())
+                    .map_err(|_| not_a_proxy())?;
+                let manager_address: Option<Addr> =
+                    from_json(raw_admin).map_err(|_| not_a_proxy())?;
+                let manager_address = manager_address.ok_or_else(not_a_proxy)?;
+
+                let manager_config: abstract_std::manager::ConfigResponse = chain
+                    .query(
+                        &abstract_std::manager::QueryMsg::Config {},
+                        &manager_address,
+                    )
+                    .map_err(|_| not_a_proxy())?;
+                let abstract_account: AbstractAccount<Chain> =
+                    AbstractAccount::new(&self.abstr, manager_config.account_id);
+                // Make sure the address is the proxy of this account.
+                if abstract_account.proxy.address()? != proxy {
+                    return Err(not_a_proxy());
+                }
+                Ok(Account::new(abstract_account, true))
+            }
+            AccountSource::Adapter { adapter, proxy } => {
+                let account = self.account_from(AccountSource::Proxy(proxy))?;
+                let account_id = account.id()?;
+                let not_installed = || AbstractClientError::AdapterNotInstalled {
+                    adapter: adapter.clone(),
+                    account_id: account_id.clone(),
+                };
+                // Adapters aren't account-scoped, so make sure it's installed on the account.
+                let adapter_version = self
+                    .contract_version(&adapter)
+                    .map_err(|_| not_installed())?;
+                let installed = account.module_addresses(vec![adapter_version.contract])?;
+                if installed.modules.first().map(|(_, addr)| addr) != Some(&adapter) {
+                    return Err(not_installed());
+                }
+                Ok(account)
+            }
+        }
+    }
+
+    /// Fetch an [`Account`] from its [`descriptor`](Account::descriptor).
+    /// Errors if the descriptor is malformed or refers to an Account on another chain.
+    pub fn account_from_descriptor(
+        &self,
+        descriptor: &str,
+    ) -> AbstractClientResult<Account<Chain>> {
+        let invalid_descriptor = || AbstractClientError::InvalidAccountDescriptor {
+            descriptor: descriptor.to_owned(),
+        };
+        let (descriptor_chain_id, account_id) =
+            descriptor.split_once(':').ok_or_else(invalid_descriptor)?;
+        let (trace, seq) = account_id.rsplit_once('-').ok_or_else(invalid_descriptor)?;
+        let seq = seq.parse().map_err(|_| invalid_descriptor())?;
+        let trace = AccountTrace::try_from(trace).map_err(|_| invalid_descriptor())?;
+        let account_id = AccountId::new(seq, trace).map_err(|_| invalid_descriptor())?;
+
+        let chain_id = self.environment().env_info().chain_id;
+        if descriptor_chain_id != chain_id {
+            return Err(AbstractClientError::AccountDescriptorChainMismatch {
+                descriptor_chain_id: descriptor_chain_id.to_owned(),
+                chain_id,
+            });
         }
+        self.account_from(account_id)
+    }
+
+    /// Query a module installed on `account` by its id.
+    /// `msg` is sent as-is, so it must include any wrapping the module expects (e.g. `QueryMsg::Module`).
+    pub fn query_module<M: Serialize, R: DeserializeOwned>(
+        &self,
+        account: &Account<Chain>,
+        module_id: &str,
+        msg: &M,
+    ) -> AbstractClientResult<R> {
+        let module_addresses = account.module_addresses(vec![module_id.to_owned()])?;
+        let (_, address) = module_addresses
+            .modules
+            .into_iter()
+            .next()
+            .ok_or(AbstractClientError::ModuleNotInstalled {})?;
+        self.environment()
+            .wasm_querier()
+            .smart_query(address, msg)
+            .map_err(|e| AbstractClientError::CwOrch(e.into()))
     }
 
     /// Retrieve denom balance for provided address
@@ -238,6 +394,26 @@ impl<Chain: CwEnv> AbstractClient<Chain> {
             .map_err(Into::into)
     }
 
+    /// Retrieve balances of all denoms for each of the provided addresses.
+    /// Stops at the first failing query and returns an error that includes its address.
+    pub fn query_balances_many(
+        &self,
+        addresses: &[Addr],
+    ) -> AbstractClientResult<Vec<(Addr, Vec<Coin>)>> {
+        addresses
+            .iter()
+            .map(|address| {
+                let balances = self.query_balances(address).map_err(|error| {
+                    AbstractClientError::BalanceQueryFailed {
+                        address: address.clone(),
+                        error: Box::new(error),
+                    }
+                })?;
+                Ok((address.clone(), balances))
+            })
+            .collect()
+    }
+
     /// Waits for a specified number of blocks.
     pub fn wait_blocks(&self, amount: u64) -> AbstractClientResult<()> {
         self.environment()
@@ -254,6 +430,26 @@ impl<Chain: CwEnv> AbstractClient<Chain> {
             .map_err(Into::into)
     }
 
+    /// Waits until the chain reaches block `target`.
+    /// Returns immediately if the chain is already at or past it.
+    pub fn wait_until_block(&self, target: u64) -> AbstractClientResult<()> {
+        let height = self.block_info()?.height;
+        if height >= target {
+            return Ok(());
+        }
+        self.wait_blocks(target - height)
+    }
+
+    /// Waits until the chain time reaches the unix timestamp `target_unix`, in seconds.
+    /// Returns immediately if the chain is already at or past it.
+    pub fn wait_until_seconds(&self, target_unix: u64) -> AbstractClientResult<()> {
+        let seconds = self.block_info()?.time.seconds();
+        if seconds >= target_unix {
+            return Ok(());
+        }
+        self.wait_seconds(target_unix - seconds)
+    }
+
     /// Waits for next block.
     pub fn next_block(&self) -> AbstractClientResult<()> {
         self.environment()
@@ -266,29 +462,30 @@ impl<Chain: CwEnv> AbstractClient<Chain> {
     /// Returns `None` if no account has been created yet.
     /// **Note**: This only returns accounts that were created with the Client. Any accounts created through the web-app will not be returned.
     pub fn get_last_account(&self) -> AbstractClientResult<Option<Account<Chain>>> {
+        let mut accounts = self.accounts_owned_by(&self.environment().sender())?;
+        Ok(accounts.pop())
+    }
+
+    /// Retrieve all the local accounts owned by `owner`, sorted by account sequence.
+    /// **Note**: This only returns accounts that were created with the Client. Any accounts created through the web-app will not be returned.
+    pub fn accounts_owned_by(&self, owner: &Addr) -> AbstractClientResult<Vec<Account<Chain>>> {
         let addresses = self.environment().state().get_all_addresses()?;
-        // Now search for all the keys that start with "abstract:manager-x" and return the one which has the highest x.
-        let mut last_account: Option<(u32, Account<Chain>)> = None;
+        // Search for all the keys that start with "abstract:manager-x" and keep the ones owned by `owner`.
+        let mut accounts: Vec<(u32, Account<Chain>)> = vec![];
         for id in addresses.keys() {
             let Some(account_id) = is_local_manager(id.as_str())? else {
                 continue;
             };
 
-            // only take accounts that the current sender owns
             let account = AbstractAccount::new(&self.abstr, account_id.clone());
-            if account.manager.ownership()?.owner != Some(self.environment().sender().to_string()) {
+            if account.manager.ownership()?.owner != Some(owner.to_string()) {
                 continue;
             }
 
-            if let Some((last_account_id, _)) = last_account {
-                if account_id.seq() > last_account_id {
-                    last_account = Some((account_id.seq(), Account::new(account, true)));
-                }
-            } else {
-                last_account = Some((account_id.seq(), Account::new(account, true)));
-            }
+            accounts.push((account_id.seq(), Account::new(account, true)));
         }
-        Ok(last_account.map(|(_, account)| account))
+        accounts.sort_by_key(|(seq, _)| *seq);
+        Ok(accounts.into_iter().map(|(_, account)| account).collect())
     }
 
     /// Get random local account id sequence(unclaimed) in 2147483648..u32::MAX range
@@ -438,4 +635,33 @@ mod tests {
 
         assert_eq!(acc_2.id().unwrap(), last_account.id().unwrap());
     }
+
+    #[test]
+    fn accounts_owned_by_address() {
+        let chain = MockBech32::new("mock");
+        let sender = chain.sender();
+        Abstract::deploy_on(chain.clone(), sender.to_string()).unwrap();
+
+        let client = AbstractClient::new(chain.clone()).unwrap();
+        let other_owner = chain.addr_make("other_owner");
+        let owned_by_other = |client: &AbstractClient<MockBech32>| {
+            client
+                .account_builder()
+                .ownership(
+                    abstract_std::objects::gov_type::GovernanceDetails::Monarchy {
+                        monarch: other_owner.to_string(),
+                    },
+                )
+                .build()
+                .unwrap()
+        };
+        let acc_1 = owned_by_other(&client);
+        let _sender_acc = client.account_builder().build().unwrap();
+        let acc_2 = owned_by_other(&client);
+
+        let accounts = client.accounts_owned_by(&other_owner).unwrap();
+
+        let ids: Vec<AccountId> = accounts.iter().map(|acc| acc.id().unwrap()).collect();
+        assert_eq!(ids, vec![acc_1.id().unwrap(), acc_2.id().unwrap()]);
+    }
 }
@@ -38,6 +38,49 @@ pub enum AbstractClientError {
     #[error("Account creation auto_fund assertion failed with required funds: {0:?}")]
     AutoFundsAssertFailed(Vec<cosmwasm_std::Coin>),
 
+    #[error("Account {account_id} is not a sub-account.")]
+    NotSubAccount {
+        account_id: abstract_std::objects::AccountId,
+    },
+
+    #[error("Adapter {adapter} is not installed on Account {account_id}.")]
+    AdapterNotInstalled {
+        adapter: cosmwasm_std::Addr,
+        account_id: abstract_std::objects::AccountId,
+    },
+
+    #[error("Address {address} is not the proxy of an Abstract Account.")]
+    NotAProxy { address: cosmwasm_std::Addr },
+
+    #[error("Invalid account descriptor \"{descriptor}\", expected \"<chain-id>:<account-id>\".")]
+    InvalidAccountDescriptor { descriptor: String },
+
+    #[error(
+        "Account descriptor is for chain {descriptor_chain_id}, but the client is on {chain_id}."
+    )]
+    AccountDescriptorChainMismatch {
+        descriptor_chain_id: String,
+        chain_id: String,
+    },
+
+    #[error("Module versions must be strictly increasing, got {next} after {previous}.")]
+    UnorderedModuleVersions {
+        previous: semver::Version,
+        next: semver::Version,
+    },
+
+    #[error("Can't fund the proxy with `with_funds` when the account is owned by {owner}, only the creator can send funds to a new proxy.")]
+    FundsWithForeignOwner { owner: String },
+
+    #[error("Can't build multiple accounts with a {setting}, it can only be claimed once.")]
+    NotRepeatable { setting: String },
+
+    #[error("Balance query for {address} failed: {error}")]
+    BalanceQueryFailed {
+        address: cosmwasm_std::Addr,
+        error: Box<AbstractClientError>,
+    },
+
     #[cfg(feature = "interchain")]
     #[error("Remote account of {account_id} not found on {chain} in {ibc_client_addr}")]
     RemoteAccountNotFound {
@@ -382,6 +382,7 @@ impl<'a, Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>> RemoteAccount<'a, Ch
             action: ibc_host::HostAction::Dispatch {
                 manager_msgs: vec![manager::ExecuteMsg::Upgrade { modules }],
             },
+            timeout_seconds: None,
         })
     }
 
@@ -446,6 +447,7 @@ impl<'a, Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>> RemoteAccount<'a, Ch
         self.ibc_client_execute(ibc_client::ExecuteMsg::RemoteAction {
             host_chain: self.host_chain(),
             action: ibc_host::HostAction::Dispatch { manager_msgs },
+            timeout_seconds: None,
         })
     }
 
@@ -520,6 +522,7 @@ impl<'a, Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>> RemoteAccount<'a, Ch
             action: ibc_host::HostAction::Dispatch {
                 manager_msgs: vec![manager::ExecuteMsg::InstallModules { modules }],
             },
+            timeout_seconds: None,
         })?;
 
         let module = self.module()?;
@@ -55,6 +55,7 @@ impl<
                         exec_msg: to_json_binary(execute).map_err(AbstractInterfaceError::from)?,
                     }],
                 },
+                timeout_seconds: None,
             })
     }
 
@@ -105,6 +106,7 @@ impl<'a, Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>, M: ContractInstance<
             .ibc_client_execute(ibc_client::ExecuteMsg::RemoteAction {
                 host_chain: self.remote_account.host_chain(),
                 action: ibc_host::HostAction::Dispatch { manager_msgs },
+                timeout_seconds: None,
             })?;
         Ok(())
     }
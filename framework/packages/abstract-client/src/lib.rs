@@ -16,10 +16,10 @@ pub(crate) mod source;
 pub use abstract_std::objects::{gov_type::GovernanceDetails, namespace::Namespace};
 // Re-export `ClientResolve` trait
 pub use abstract_interface::ClientResolve;
-pub use account::{Account, AccountBuilder};
+pub use account::{Account, AccountBuilder, IncentivesClaim};
 pub use application::Application;
 pub use builder::AbstractClientBuilder;
-pub use client::AbstractClient;
+pub use client::{AbstractClient, DeployedVersions};
 pub use error::AbstractClientError;
 pub use infrastructure::Environment;
 pub use publisher::{Publisher, PublisherBuilder};
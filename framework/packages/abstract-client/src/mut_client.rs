@@ -26,13 +26,13 @@ impl<Chain: MutCwEnv> AbstractClient<Chain> {
     }
 
     /// Set on chain balance of addresses
-    pub fn set_balances<'a>(
+    pub fn set_balances(
         &self,
-        balances: impl IntoIterator<Item = (impl Into<String>, &'a [Coin])>,
+        balances: impl IntoIterator<Item = (impl Into<String>, impl AsRef<[Coin]>)>,
     ) -> AbstractClientResult<()> {
         balances
             .into_iter()
-            .try_for_each(|(address, amount)| self.set_balance(address, amount))?;
+            .try_for_each(|(address, amount)| self.set_balance(address, amount.as_ref()))?;
         Ok(())
     }
 
@@ -49,12 +49,12 @@ impl<Chain: MutCwEnv> AbstractClient<Chain> {
     }
 
     /// Add balance for the addresses
-    pub fn add_balances<'a>(
+    pub fn add_balances(
         &self,
-        balances: impl IntoIterator<Item = (impl Into<String>, &'a [Coin])>,
+        balances: impl IntoIterator<Item = (impl Into<String>, impl AsRef<[Coin]>)>,
     ) -> AbstractClientResult<()> {
         balances
             .into_iter()
-            .try_for_each(|(address, amount)| self.add_balance(address, amount))
+            .try_for_each(|(address, amount)| self.add_balance(address, amount.as_ref()))
     }
 }
@@ -3,19 +3,26 @@
 //! [`Publisher`] is an Account with helpers for publishing and maintaining Abstract Applications and Adapters
 
 use abstract_interface::{
-    AdapterDeployer, AppDeployer, DeployStrategy, RegisteredModule, StandaloneDeployer,
+    AdapterDeployer, AppDeployer, DeployStrategy, InstallConfig, RegisteredModule,
+    StandaloneDeployer,
+};
+use abstract_std::objects::{
+    gov_type::GovernanceDetails,
+    module::{ModuleInfo, ModuleVersion},
+    namespace::Namespace,
+    AssetEntry,
 };
-use abstract_std::objects::{gov_type::GovernanceDetails, namespace::Namespace, AssetEntry};
 use cw_orch::{
     contract::Contract,
     prelude::{ContractInstance, CwEnv},
 };
+use semver::Version;
 use serde::Serialize;
 
 use crate::{
     account::{Account, AccountBuilder},
     client::AbstractClientResult,
-    Environment,
+    AbstractClientError, Environment,
 };
 
 /// A builder for creating [`Publishers`](Account).
@@ -123,6 +130,52 @@ impl<Chain: CwEnv> Publisher<Chain> {
             .map_err(Into::into)
     }
 
+    /// Publish an Abstract App and install it on the publisher's account.
+    /// If `install_on_sub_account` is `true` (default), the app is installed on a new sub-account.
+    /// Returns the [`Account`] the app got installed on.
+    pub fn publish_and_install_app<
+        M: ContractInstance<Chain>
+            + RegisteredModule
+            + From<Contract<Chain>>
+            + AppDeployer<Chain>
+            + InstallConfig,
+    >(
+        &self,
+        configuration: &M::InitMsg,
+    ) -> AbstractClientResult<Account<Chain>> {
+        self.publish_app::<M>()?;
+        let app = self.account.install_app::<M>(configuration, &[])?;
+        Ok(app.account().clone())
+    }
+
+    /// Publish multiple versions of an Abstract App, in order.
+    /// Versions must be strictly increasing. Already registered versions are skipped.
+    /// Returns the [`ModuleInfo`] of every published version.
+    pub fn publish_app_versions<
+        M: ContractInstance<Chain> + RegisteredModule + From<Contract<Chain>> + AppDeployer<Chain>,
+    >(
+        &self,
+        versions: Vec<Version>,
+    ) -> AbstractClientResult<Vec<ModuleInfo>> {
+        if let Some(pair) = versions.windows(2).find(|pair| pair[0] >= pair[1]) {
+            return Err(AbstractClientError::UnorderedModuleVersions {
+                previous: pair[0].clone(),
+                next: pair[1].clone(),
+            });
+        }
+
+        let contract = Contract::new(M::module_id().to_owned(), self.account.environment());
+        let app: M = contract.into();
+        versions
+            .into_iter()
+            .map(|version| {
+                app.deploy(version.clone(), DeployStrategy::Try)?;
+                ModuleInfo::from_id(M::module_id(), ModuleVersion::from(version.to_string()))
+                    .map_err(Into::into)
+            })
+            .collect()
+    }
+
     /// Publish an Abstract Standalone
     pub fn publish_standalone<
         M: ContractInstance<Chain>
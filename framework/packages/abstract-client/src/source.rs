@@ -9,6 +9,11 @@ pub enum AccountSource {
     AccountId(AccountId),
     /// Get the account from the address of an installed App.
     App(Addr),
+    /// Get the account from the address of its proxy.
+    Proxy(Addr),
+    /// Get the account from the address of an installed Adapter.
+    /// Adapters serve many accounts, so the proxy of the account is required to resolve it.
+    Adapter { adapter: Addr, proxy: Addr },
 }
 
 impl From<Namespace> for AccountSource {
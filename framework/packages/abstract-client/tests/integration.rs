@@ -21,13 +21,14 @@ use abstract_std::{
     adapter::AuthorizedAddressesResponse,
     ans_host::QueryMsgFns,
     manager::{
-        state::AccountInfo, ManagerModuleInfo, ModuleAddressesResponse, ModuleInfosResponse,
+        self, state::AccountInfo, ManagerModuleInfo, ModuleAddressesResponse, ModuleInfosResponse,
     },
     objects::{
         dependency::Dependency, fee::FixedFee, gov_type::GovernanceDetails,
-        module_version::ModuleDataResponse, namespace::Namespace, AccountId, AssetEntry,
+        module_reference::ModuleReference, module_version::ModuleDataResponse,
+        namespace::Namespace, AccountId, AssetEntry,
     },
-    IBC_CLIENT,
+    IBC_CLIENT, IBC_HOST,
 };
 use abstract_testing::{
     addresses::{TEST_MODULE_NAME, TTOKEN},
@@ -73,6 +74,29 @@ fn can_create_account_without_optional_parameters() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn can_build_many_accounts() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    let client = AbstractClient::builder(chain).build()?;
+
+    let accounts = client.account_builder().build_many(5)?;
+
+    let seqs = accounts
+        .iter()
+        .map(|account| Ok(account.id()?.seq()))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let first = seqs[0];
+    assert_eq!(seqs, (first..first + 5).collect::<Vec<_>>());
+
+    let err = client
+        .account_builder()
+        .namespace(Namespace::new("many")?)
+        .build_many(2)
+        .unwrap_err();
+    assert!(matches!(err, AbstractClientError::NotRepeatable { .. }));
+    Ok(())
+}
+
 #[test]
 fn can_create_account_with_optional_parameters() -> anyhow::Result<()> {
     let asset = "asset";
@@ -253,6 +277,116 @@ fn can_get_publisher_from_namespace() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn can_publish_multiple_app_versions() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    let client = AbstractClient::builder(chain).build()?;
+
+    let publisher: Publisher<MockBech32> = client
+        .publisher_builder(Namespace::new(TEST_NAMESPACE)?)
+        .build()?;
+
+    let versions: Vec<semver::Version> = vec!["1.0.0".parse()?, "1.1.0".parse()?];
+    let published = publisher.publish_app_versions::<MockAppI<MockBech32>>(versions)?;
+    assert_eq!(
+        published,
+        vec![
+            ModuleInfo::from_id(TEST_MODULE_ID, "1.0.0".into())?,
+            ModuleInfo::from_id(TEST_MODULE_ID, "1.1.0".into())?,
+        ]
+    );
+
+    let registered: Vec<ModuleInfo> = client
+        .version_control()
+        .module_list(
+            Some(abstract_std::version_control::ModuleFilter {
+                namespace: Some(TEST_NAMESPACE.to_owned()),
+                name: Some(TEST_MODULE_NAME.to_owned()),
+                version: None,
+                status: None,
+            }),
+            None,
+            None,
+        )?
+        .modules
+        .into_iter()
+        .map(|module| module.module.info)
+        .collect();
+    assert_eq!(registered, published);
+
+    // Latest version is the last published one
+    let latest = client
+        .version_control()
+        .module(ModuleInfo::from_id_latest(TEST_MODULE_ID)?)?;
+    assert_eq!(latest.info, published[1]);
+
+    // Duplicate and out-of-order versions are rejected
+    let err = publisher
+        .publish_app_versions::<MockAppI<MockBech32>>(vec!["1.1.0".parse()?, "1.1.0".parse()?])
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        AbstractClientError::UnorderedModuleVersions { .. }
+    ));
+    let err = publisher
+        .publish_app_versions::<MockAppI<MockBech32>>(vec!["1.1.0".parse()?, "1.0.0".parse()?])
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        AbstractClientError::UnorderedModuleVersions { .. }
+    ));
+    Ok(())
+}
+
+#[test]
+fn can_publish_and_install_app_in_one_call() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    let client = AbstractClient::builder(chain).build()?;
+
+    let publisher: Publisher<MockBech32> = client
+        .publisher_builder(Namespace::new(TEST_NAMESPACE)?)
+        .build()?;
+
+    let account = publisher.publish_and_install_app::<MockAppI<MockBech32>>(&MockInitMsg {})?;
+
+    // App got installed on a new sub-account of the publisher
+    let sub_accounts = publisher.account().sub_accounts()?;
+    assert_eq!(sub_accounts.len(), 1);
+    assert_eq!(sub_accounts[0].id()?, account.id()?);
+
+    let my_app: Application<_, MockAppI<_>> = account.application()?;
+    let something = my_app.get_something()?;
+    assert_eq!(MockQueryResponse {}, something);
+    Ok(())
+}
+
+#[test]
+fn can_query_module_by_id() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    let client = AbstractClient::builder(chain).build()?;
+
+    let publisher: Publisher<MockBech32> = client
+        .publisher_builder(Namespace::new(TEST_NAMESPACE)?)
+        .install_on_sub_account(false)
+        .build()?;
+    publisher.publish_app::<MockAppI<MockBech32>>()?;
+    let _: Application<_, MockAppI<_>> = publisher.account().install_app(&MockInitMsg {}, &[])?;
+
+    let msg: abstract_app::mock::QueryMsg =
+        abstract_app::mock::MockQueryMsg::GetSomething {}.into();
+    let response: MockQueryResponse =
+        client.query_module(publisher.account(), TEST_MODULE_ID, &msg)?;
+    assert_eq!(MockQueryResponse {}, response);
+
+    // Base queries go through the same path
+    let base_msg: abstract_app::mock::QueryMsg =
+        abstract_std::app::BaseQueryMsg::BaseConfig {}.into();
+    let config: abstract_std::app::AppConfigResponse =
+        client.query_module(publisher.account(), TEST_MODULE_ID, &base_msg)?;
+    assert_eq!(config.proxy_address, publisher.account().proxy()?);
+    Ok(())
+}
+
 #[test]
 fn can_publish_and_install_app() -> anyhow::Result<()> {
     let chain = MockBech32::new("mock");
@@ -401,6 +535,257 @@ fn can_publish_and_install_adapter() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn installed_app_address_matches_manager() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    let client = AbstractClient::builder(chain).build()?;
+
+    let publisher: Publisher<MockBech32> = client
+        .publisher_builder(Namespace::new(TEST_NAMESPACE)?)
+        .build()?;
+    publisher.publish_app::<MockAppI<MockBech32>>()?;
+
+    // Installed on a sub-account
+    let app: Application<_, MockAppI<_>> = publisher.account().install_app(&MockInitMsg {}, &[])?;
+    let module_addrs = app
+        .account()
+        .module_addresses(vec![TEST_MODULE_ID.to_owned()])?
+        .modules;
+    assert_eq!(
+        module_addrs,
+        vec![(TEST_MODULE_ID.to_owned(), app.address()?)]
+    );
+
+    // Installed on the current account
+    let account = client
+        .account_builder()
+        .install_on_sub_account(false)
+        .build()?;
+    let app: Application<_, MockAppI<_>> = account.install_app(&MockInitMsg {}, &[])?;
+    let module_addrs = account
+        .module_addresses(vec![TEST_MODULE_ID.to_owned()])?
+        .modules;
+    assert_eq!(
+        module_addrs,
+        vec![(TEST_MODULE_ID.to_owned(), app.address()?)]
+    );
+    Ok(())
+}
+
+#[test]
+fn install_app_with_funds_funds_the_app() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    let client = AbstractClient::builder(chain).build()?;
+    let denom = "denom1";
+    client.set_balance(client.sender(), &coins(100, denom))?;
+
+    let publisher: Publisher<MockBech32> = client
+        .publisher_builder(Namespace::new(TEST_NAMESPACE)?)
+        .build()?;
+    publisher.publish_app::<MockAppI<MockBech32>>()?;
+
+    let app: Application<_, MockAppI<_>> =
+        publisher
+            .account()
+            .install_app_with_funds(&MockInitMsg {}, &[], &coins(40, denom))?;
+
+    // Funds went from the sender to the app on instantiation, nothing is left on the proxy
+    assert_eq!(
+        client.query_balance(&app.address()?, denom)?,
+        Uint128::new(40)
+    );
+    assert_eq!(app.account().query_balance(denom)?, Uint128::zero());
+    assert_eq!(
+        client.query_balance(&client.sender(), denom)?,
+        Uint128::new(60)
+    );
+    Ok(())
+}
+
+#[test]
+fn install_app_with_funds_on_current_account() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    let client = AbstractClient::builder(chain).build()?;
+    let denom = "denom1";
+    client.set_balance(client.sender(), &coins(100, denom))?;
+
+    let publisher: Publisher<MockBech32> = client
+        .publisher_builder(Namespace::new(TEST_NAMESPACE)?)
+        .install_on_sub_account(false)
+        .build()?;
+    publisher.publish_app::<MockAppI<MockBech32>>()?;
+
+    let app: Application<_, MockAppI<_>> =
+        publisher
+            .account()
+            .install_app_with_funds(&MockInitMsg {}, &[], &coins(40, denom))?;
+
+    assert_eq!(app.account().id()?, publisher.account().id()?);
+    assert_eq!(
+        client.query_balance(&app.address()?, denom)?,
+        Uint128::new(40)
+    );
+    assert_eq!(publisher.account().query_balance(denom)?, Uint128::zero());
+    assert_eq!(
+        client.query_balance(&client.sender(), denom)?,
+        Uint128::new(60)
+    );
+    Ok(())
+}
+
+#[test]
+fn can_move_sub_account_to_other_parent() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    let client = AbstractClient::builder(chain).build()?;
+
+    let parent: Account<MockBech32> = client.account_builder().build()?;
+    let new_parent: Account<MockBech32> = client.account_builder().build()?;
+    let sub_account: Account<MockBech32> = client.account_builder().sub_account(&parent).build()?;
+
+    let new_gov = GovernanceDetails::SubAccount {
+        manager: new_parent.manager()?.to_string(),
+        proxy: new_parent.proxy()?.to_string(),
+    };
+    sub_account.set_sub_account_gov(new_gov.clone())?;
+
+    assert_eq!(
+        sub_account.info()?.governance_details,
+        GovernanceDetails::SubAccount {
+            manager: new_parent.manager()?,
+            proxy: new_parent.proxy()?,
+        }
+    );
+    // Parent-child relationship moved to the new parent
+    assert!(parent.sub_accounts()?.is_empty());
+    let new_parent_subs = new_parent.sub_accounts()?;
+    assert_eq!(new_parent_subs.len(), 1);
+    assert_eq!(new_parent_subs[0].id()?, sub_account.id()?);
+    assert_eq!(sub_account.owner()?, client.sender());
+
+    // Top-level accounts can't use this helper
+    let res = parent.set_sub_account_gov(new_gov);
+    assert!(matches!(
+        res,
+        Err(AbstractClientError::NotSubAccount { .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn can_hand_sub_account_to_timelock() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    let client = AbstractClient::builder(chain.clone()).build()?;
+
+    let parent: Account<MockBech32> = client.account_builder().build()?;
+    let sub_account: Account<MockBech32> = client.account_builder().sub_account(&parent).build()?;
+
+    let timelock = chain.addr_make("timelock");
+    let new_gov = GovernanceDetails::External {
+        governance_address: timelock.to_string(),
+        governance_type: "timelock".to_owned(),
+    };
+    sub_account.set_sub_account_gov(new_gov)?;
+
+    // Proposed, the timelock still has to accept
+    assert!(matches!(
+        sub_account.info()?.governance_details,
+        GovernanceDetails::SubAccount { .. }
+    ));
+
+    chain.call_as(&timelock).execute(
+        &manager::ExecuteMsg::UpdateOwnership(cw_ownable::Action::AcceptOwnership),
+        &[],
+        &sub_account.manager()?,
+    )?;
+
+    assert_eq!(
+        sub_account.info()?.governance_details,
+        GovernanceDetails::External {
+            governance_address: timelock.clone(),
+            governance_type: "timelock".to_owned(),
+        }
+    );
+    assert_eq!(sub_account.owner()?, timelock);
+    // No longer a sub-account of the parent
+    assert!(parent.sub_accounts()?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn account_builder_funds_proxy() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    let client = AbstractClient::builder(chain).build()?;
+    client.set_balance(client.sender(), &coins(100, "ucoin"))?;
+
+    let account = client
+        .account_builder()
+        .with_funds(coins(60, "ucoin"))
+        .build()?;
+
+    assert_eq!(account.query_balance("ucoin")?, Uint128::new(60));
+    assert_eq!(
+        client.query_balance(&client.sender(), "ucoin")?,
+        Uint128::new(40)
+    );
+    Ok(())
+}
+
+#[test]
+fn account_builder_funds_fetched_account() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    let client = AbstractClient::builder(chain).build()?;
+    client.set_balance(client.sender(), &coins(100, "ucoin"))?;
+
+    let namespace = Namespace::new("funded")?;
+    let account = client
+        .account_builder()
+        .namespace(namespace.clone())
+        .with_funds(coins(60, "ucoin"))
+        .build()?;
+    assert_eq!(account.query_balance("ucoin")?, Uint128::new(60));
+
+    // The account with the claimed namespace is fetched and funded again
+    let fetched_account = client
+        .account_builder()
+        .namespace(namespace)
+        .with_funds(coins(30, "ucoin"))
+        .build()?;
+    assert_eq!(fetched_account.id()?, account.id()?);
+    assert_eq!(fetched_account.query_balance("ucoin")?, Uint128::new(90));
+    assert_eq!(
+        client.query_balance(&client.sender(), "ucoin")?,
+        Uint128::new(10)
+    );
+    Ok(())
+}
+
+#[test]
+fn account_builder_funds_proxy_rejects_foreign_owner() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    let client = AbstractClient::builder(chain.clone()).build()?;
+    client.set_balance(client.sender(), &coins(100, "ucoin"))?;
+
+    let governance_details = GovernanceDetails::Monarchy {
+        monarch: chain.addr_make("monarch").to_string(),
+    };
+    let res = client
+        .account_builder()
+        .ownership(governance_details.clone())
+        .with_funds(coins(60, "ucoin"))
+        .build();
+
+    let Err(AbstractClientError::FundsWithForeignOwner { owner }) = res else {
+        panic!("expected FundsWithForeignOwner error");
+    };
+    assert_eq!(owner, governance_details.to_string());
+    // Nothing was sent
+    assert_eq!(
+        client.query_balance(&client.sender(), "ucoin")?,
+        Uint128::new(100)
+    );
+    Ok(())
+}
+
 #[test]
 fn can_fetch_account_from_id() -> anyhow::Result<()> {
     let chain = MockBech32::new("mock");
@@ -434,6 +819,11 @@ fn can_fetch_account_from_app() -> anyhow::Result<()> {
 
     let app = account1.install_app::<MockAppI<MockBech32>>(&MockInitMsg {}, &[])?;
 
+    let app_config =
+        abstract_interface::query_app_base_config(&client.environment(), &app.address()?)?;
+    assert_eq!(app_config.manager_address, account1.manager()?);
+    assert_eq!(app_config.proxy_address, account1.proxy()?);
+
     let account2 = client.account_from(AccountSource::App(app.address()?))?;
 
     assert_eq!(account1.info()?, account2.info()?);
@@ -441,6 +831,100 @@ fn can_fetch_account_from_app() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn can_fetch_account_from_proxy() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    let client = AbstractClient::builder(chain.clone()).build()?;
+
+    let account1 = client.account_builder().build()?;
+
+    let account2 = client.account_from(AccountSource::Proxy(account1.proxy()?))?;
+    assert_eq!(account1.id()?, account2.id()?);
+
+    // The manager isn't a proxy
+    let err = client
+        .account_from(AccountSource::Proxy(account1.manager()?))
+        .unwrap_err();
+    assert!(matches!(err, AbstractClientError::NotAProxy { .. }));
+
+    // Neither is a random address
+    let err = client
+        .account_from(AccountSource::Proxy(chain.addr_make("random")))
+        .unwrap_err();
+    assert!(matches!(err, AbstractClientError::NotAProxy { .. }));
+    Ok(())
+}
+
+#[test]
+fn can_fetch_account_from_descriptor() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    let client = AbstractClient::builder(chain.clone()).build()?;
+
+    let account1 = client.account_builder().build()?;
+
+    let descriptor = account1.descriptor()?;
+    assert_eq!(descriptor, format!("{}:local-1", chain.env_info().chain_id));
+
+    let account2 = client.account_from_descriptor(&descriptor)?;
+    assert_eq!(account1.id()?, account2.id()?);
+    assert_eq!(account1.manager()?, account2.manager()?);
+    assert_eq!(account1.proxy()?, account2.proxy()?);
+    assert_eq!(descriptor, account2.descriptor()?);
+
+    // Malformed descriptors are rejected
+    for descriptor in ["local-1", "mock-chain:local", "mock-chain:local-one"] {
+        let err = client.account_from_descriptor(descriptor).unwrap_err();
+        assert!(matches!(
+            err,
+            AbstractClientError::InvalidAccountDescriptor { .. }
+        ));
+    }
+
+    // And so are descriptors of another chain
+    let err = client
+        .account_from_descriptor("other-chain-1:local-1")
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        AbstractClientError::AccountDescriptorChainMismatch { .. }
+    ));
+    Ok(())
+}
+
+#[test]
+fn can_fetch_account_from_adapter() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    let client = AbstractClient::builder(chain).build()?;
+
+    let publisher: Publisher<_> = client
+        .publisher_builder(Namespace::new(TEST_NAMESPACE)?)
+        .install_on_sub_account(false)
+        .build()?;
+    publisher.publish_adapter::<AdapterMockInitMsg, MockAdapterI<_>>(AdapterMockInitMsg {})?;
+
+    let account1 = client.account_builder().build()?;
+    let adapter: Application<_, MockAdapterI<_>> = account1.install_adapter(&[])?;
+
+    let account2 = client.account_from(AccountSource::Adapter {
+        adapter: adapter.address()?,
+        proxy: account1.proxy()?,
+    })?;
+    assert_eq!(account1.id()?, account2.id()?);
+
+    // The adapter isn't installed on the publisher account
+    let err = client
+        .account_from(AccountSource::Adapter {
+            adapter: adapter.address()?,
+            proxy: publisher.account().proxy()?,
+        })
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        AbstractClientError::AdapterNotInstalled { .. }
+    ));
+    Ok(())
+}
+
 #[test]
 fn can_install_module_with_dependencies() -> anyhow::Result<()> {
     let chain = MockBech32::new("mock");
@@ -675,6 +1159,29 @@ fn can_get_module_dependency() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn can_wait_until_block_and_time() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    let client = AbstractClient::builder(chain).build()?;
+
+    let block = client.block_info()?;
+    client.wait_until_block(block.height + 10)?;
+    assert_eq!(client.block_info()?.height, block.height + 10);
+
+    // Already past the target
+    client.wait_until_block(block.height)?;
+    assert_eq!(client.block_info()?.height, block.height + 10);
+
+    let seconds = client.block_info()?.time.seconds();
+    client.wait_until_seconds(seconds + 100)?;
+    assert_eq!(client.block_info()?.time.seconds(), seconds + 100);
+
+    // Already past the target
+    client.wait_until_seconds(seconds)?;
+    assert_eq!(client.block_info()?.time.seconds(), seconds + 100);
+    Ok(())
+}
+
 #[test]
 fn can_set_and_query_balance_with_client() -> anyhow::Result<()> {
     let chain = MockBech32::new("mock");
@@ -696,6 +1203,64 @@ fn can_set_and_query_balance_with_client() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn can_set_balances_for_multiple_addresses_with_client() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    let client = AbstractClient::builder(chain.clone()).build()?;
+
+    let alice = chain.addr_make("alice");
+    let bob = chain.addr_make("bob");
+    let carol = chain.addr_make("carol");
+    client.set_balances(vec![
+        (alice.clone(), coins(50, "denom1")),
+        (bob.clone(), coins(20, "denom2")),
+        (
+            carol.clone(),
+            vec![Coin::new(10, "denom1"), Coin::new(30, "denom3")],
+        ),
+    ])?;
+
+    assert_eq!(coins(50, "denom1"), client.query_balances(&alice)?);
+    assert_eq!(coins(20, "denom2"), client.query_balances(&bob)?);
+    assert_eq!(
+        vec![Coin::new(10, "denom1"), Coin::new(30, "denom3")],
+        client.query_balances(&carol)?
+    );
+    Ok(())
+}
+
+#[test]
+fn can_query_balances_of_many_addresses_with_client() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    let client = AbstractClient::builder(chain.clone()).build()?;
+
+    let alice = chain.addr_make("alice");
+    let bob = chain.addr_make("bob");
+    let carol = chain.addr_make("carol");
+    client.set_balances(vec![
+        (alice.clone(), coins(50, "denom1")),
+        (bob.clone(), coins(20, "denom2")),
+        (
+            carol.clone(),
+            vec![Coin::new(10, "denom1"), Coin::new(30, "denom3")],
+        ),
+    ])?;
+
+    let balances = client.query_balances_many(&[carol.clone(), alice.clone(), bob.clone()])?;
+    assert_eq!(
+        balances,
+        vec![
+            (
+                carol,
+                vec![Coin::new(10, "denom1"), Coin::new(30, "denom3")]
+            ),
+            (alice, coins(50, "denom1")),
+            (bob, coins(20, "denom2")),
+        ]
+    );
+    Ok(())
+}
+
 #[test]
 fn cannot_get_nonexisting_module_dependency() -> anyhow::Result<()> {
     let chain = MockBech32::new("mock");
@@ -1303,6 +1868,37 @@ fn instantiate2_raw_addr() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn prefund_predicted_sub_account() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    let client = AbstractClient::builder(chain).build()?;
+    let account = client.account_builder().build()?;
+
+    let next_seq = client.random_account_id()?;
+    let proxy_addr = client.module_instantiate2_address_raw(
+        &AccountId::local(next_seq),
+        ModuleInfo::from_id_latest(abstract_std::PROXY)?,
+    )?;
+    client.set_balance(&proxy_addr, &coins(100, TTOKEN))?;
+
+    let sub_account = client
+        .account_builder()
+        .sub_account(&account)
+        .expected_account_id(next_seq)
+        .build()?;
+    assert_eq!(sub_account.proxy()?, proxy_addr);
+    assert_eq!(sub_account.query_balance(TTOKEN)?, Uint128::new(100));
+
+    // The account id is the salt, so it can't be used twice
+    let res = client
+        .account_builder()
+        .sub_account(&account)
+        .expected_account_id(next_seq)
+        .build();
+    assert!(res.is_err());
+    Ok(())
+}
+
 #[test]
 fn install_same_app_on_different_accounts() -> anyhow::Result<()> {
     let chain = MockBech32::new("mock");
@@ -1375,3 +1971,312 @@ fn install_ibc_client_on_creation() -> anyhow::Result<()> {
     assert_eq!(ibc_module_addr.modules[0].0, IBC_CLIENT);
     Ok(())
 }
+
+#[test]
+fn builder_deploys_ibc_infrastructure() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    let client = AbstractClient::builder(chain).build()?;
+    let version_control = client.version_control();
+
+    // Both IBC contracts are registered as native modules
+    let ibc_client = version_control.module(ModuleInfo::from_id_latest(IBC_CLIENT)?)?;
+    assert_eq!(
+        ibc_client.reference,
+        ModuleReference::Native(client.ibc_client().address()?)
+    );
+    let ibc_host = version_control.module(ModuleInfo::from_id_latest(IBC_HOST)?)?;
+    assert_eq!(
+        ibc_host.reference,
+        ModuleReference::Native(client.ibc_host().address()?)
+    );
+
+    // And configured with the deployed version control
+    let client_config: abstract_std::ibc_client::ConfigResponse = client
+        .ibc_client()
+        .query(&abstract_std::ibc_client::QueryMsg::Config {})?;
+    assert_eq!(
+        client_config.version_control_address,
+        version_control.address()?.to_string()
+    );
+    let host_config: abstract_std::ibc_host::ConfigResponse = client
+        .ibc_host()
+        .query(&abstract_std::ibc_host::QueryMsg::Config {})?;
+    assert_eq!(
+        host_config.version_control_address,
+        version_control.address()?
+    );
+    Ok(())
+}
+
+mod isolated_transfer_app {
+    use abstract_app::{
+        mock::{MockAppContract, MockError},
+        sdk::TransferInterface,
+    };
+    use cosmwasm_std::{coins, DepsMut, Env, Reply, Response};
+    use cw_storage_plus::Item;
+
+    pub const FAILED_TRANSFERS: Item<Vec<u64>> = Item::new("failed_transfers");
+    const REPLY_ID_OFFSET: u64 = 1;
+
+    pub const ISOLATED_TRANSFER_APP: MockAppContract =
+        MockAppContract::new("tester:isolated-transfer", "1.0.0", None)
+            .with_execute(|deps, env, info, module, _| {
+                // The second transfer fails as the proxy doesn't hold any "umissing"
+                let payments = vec![
+                    (coins(100, "ucoin"), env.contract.address),
+                    (coins(100, "umissing"), info.sender.clone()),
+                    (coins(100, "ucoin"), info.sender),
+                ];
+                let transfers = module
+                    .bank(deps.as_ref())
+                    .transfer_isolated(payments, REPLY_ID_OFFSET)?;
+                Ok(Response::new().add_submessages(transfers))
+            })
+            .with_replies(&[
+                (REPLY_ID_OFFSET, record_failed_transfer),
+                (REPLY_ID_OFFSET + 1, record_failed_transfer),
+                (REPLY_ID_OFFSET + 2, record_failed_transfer),
+            ]);
+
+    fn record_failed_transfer(
+        deps: DepsMut,
+        _env: Env,
+        _app: &MockAppContract,
+        reply: Reply,
+    ) -> Result<Response, MockError> {
+        let mut failed = FAILED_TRANSFERS.may_load(deps.storage)?.unwrap_or_default();
+        failed.push(reply.id - REPLY_ID_OFFSET);
+        FAILED_TRANSFERS.save(deps.storage, &failed)?;
+        Ok(Response::new())
+    }
+
+    abstract_app::cw_orch_interface!(ISOLATED_TRANSFER_APP, MockAppContract, IsolatedTransferAppI);
+}
+
+#[test]
+fn bank_isolated_transfers_report_failures() -> anyhow::Result<()> {
+    use isolated_transfer_app::{interface::IsolatedTransferAppI, FAILED_TRANSFERS};
+
+    let chain = MockBech32::new("mock");
+    let client = AbstractClient::builder(chain.clone()).build()?;
+    let publisher: Publisher<MockBech32> = client
+        .publisher_builder(Namespace::new(TEST_NAMESPACE)?)
+        .build()?;
+    publisher.publish_app::<IsolatedTransferAppI<MockBech32>>()?;
+
+    let account = client.account_builder().build()?;
+    let app: Application<_, IsolatedTransferAppI<_>> = account.install_app(&MockInitMsg {}, &[])?;
+    account.set_balance(&coins(200, "ucoin"))?;
+
+    app.do_something()?;
+
+    // Both "ucoin" transfers succeeded, the "umissing" transfer got reverted on its own
+    assert_eq!(
+        client.query_balance(&app.address()?, "ucoin")?,
+        Uint128::new(100)
+    );
+    assert_eq!(
+        client.query_balance(&client.sender(), "ucoin")?,
+        Uint128::new(100)
+    );
+    assert_eq!(account.query_balance("ucoin")?, Uint128::zero());
+
+    let failed = FAILED_TRANSFERS.query(&chain.app.borrow().wrap(), app.address()?)?;
+    assert_eq!(failed, vec![1]);
+    Ok(())
+}
+
+mod sweep_app {
+    use abstract_app::{
+        mock::MockAppContract,
+        sdk::{Execution, TransferInterface},
+    };
+    use cosmwasm_std::Response;
+
+    // Sweeps the whole balance of its account to the caller
+    pub const SWEEP_APP: MockAppContract = MockAppContract::new("tester:sweep", "1.0.0", None)
+        .with_execute(|deps, _, info, module, _| {
+            let sweep = module.bank(deps.as_ref()).sweep_to(&info.sender)?;
+            let msg = module.executor(deps.as_ref()).execute(vec![sweep])?;
+            Ok(Response::new().add_message(msg))
+        });
+
+    abstract_app::cw_orch_interface!(SWEEP_APP, MockAppContract, SweepAppI);
+}
+
+#[test]
+fn bank_sweep_transfers_all_registered_cw20s() -> anyhow::Result<()> {
+    use abstract_std::{
+        ans_host::ExecuteMsgFns as _, manager, objects::price_source::UncheckedPriceSource, proxy,
+        PROXY,
+    };
+    use cosmwasm_std::{to_json_binary, Decimal};
+    use sweep_app::interface::SweepAppI;
+
+    let chain = MockBech32::new("mock");
+    let client = AbstractClient::builder(chain.clone()).build()?;
+    let publisher: Publisher<MockBech32> = client
+        .publisher_builder(Namespace::new(TEST_NAMESPACE)?)
+        .build()?;
+    publisher.publish_app::<SweepAppI<MockBech32>>()?;
+
+    let account = client.account_builder().build()?;
+    let app: Application<_, SweepAppI<_>> = account.install_app(&MockInitMsg {}, &[])?;
+
+    // Fill the oracle up to its size limit, a full page of assets is followed by an empty one
+    let tokens = (0..abstract_std::objects::oracle::LIST_SIZE_LIMIT)
+        .map(|i| {
+            client
+                .cw20_builder(
+                    format!("token {i}"),
+                    format!("TOK{}", (b'A' + i) as char),
+                    6,
+                )
+                .initial_balance(cw20_builder::Cw20Coin {
+                    address: account.proxy()?.to_string(),
+                    amount: Uint128::new(100),
+                })
+                .instantiate_with_id(&format!("tester:cw20-{i}"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    client.name_service().update_asset_addresses(
+        false,
+        tokens
+            .iter()
+            .enumerate()
+            .map(|(i, token)| {
+                Ok((
+                    format!("token{i}"),
+                    AssetInfoUnchecked::cw20(token.addr_str()?),
+                ))
+            })
+            .collect::<anyhow::Result<_>>()?,
+        vec![],
+    )?;
+    let to_add = (0..tokens.len())
+        .map(|i| {
+            let price_source = if i == 0 {
+                UncheckedPriceSource::None
+            } else {
+                UncheckedPriceSource::ValueAs {
+                    asset: AssetEntry::new("token0"),
+                    multiplier: Decimal::one(),
+                }
+            };
+            (AssetEntry::new(&format!("token{i}")), price_source)
+        })
+        .collect();
+    account.execute_on_manager(
+        &manager::ExecuteMsg::ExecOnModule {
+            module_id: PROXY.to_owned(),
+            exec_msg: to_json_binary(&proxy::ExecuteMsg::UpdateAssets {
+                to_add,
+                to_remove: vec![],
+            })?,
+        },
+        &[],
+    )?;
+
+    app.do_something()?;
+
+    for token in tokens {
+        assert_eq!(
+            token.balance(client.sender().to_string())?.balance,
+            Uint128::new(100)
+        );
+        assert_eq!(
+            token.balance(account.proxy()?.to_string())?.balance,
+            Uint128::zero()
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn deployed_versions_match_deployment() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    let client = AbstractClient::builder(chain).build()?;
+
+    let versions = client.deployed_versions()?;
+    let expected = |contract: &str| cw2::ContractVersion {
+        contract: contract.to_owned(),
+        version: abstract_std::registry::ABSTRACT_VERSION.to_owned(),
+    };
+    assert_eq!(
+        versions,
+        abstract_client::DeployedVersions {
+            version_control: expected(abstract_std::VERSION_CONTROL),
+            ans_host: expected(abstract_std::ANS_HOST),
+            account_factory: expected(abstract_std::ACCOUNT_FACTORY),
+            module_factory: expected(abstract_std::MODULE_FACTORY),
+            manager: expected(abstract_std::MANAGER),
+            proxy: expected(abstract_std::PROXY),
+            ibc_client: Some(expected(IBC_CLIENT)),
+            ibc_host: Some(expected(IBC_HOST)),
+        }
+    );
+    Ok(())
+}
+
+mod incentives_app {
+    use abstract_app::{mock::MockAppContract, sdk::features::AccountIdentification};
+    use cosmwasm_std::{BankMsg, Response};
+
+    pub const INCENTIVE_DENOM: &str = "uincentive";
+
+    // Pays out all of its incentive tokens to the proxy of its account
+    pub const INCENTIVES_APP: MockAppContract =
+        MockAppContract::new("tester:incentives", "1.0.0", None).with_execute(
+            |deps, env, _, module, _| {
+                let balance = deps
+                    .querier
+                    .query_balance(env.contract.address, INCENTIVE_DENOM)?;
+                if balance.amount.is_zero() {
+                    return Ok(Response::new());
+                }
+                let proxy = module.proxy_address(deps.as_ref())?;
+                Ok(Response::new().add_message(BankMsg::Send {
+                    to_address: proxy.to_string(),
+                    amount: vec![balance],
+                }))
+            },
+        );
+
+    abstract_app::cw_orch_interface!(INCENTIVES_APP, MockAppContract, IncentivesAppI);
+}
+
+#[test]
+fn claim_incentives_credits_proxy() -> anyhow::Result<()> {
+    use abstract_app::mock::MockExecMsg;
+    use abstract_client::IncentivesClaim;
+    use incentives_app::{interface::IncentivesAppI, INCENTIVE_DENOM};
+
+    let chain = MockBech32::new("mock");
+    let client = AbstractClient::builder(chain.clone()).build()?;
+    let publisher: Publisher<MockBech32> = client
+        .publisher_builder(Namespace::new(TEST_NAMESPACE)?)
+        .build()?;
+    publisher.publish_app::<IncentivesAppI<MockBech32>>()?;
+
+    let account = client.account_builder().build()?;
+    let app: Application<_, IncentivesAppI<_>> = account.install_app(&MockInitMsg {}, &[])?;
+    let claim_msg = abstract_std::app::ExecuteMsg::<MockExecMsg>::from(MockExecMsg::DoSomething {});
+
+    // Nothing to claim yet
+    let claim = account.claim_incentives("tester:incentives", &claim_msg)?;
+    assert_eq!(claim, IncentivesClaim::NothingToClaim);
+
+    client.set_balance(app.address()?, &coins(100, INCENTIVE_DENOM))?;
+    let claim = account.claim_incentives("tester:incentives", &claim_msg)?;
+    assert_eq!(claim, IncentivesClaim::Claimed(coins(100, INCENTIVE_DENOM)));
+    assert_eq!(account.query_balance(INCENTIVE_DENOM)?, Uint128::new(100));
+
+    // Claiming from a module that isn't installed fails
+    let res = account.claim_incentives("tester:missing", &claim_msg);
+    assert!(matches!(
+        res,
+        Err(AbstractClientError::ModuleNotInstalled {})
+    ));
+    Ok(())
+}
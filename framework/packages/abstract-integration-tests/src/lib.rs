@@ -16,7 +16,8 @@ use abstract_std::objects::{
     AccountId,
 };
 use abstract_testing::prelude::*;
-use cw_orch::prelude::*;
+use cw_asset::AssetInfoUnchecked;
+use cw_orch::{environment::MutCwEnv, prelude::*};
 pub type AResult = anyhow::Result<()>; // alias for Result<(), anyhow::Error>
 
 pub fn create_default_account<T: CwEnv>(
@@ -30,6 +31,27 @@ pub fn create_default_account<T: CwEnv>(
     Ok(account)
 }
 
+/// Registers the `assets` on the ANS host and creates a default account with its proxy funded with `proxy_balance`.
+pub fn create_funded_account_with_assets<T: MutCwEnv>(
+    mut chain: T,
+    deployment: &Abstract<T>,
+    assets: Vec<(&str, AssetInfoUnchecked)>,
+    proxy_balance: Vec<Coin>,
+) -> anyhow::Result<AbstractAccount<T>> {
+    deployment.ans_host.update_asset_addresses(
+        false,
+        assets
+            .into_iter()
+            .map(|(name, info)| (name.to_string(), info))
+            .collect(),
+        vec![],
+    )?;
+
+    let account = create_default_account(&deployment.account_factory)?;
+    chain.set_balance(&account.proxy.address()?, proxy_balance)?;
+    Ok(account)
+}
+
 pub fn install_module_version<T: CwEnv>(
     manager: &Manager<T>,
     module: &str,
@@ -61,6 +61,26 @@ impl<Chain: CwEnv> Manager<Chain> {
         Ok(())
     }
 
+    /// Migrate the installed instance of a module to the provided version in place.
+    /// The manager checks the dependency requirements of the module and its dependents before and after the migration.
+    pub fn upgrade_module_version<M: Serialize>(
+        &self,
+        module_id: &str,
+        version: ModuleVersion,
+        migrate_msg: &M,
+    ) -> Result<Chain::Response, crate::AbstractInterfaceError> {
+        self.execute(
+            &ExecuteMsg::Upgrade {
+                modules: vec![(
+                    ModuleInfo::from_id(module_id, version)?,
+                    Some(to_json_binary(migrate_msg)?),
+                )],
+            },
+            None,
+        )
+        .map_err(Into::into)
+    }
+
     pub fn replace_api(
         &self,
         module_id: &str,
@@ -242,6 +262,7 @@ impl<Chain: CwEnv> Manager<Chain> {
                 action: HostAction::Dispatch {
                     manager_msgs: vec![msg],
                 },
+                timeout_seconds: None,
             },
         };
 
@@ -264,6 +285,7 @@ impl<Chain: CwEnv> Manager<Chain> {
                         exec_msg: msg,
                     }],
                 },
+                timeout_seconds: None,
             },
         };
 
@@ -279,6 +301,7 @@ impl<Chain: CwEnv> Manager<Chain> {
             msg: abstract_std::ibc_client::ExecuteMsg::RemoteAction {
                 host_chain,
                 action: HostAction::Helpers(HelperAction::SendAllBack),
+                timeout_seconds: None,
             },
         };
 
@@ -6,7 +6,7 @@ use abstract_std::{
     },
 };
 use cosmwasm_std::to_json_binary;
-use cw_orch::prelude::{CwOrchError::StdErr, *};
+use cw_orch::prelude::*;
 use semver::Version;
 use serde::Serialize;
 
@@ -104,12 +104,10 @@ pub trait AdapterDeployer<Chain: CwEnv, CustomInitMsg: Serialize>: ContractInsta
         match strategy {
             DeployStrategy::Error => {
                 if vc_has_module().is_ok() {
-                    return Err(StdErr(format!(
-                        "Adapter {} already exists with version {}",
-                        self.id(),
-                        version
-                    ))
-                    .into());
+                    return Err(crate::AbstractInterfaceError::AlreadyDeployed {
+                        module: self.id(),
+                        version: version.to_string(),
+                    });
                 }
             }
             DeployStrategy::Try => {
@@ -164,12 +162,10 @@ pub trait AppDeployer<Chain: CwEnv>: Sized + Uploadable + ContractInstance<Chain
         match strategy {
             DeployStrategy::Error => {
                 if vc_has_module().is_ok() {
-                    return Err(StdErr(format!(
-                        "App {} already exists with version {}",
-                        self.id(),
-                        version
-                    ))
-                    .into());
+                    return Err(crate::AbstractInterfaceError::AlreadyDeployed {
+                        module: self.id(),
+                        version: version.to_string(),
+                    });
                 }
             }
             DeployStrategy::Try => {
@@ -215,12 +211,10 @@ pub trait StandaloneDeployer<Chain: CwEnv>: Sized + Uploadable + ContractInstanc
         match strategy {
             DeployStrategy::Error => {
                 if vc_has_module().is_ok() {
-                    return Err(StdErr(format!(
-                        "Standalone {} already exists with version {}",
-                        self.id(),
-                        version
-                    ))
-                    .into());
+                    return Err(crate::AbstractInterfaceError::AlreadyDeployed {
+                        module: self.id(),
+                        version: version.to_string(),
+                    });
                 }
             }
             DeployStrategy::Try => {
@@ -31,6 +31,9 @@ pub enum AbstractInterfaceError {
     #[error("Module Not Found {0}")]
     ModuleNotFound(String),
 
+    #[error("Module {module} already exists with version {version}")]
+    AlreadyDeployed { module: String, version: String },
+
     #[error("No need to update {0}")]
     NotUpdated(String),
 
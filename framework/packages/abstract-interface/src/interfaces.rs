@@ -1,6 +1,7 @@
 use abstract_std::{
-    objects::AccountId, ACCOUNT_FACTORY, ANS_HOST, IBC_CLIENT, IBC_HOST, MODULE_FACTORY,
-    VERSION_CONTROL,
+    app::{AppConfigResponse, BaseQueryMsg, QueryMsg as AppQueryMsg},
+    objects::AccountId,
+    ACCOUNT_FACTORY, ANS_HOST, IBC_CLIENT, IBC_HOST, MODULE_FACTORY, VERSION_CONTROL,
 };
 use cw_orch::prelude::*;
 
@@ -55,3 +56,16 @@ where
 
     (ibc_client, ibc_host)
 }
+
+/// Query the base config of the app at `app_addr`.
+pub fn query_app_base_config<Chain: CwEnv>(
+    chain: &Chain,
+    app_addr: &Addr,
+) -> Result<AppConfigResponse, CwOrchError> {
+    chain
+        .query(
+            &AppQueryMsg::<Empty>::Base(BaseQueryMsg::BaseConfig {}),
+            app_addr,
+        )
+        .map_err(Into::into)
+}
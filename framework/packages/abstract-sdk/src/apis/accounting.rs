@@ -9,6 +9,7 @@ use abstract_std::{
     proxy::{AssetsInfoResponse, BaseAssetResponse, QueryMsg, TokenValueResponse},
 };
 use cosmwasm_std::{Deps, Uint128};
+use cw_asset::AssetInfo;
 
 use super::{AbstractApi, ApiIdentification};
 use crate::{
@@ -129,6 +130,29 @@ impl<'a, T: AccountingInterface> Accountant<'a, T> {
         Ok(resp)
     }
 
+    /// List all enabled assets (AssetInfos), paging through the proxy's asset list
+    pub fn all_assets_list(&self) -> AbstractSdkResult<AssetsInfoResponse> {
+        let proxy_address = self.base.proxy_address(self.deps)?;
+
+        let mut assets = vec![];
+        loop {
+            let resp: AssetsInfoResponse = self.smart_query(
+                &proxy_address,
+                &QueryMsg::AssetsInfo {
+                    start_after: assets.last().map(|(info, _): &(AssetInfo, _)| info.clone()),
+                    limit: Some(oracle::LIST_SIZE_LIMIT),
+                },
+            )?;
+            let page_size = resp.assets.len();
+            assets.extend(resp.assets);
+            if page_size < oracle::LIST_SIZE_LIMIT as usize {
+                break;
+            }
+        }
+
+        Ok(AssetsInfoResponse { assets })
+    }
+
     // /// List ProxyAssets raw
     // pub fn proxy_assets_list(&self) -> AbstractSdkResult<Vec<(AssetEntry, ProxyAsset)>> {
     //     let querier = self.deps.querier;
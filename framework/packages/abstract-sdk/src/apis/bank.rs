@@ -1,19 +1,27 @@
 //! # Bank
 //! The Bank object handles asset transfers to and from the Account.
 
-use abstract_std::objects::{ans_host::AnsHostError, AnsAsset, AssetEntry};
-use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, Deps, Env};
-use cw_asset::Asset;
-use serde::Serialize;
+use abstract_std::objects::{ans_host::AnsHostError, oracle::AccountValue, AnsAsset, AssetEntry};
+use cosmwasm_std::{
+    to_json_binary, wasm_execute, Addr, BankMsg, Coin, CosmosMsg, Decimal, Deps, Env, ReplyOn,
+    StdError, SubMsg, Timestamp, Uint128,
+};
+use cw_asset::{Asset, AssetInfo, AssetList};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 use super::{AbstractApi, ApiIdentification};
 use crate::{
     ans_resolve::Resolve,
+    apis::accounting::AccountingInterface,
     cw_helpers::ApiQuery,
     features::{AbstractNameService, AccountExecutor, AccountIdentification, ModuleIdentification},
-    AbstractSdkError, AbstractSdkResult, AccountAction,
+    AbstractSdkError, AbstractSdkResult, AccountAction, Execution,
 };
 
+/// Maximum number of coins sent in a single `BankMsg::Send` by [`Bank::sweep_to`].
+pub const MAX_SWEEP_COINS_PER_SEND: usize = 10;
+
 /// Query and Transfer assets from and to the Abstract Account.
 pub trait TransferInterface:
     AbstractNameService + AccountIdentification + ModuleIdentification
@@ -33,7 +41,11 @@ pub trait TransferInterface:
         ```
     */
     fn bank<'a>(&'a self, deps: Deps<'a>) -> Bank<Self> {
-        Bank { base: self, deps }
+        Bank {
+            base: self,
+            deps,
+            archive: None,
+        }
     }
 }
 
@@ -75,6 +87,19 @@ impl<'a, T: TransferInterface> ApiIdentification for Bank<'a, T> {
 pub struct Bank<'a, T: TransferInterface> {
     base: &'a T,
     deps: Deps<'a>,
+    archive: Option<&'a dyn ArchiveQuerier>,
+}
+
+/// Serves balances at past block heights, e.g. backed by an archive node.
+/// Contract queriers only have access to the latest state.
+pub trait ArchiveQuerier {
+    /// Balance of `address` for `asset` at block `height`, or `None` if the height can't be served.
+    fn balance_at(
+        &self,
+        asset: &AssetInfo,
+        address: &Addr,
+        height: u64,
+    ) -> AbstractSdkResult<Option<Uint128>>;
 }
 
 impl<'a, T: TransferInterface> Bank<'a, T> {
@@ -95,6 +120,97 @@ impl<'a, T: TransferInterface> Bank<'a, T> {
         Ok(Asset::new(resolved_info, balance))
     }
 
+    /// Serve historical queries like [`Bank::balance_at`] through the provided `archive`.
+    pub fn with_archive(mut self, archive: &'a dyn ArchiveQuerier) -> Self {
+        self.archive = Some(archive);
+        self
+    }
+
+    /// Get the balance of the provided asset at block `height`.
+    /// Requires an [`ArchiveQuerier`] that can serve the height, see [`Bank::with_archive`].
+    /// Errors with [`AbstractSdkError::HistoricalQueryUnsupported`] otherwise.
+    pub fn balance_at(&self, asset: &AssetEntry, height: u64) -> AbstractSdkResult<Asset> {
+        let resolved_info = asset
+            .resolve(&self.deps.querier, &self.base.ans_host(self.deps)?)
+            .map_err(|error| self.wrap_query_error(error))?;
+        let unsupported = || AbstractSdkError::HistoricalQueryUnsupported { height };
+        let balance = self
+            .archive
+            .ok_or_else(unsupported)?
+            .balance_at(&resolved_info, &self.base.proxy_address(self.deps)?, height)?
+            .ok_or_else(unsupported)?;
+        Ok(Asset::new(resolved_info, balance))
+    }
+
+    /// Whether the Account holds enough funds to cover all the `payouts`.
+    /// See [`Bank::shortfall`] to get the missing amounts.
+    pub fn can_afford(&self, payouts: &[AnsAsset]) -> AbstractSdkResult<bool> {
+        Ok(self.shortfall(payouts)?.is_empty())
+    }
+
+    /// Get the amount of each asset the Account is missing to cover all the `payouts`.
+    /// Amounts of the same asset are summed up and its balance is queried once.
+    /// Returns an empty list if the Account can afford all the payouts.
+    pub fn shortfall(&self, payouts: &[AnsAsset]) -> AbstractSdkResult<Vec<AnsAsset>> {
+        let mut required: Vec<AnsAsset> = vec![];
+        for payout in payouts {
+            match required.iter_mut().find(|a| a.name == payout.name) {
+                Some(total) => {
+                    total.amount = total
+                        .amount
+                        .checked_add(payout.amount)
+                        .map_err(StdError::from)?
+                }
+                None => required.push(payout.clone()),
+            }
+        }
+
+        required
+            .into_iter()
+            .filter_map(|required| {
+                self.balance(&required.name)
+                    .map(|balance| {
+                        (required.amount > balance.amount)
+                            .then(|| AnsAsset::new(required.name, required.amount - balance.amount))
+                    })
+                    .transpose()
+            })
+            .collect()
+    }
+
+    /// Get the balances of all the assets registered on the Account's oracle together with the
+    /// Account's total value, both queried at the current block.
+    /// The total value and its per-asset breakdown are valued by the proxy's oracle.
+    pub fn portfolio(&self) -> AbstractSdkResult<Portfolio> {
+        let accountant = self.base.accountant(self.deps);
+        let proxy_address = self.base.proxy_address(self.deps)?;
+        let balances = accountant
+            .all_assets_list()?
+            .assets
+            .into_iter()
+            .map(|(info, _)| {
+                let balance = info.query_balance(&self.deps.querier, &proxy_address)?;
+                Ok(Asset::new(info, balance))
+            })
+            .collect::<AbstractSdkResult<Vec<Asset>>>()?;
+        let total_value = accountant.query_total_value()?;
+        Ok(Portfolio {
+            balances,
+            total_value,
+        })
+    }
+
+    /// Number of distinct native denoms the Account holds a non-zero balance of.
+    /// Queries all balances once, cw20 tokens are not included as they can't be enumerated.
+    pub fn held_denom_count(&self) -> AbstractSdkResult<u64> {
+        let proxy_address = self.base.proxy_address(self.deps)?;
+        let balances = self.deps.querier.query_all_balances(proxy_address)?;
+        Ok(balances
+            .iter()
+            .filter(|coin| !coin.amount.is_zero())
+            .count() as u64)
+    }
+
     /// Move funds from the contract into the Account.
     pub fn deposit<R: Transferable>(&self, funds: Vec<R>) -> AbstractSdkResult<Vec<CosmosMsg>> {
         let recipient = self.base.proxy_address(self.deps)?;
@@ -108,10 +224,35 @@ impl<'a, T: TransferInterface> Bank<'a, T> {
             .collect::<Result<Vec<_>, _>>()
             .map_err(Into::into)
     }
+
+    /// Pull cw20 tokens from `owner` into the Account with `TransferFrom`.
+    /// The owner must have approved an allowance for the calling contract. Native assets are rejected.
+    pub fn deposit_from<R: Transferable>(
+        &self,
+        owner: &Addr,
+        funds: Vec<R>,
+    ) -> AbstractSdkResult<Vec<CosmosMsg>> {
+        let recipient = self.base.proxy_address(self.deps)?;
+        funds
+            .into_iter()
+            .map(|asset| {
+                let asset = asset.transferable_asset(self.base, self.deps)?;
+                if let AssetInfo::Native(_) = asset.info {
+                    return Err(AbstractSdkError::CannotPullNativeAsset {
+                        asset: asset.to_string(),
+                        owner: owner.clone(),
+                    });
+                }
+                Ok(asset.transfer_from_msg(owner, &recipient)?)
+            })
+            .collect()
+    }
 }
 
 impl<'a, T: TransferInterface + AccountExecutor> Bank<'a, T> {
     /// Transfer the provided funds from the Account to the recipient.
+    /// Funds that resolve to the same asset are summed and sent in a single message,
+    /// so each native denom gets its own `BankMsg::Send`. Use [`Bank::transfer_assets`] to send all native coins at once.
     /// ```
     /// # use cosmwasm_std::{Addr, Response, Deps, DepsMut, MessageInfo};
     /// # use abstract_std::objects::AnsAsset;
@@ -156,11 +297,13 @@ impl<'a, T: TransferInterface + AccountExecutor> Bank<'a, T> {
         funds: Vec<R>,
         recipient: &Addr,
     ) -> AbstractSdkResult<AccountAction> {
-        let transferable_funds = funds
-            .into_iter()
-            .map(|asset| asset.transferable_asset(self.base, self.deps))
-            .collect::<AbstractSdkResult<Vec<Asset>>>()?;
+        // Sum resolved assets of the same denom or cw20 contract so each is sent only once
+        let mut transferable_funds = AssetList::new();
+        for asset in funds {
+            transferable_funds.add(&asset.transferable_asset(self.base, self.deps)?)?;
+        }
         let msgs = transferable_funds
+            .to_vec()
             .iter()
             .map(|asset| asset.transfer_msg(recipient.clone()))
             .collect::<Result<Vec<_>, _>>()?;
@@ -168,6 +311,203 @@ impl<'a, T: TransferInterface + AccountExecutor> Bank<'a, T> {
         Ok(AccountAction::from_vec(msgs))
     }
 
+    /// Transfer funds to each recipient in its own submessage that only replies on error,
+    /// so a failing transfer doesn't revert the others.
+    ///
+    /// The transfer to `payments[i]` replies with id `reply_id_offset + i`.
+    /// Register a reply handler for each of these ids to record the failed transfers.
+    /// Errors if any of these ids overflows a `u64`.
+    pub fn transfer_isolated<R: Transferable>(
+        &self,
+        payments: Vec<(Vec<R>, Addr)>,
+        reply_id_offset: u64,
+    ) -> AbstractSdkResult<Vec<SubMsg>> {
+        let executor = self.base.executor(self.deps);
+        payments
+            .into_iter()
+            .enumerate()
+            .map(|(index, (funds, recipient))| {
+                let reply_id = u64::try_from(index)
+                    .ok()
+                    .and_then(|index| reply_id_offset.checked_add(index))
+                    .ok_or(AbstractSdkError::ReplyIdOverflow {
+                        offset: reply_id_offset,
+                        index,
+                    })?;
+                let action = self.transfer(funds, &recipient)?;
+                executor.execute_with_reply(vec![action], ReplyOn::Error, reply_id)
+            })
+            .collect()
+    }
+
+    /// Transfer a resolved [`AssetList`] from the Account to the recipient.
+    ///
+    /// Unlike [`Bank::transfer`], which sends one message per asset, all native coins are merged
+    /// into a single `BankMsg::Send`, followed by one transfer per cw20 asset. See [`Bank::transfer_to_many`].
+    pub fn transfer_assets(
+        &self,
+        assets: AssetList,
+        recipient: &Addr,
+    ) -> AbstractSdkResult<AccountAction> {
+        self.transfer_to_many(vec![(assets.to_vec(), recipient.clone())])
+    }
+
+    /// Transfer a `fraction` of the Account's current balance of `asset` to the recipient.
+    /// The transferred amount is rounded down, if it's zero no transfer is made.
+    /// Errors if `fraction` is greater than one.
+    pub fn transfer_fraction(
+        &self,
+        asset: &AssetEntry,
+        fraction: Decimal,
+        recipient: &Addr,
+    ) -> AbstractSdkResult<AccountAction> {
+        if fraction > Decimal::one() {
+            return Err(AbstractSdkError::InvalidTransferFraction { fraction });
+        }
+        let mut funds = self.balance(asset)?;
+        funds.amount = funds.amount * fraction;
+        if funds.amount.is_zero() {
+            return Ok(AccountAction::new());
+        }
+        self.transfer(vec![funds], recipient)
+    }
+
+    /// Transfer funds from the Account to multiple recipients in a single [`AccountAction`].
+    ///
+    /// Payments are grouped per recipient, in order of first appearance. Native coins to the same recipient
+    /// are merged into a single `BankMsg::Send`, followed by one transfer per other asset.
+    pub fn transfer_to_many<R: Transferable>(
+        &self,
+        payments: Vec<(Vec<R>, Addr)>,
+    ) -> AbstractSdkResult<AccountAction> {
+        let mut grouped: Vec<(Addr, Vec<Coin>, Vec<Asset>)> = vec![];
+        for (funds, recipient) in payments {
+            let index = match grouped.iter().position(|(addr, ..)| *addr == recipient) {
+                Some(index) => index,
+                None => {
+                    grouped.push((recipient, vec![], vec![]));
+                    grouped.len() - 1
+                }
+            };
+            let (_, coins, assets) = &mut grouped[index];
+            for fund in funds {
+                let asset = fund.transferable_asset(self.base, self.deps)?;
+                if let AssetInfo::Native(denom) = &asset.info {
+                    match coins.iter_mut().find(|coin| &coin.denom == denom) {
+                        Some(coin) => {
+                            coin.amount = coin
+                                .amount
+                                .checked_add(asset.amount)
+                                .map_err(StdError::from)?
+                        }
+                        None => coins.push(Coin {
+                            denom: denom.clone(),
+                            amount: asset.amount,
+                        }),
+                    }
+                } else {
+                    match assets.iter_mut().find(|a| a.info == asset.info) {
+                        Some(a) => {
+                            a.amount = a.amount.checked_add(asset.amount).map_err(StdError::from)?
+                        }
+                        None => assets.push(asset),
+                    }
+                }
+            }
+        }
+
+        let mut msgs: Vec<CosmosMsg> = vec![];
+        for (recipient, coins, assets) in grouped {
+            if !coins.is_empty() {
+                msgs.push(
+                    BankMsg::Send {
+                        to_address: recipient.to_string(),
+                        amount: coins,
+                    }
+                    .into(),
+                );
+            }
+            for asset in assets {
+                msgs.push(asset.transfer_msg(recipient.clone())?);
+            }
+        }
+
+        Ok(AccountAction::from_vec(msgs))
+    }
+
+    /// Transfer the Account's entire balance to another Account's proxy, e.g. to consolidate Accounts.
+    ///
+    /// Non-zero native balances are sent in chunks of at most [`MAX_SWEEP_COINS_PER_SEND`] coins.
+    /// cw20 tokens can't be enumerated, so only those registered on the Account's oracle are swept.
+    /// All pages of the oracle's asset list are queried.
+    pub fn sweep_to(&self, destination_proxy: &Addr) -> AbstractSdkResult<AccountAction> {
+        let proxy_address = self.base.proxy_address(self.deps)?;
+        let coins: Vec<Coin> = self
+            .deps
+            .querier
+            .query_all_balances(&proxy_address)?
+            .into_iter()
+            .filter(|coin| !coin.amount.is_zero())
+            .collect();
+        let mut msgs: Vec<CosmosMsg> = coins
+            .chunks(MAX_SWEEP_COINS_PER_SEND)
+            .map(|chunk| {
+                BankMsg::Send {
+                    to_address: destination_proxy.to_string(),
+                    amount: chunk.to_vec(),
+                }
+                .into()
+            })
+            .collect();
+
+        for (info, _) in self.base.accountant(self.deps).all_assets_list()?.assets {
+            if let AssetInfo::Cw20(_) = info {
+                let balance = info.query_balance(&self.deps.querier, &proxy_address)?;
+                if !balance.is_zero() {
+                    msgs.push(Asset::new(info, balance).transfer_msg(destination_proxy)?);
+                }
+            }
+        }
+
+        Ok(AccountAction::from_vec(msgs))
+    }
+
+    /// Burn the provided funds held by the Account.
+    /// Native coins are burned with a `BankMsg::Burn`, cw20 tokens with a `Cw20ExecuteMsg::Burn`.
+    pub fn burn<R: Transferable>(&self, funds: Vec<R>) -> AbstractSdkResult<AccountAction> {
+        let msgs = funds
+            .into_iter()
+            .map(|fund| {
+                let asset = fund.transferable_asset(self.base, self.deps)?;
+                let msg: CosmosMsg = match asset.info {
+                    AssetInfo::Native(denom) => BankMsg::Burn {
+                        amount: vec![Coin {
+                            denom,
+                            amount: asset.amount,
+                        }],
+                    }
+                    .into(),
+                    AssetInfo::Cw20(contract_addr) => wasm_execute(
+                        contract_addr,
+                        &cw20::Cw20ExecuteMsg::Burn {
+                            amount: asset.amount,
+                        },
+                        vec![],
+                    )?
+                    .into(),
+                    info => {
+                        return Err(AbstractSdkError::Std(StdError::generic_err(format!(
+                            "Burning {info} is not supported"
+                        ))))
+                    }
+                };
+                Ok(msg)
+            })
+            .collect::<AbstractSdkResult<Vec<CosmosMsg>>>()?;
+
+        Ok(AccountAction::from_vec(msgs))
+    }
+
     /// Withdraw funds from the Account to this contract.
     pub fn withdraw<R: Transferable>(
         &self,
@@ -178,6 +518,25 @@ impl<'a, T: TransferInterface + AccountExecutor> Bank<'a, T> {
         self.transfer(funds, recipient)
     }
 
+    /// Vest `total` to the recipient linearly between `start` and `end`, releasing nothing before `cliff`.
+    ///
+    /// The funds are moved from the Account to this contract, which holds them in escrow.
+    /// Store the returned [`VestingSchedule`] and call [`VestingSchedule::release`] to pay out the vested funds.
+    pub fn vest<R: Transferable>(
+        &self,
+        env: &Env,
+        recipient: &Addr,
+        total: R,
+        start: Timestamp,
+        end: Timestamp,
+        cliff: Timestamp,
+    ) -> AbstractSdkResult<(AccountAction, VestingSchedule)> {
+        let total = total.transferable_asset(self.base, self.deps)?;
+        let schedule = VestingSchedule::new(recipient.clone(), total.clone(), start, cliff, end)?;
+        let funding = self.withdraw(env, vec![total])?;
+        Ok((funding, schedule))
+    }
+
     /// Move cw20 assets from the Account to a recipient with the possibility using the cw20 send/receive hook
     ///
     /// Note:  **Native coins are NOT and will NEVER be supported by this method**.
@@ -195,6 +554,111 @@ impl<'a, T: TransferInterface + AccountExecutor> Bank<'a, T> {
 
         Ok(AccountAction::from_vec(vec![msgs]))
     }
+
+    /// Move cw20 assets from the Account to a recipient using the cw20 send/receive hook,
+    /// executed in a submessage with the provided `reply_on` and reply `id`.
+    /// Works like [`Bank::send`], use it to react on the outcome of the hook.
+    ///
+    /// Note:  **Native coins are NOT and will NEVER be supported by this method**.
+    pub fn send_with_reply<R: Transferable, M: Serialize>(
+        &self,
+        funds: R,
+        recipient: &Addr,
+        message: &M,
+        reply_on: ReplyOn,
+        id: u64,
+    ) -> AbstractSdkResult<SubMsg> {
+        let action = self.send(funds, recipient, message)?;
+        self.base
+            .executor(self.deps)
+            .execute_with_reply(vec![action], reply_on, id)
+    }
+}
+
+/// Balances and total value of an Account, see [`Bank::portfolio`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct Portfolio {
+    /// Balances of the assets registered on the Account's oracle
+    pub balances: Vec<Asset>,
+    /// Total value of the Account denominated in its base asset.
+    /// The breakdown holds the value of each balance in the base asset.
+    pub total_value: AccountValue,
+}
+
+/// Linear vesting of an asset held in escrow by a module, see [`Bank::vest`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct VestingSchedule {
+    /// Receiver of the vested funds
+    pub recipient: Addr,
+    /// Vested asset
+    pub asset: AssetInfo,
+    /// Amount vested at `end`
+    pub total: Uint128,
+    /// Amount already paid out to the recipient
+    pub released: Uint128,
+    /// Start of the linear vesting
+    pub start: Timestamp,
+    /// Nothing is vested before the cliff, at the cliff everything vested since `start` is released at once
+    pub cliff: Timestamp,
+    /// Everything is vested from `end` onwards
+    pub end: Timestamp,
+}
+
+impl VestingSchedule {
+    /// Creates a schedule for `total`, requires `start <= cliff <= end`.
+    pub fn new(
+        recipient: Addr,
+        total: Asset,
+        start: Timestamp,
+        cliff: Timestamp,
+        end: Timestamp,
+    ) -> AbstractSdkResult<Self> {
+        if start > cliff || cliff > end {
+            return Err(AbstractSdkError::InvalidVestingSchedule { start, cliff, end });
+        }
+        Ok(Self {
+            recipient,
+            asset: total.info,
+            total: total.amount,
+            released: Uint128::zero(),
+            start,
+            cliff,
+            end,
+        })
+    }
+
+    /// Amount vested at `time`, rounded down.
+    /// `total * (time - start) / (end - start)` between the cliff and the end.
+    pub fn vested(&self, time: Timestamp) -> Uint128 {
+        if time < self.cliff {
+            Uint128::zero()
+        } else if time >= self.end {
+            self.total
+        } else {
+            // start <= cliff <= time < end, so the duration is never zero
+            let elapsed = time.nanos() - self.start.nanos();
+            let duration = self.end.nanos() - self.start.nanos();
+            self.total.multiply_ratio(elapsed, duration)
+        }
+    }
+
+    /// Amount that is vested at `time` but not released yet.
+    /// Zero when `time` is before the last release.
+    pub fn releasable(&self, time: Timestamp) -> Uint128 {
+        self.vested(time).saturating_sub(self.released)
+    }
+
+    /// Pays out the releasable funds from the escrow to the recipient and records them as released.
+    /// Returns `None` when there is nothing to release.
+    pub fn release(&mut self, time: Timestamp) -> AbstractSdkResult<Option<CosmosMsg>> {
+        let amount = self.releasable(time);
+        if amount.is_zero() {
+            return Ok(None);
+        }
+        self.released += amount;
+        let msg = Asset::new(self.asset.clone(), amount).transfer_msg(self.recipient.clone())?;
+        Ok(Some(msg))
+    }
 }
 
 /// Turn an object that represents an asset into the blockchain representation of an asset, i.e. [`Asset`].
@@ -270,6 +734,13 @@ mod test {
     use super::*;
     use crate::mock_module::*;
 
+    fn mock_deps_with_balance(balance: Vec<Coin>) -> MockDeps {
+        let mut deps = mock_dependencies();
+        deps.querier = MockAnsHost::new().with_defaults().to_querier();
+        deps.querier.update_balance(TEST_PROXY, balance);
+        deps
+    }
+
     mod transfer_coins {
         use abstract_std::proxy::ExecuteMsg;
 
@@ -309,6 +780,206 @@ mod test {
                 .into(),
             );
         }
+
+        #[test]
+        fn consolidates_duplicate_denoms() {
+            let app = MockModule::new();
+            let deps = mock_dependencies();
+
+            let recipient: Addr = Addr::unchecked("recipient");
+            let bank: Bank<'_, MockModule> = app.bank(deps.as_ref());
+            let funds = vec![
+                coin(10u128, "uosmo"),
+                coin(7u128, "uatom"),
+                coin(5u128, "uosmo"),
+            ];
+            let bank_transfer: AccountAction = bank.transfer(funds, &recipient).unwrap();
+
+            let expected_msgs: Vec<CosmosMsg> = vec![
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: recipient.to_string(),
+                    amount: coins(15u128, "uosmo"),
+                }),
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: recipient.to_string(),
+                    amount: coins(7u128, "uatom"),
+                }),
+            ];
+            assert_that!(bank_transfer.messages()).is_equal_to(expected_msgs);
+        }
+    }
+
+    mod transfer_isolated {
+        use abstract_std::proxy::ExecuteMsg;
+
+        use super::*;
+
+        #[test]
+        fn submsg_per_recipient() {
+            let app = MockModule::new();
+            let deps = mock_dependencies();
+
+            let bank = app.bank(deps.as_ref());
+            let payments = vec![
+                (coins(100u128, "asset"), Addr::unchecked("alice")),
+                (coins(50u128, "asset"), Addr::unchecked("bob")),
+            ];
+            let transfers = bank.transfer_isolated(payments.clone(), 10).unwrap();
+
+            let expected_transfers: Vec<SubMsg> = payments
+                .into_iter()
+                .enumerate()
+                .map(|(index, (amount, recipient))| SubMsg {
+                    id: 10 + index as u64,
+                    msg: wasm_execute(
+                        TEST_PROXY,
+                        &ExecuteMsg::ModuleAction {
+                            msgs: vec![CosmosMsg::Bank(BankMsg::Send {
+                                to_address: recipient.to_string(),
+                                amount,
+                            })],
+                        },
+                        vec![],
+                    )
+                    .unwrap()
+                    .into(),
+                    gas_limit: None,
+                    reply_on: ReplyOn::Error,
+                })
+                .collect();
+            assert_that!(transfers).is_equal_to(expected_transfers);
+        }
+
+        #[test]
+        fn reply_id_overflow() {
+            let app = MockModule::new();
+            let deps = mock_dependencies();
+
+            let bank = app.bank(deps.as_ref());
+            let payments = vec![
+                (coins(100u128, "asset"), Addr::unchecked("alice")),
+                (coins(50u128, "asset"), Addr::unchecked("bob")),
+            ];
+            let res = bank.transfer_isolated(payments, u64::MAX);
+
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(AbstractSdkError::ReplyIdOverflow {
+                    offset: u64::MAX,
+                    index: 1,
+                });
+        }
+    }
+
+    mod transfer_assets {
+        use super::*;
+
+        #[test]
+        fn transfer_mixed_asset_list() {
+            let app = MockModule::new();
+            let deps = mock_dependencies();
+
+            let recipient: Addr = Addr::unchecked("recipient");
+            let bank: Bank<'_, MockModule> = app.bank(deps.as_ref());
+            let assets = AssetList::from(vec![
+                Asset::native("foo", 100u128),
+                Asset::cw20(Addr::unchecked("cw20"), 10u128),
+                Asset::native("bar", 50u128),
+            ]);
+            let bank_transfer: AccountAction = bank.transfer_assets(assets, &recipient).unwrap();
+
+            let expected_msgs: Vec<CosmosMsg> = vec![
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: recipient.to_string(),
+                    amount: vec![coin(100u128, "foo"), coin(50u128, "bar")],
+                }),
+                wasm_execute(
+                    "cw20",
+                    &cw20::Cw20ExecuteMsg::Transfer {
+                        recipient: recipient.to_string(),
+                        amount: 10u128.into(),
+                    },
+                    vec![],
+                )
+                .unwrap()
+                .into(),
+            ];
+            assert_that!(bank_transfer.messages()).is_equal_to(expected_msgs);
+        }
+    }
+
+    mod transfer_to_many {
+        use abstract_std::proxy::ExecuteMsg;
+
+        use super::*;
+        use crate::{Execution, Executor, ExecutorMsg};
+
+        #[test]
+        fn transfer_assets_grouped_per_recipient() {
+            let app = MockModule::new();
+            let deps = mock_dependencies();
+
+            let alice: Addr = Addr::unchecked("alice");
+            let bob: Addr = Addr::unchecked("bob");
+            let bank: Bank<'_, MockModule> = app.bank(deps.as_ref());
+            let bank_transfer: AccountAction = bank
+                .transfer_to_many(vec![
+                    (vec![Asset::native("foo", 100u128)], alice.clone()),
+                    (
+                        vec![Asset::cw20(Addr::unchecked("cw20"), 10u128)],
+                        bob.clone(),
+                    ),
+                    (
+                        vec![
+                            Asset::native("bar", 50u128),
+                            Asset::native("foo", 25u128),
+                            Asset::cw20(Addr::unchecked("cw20"), 5u128),
+                        ],
+                        alice.clone(),
+                    ),
+                ])
+                .unwrap();
+
+            let executor: Executor<'_, MockModule> = app.executor(deps.as_ref());
+            let account_message: ExecutorMsg = executor.execute(vec![bank_transfer]).unwrap();
+            let response: Response = Response::new().add_message(account_message);
+
+            let expected_msgs: Vec<CosmosMsg> = vec![
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: alice.to_string(),
+                    amount: vec![coin(125u128, "foo"), coin(50u128, "bar")],
+                }),
+                Asset::cw20(Addr::unchecked("cw20"), 5u128)
+                    .transfer_msg(&alice)
+                    .unwrap(),
+                Asset::cw20(Addr::unchecked("cw20"), 10u128)
+                    .transfer_msg(&bob)
+                    .unwrap(),
+            ];
+
+            assert_that!(response.messages[0].msg).is_equal_to(
+                &wasm_execute(
+                    TEST_PROXY,
+                    &ExecuteMsg::ModuleAction {
+                        msgs: expected_msgs,
+                    },
+                    vec![],
+                )
+                .unwrap()
+                .into(),
+            );
+        }
+
+        #[test]
+        fn no_payments() {
+            let app = MockModule::new();
+            let deps = mock_dependencies();
+
+            let bank = app.bank(deps.as_ref());
+            let bank_transfer = bank.transfer_to_many::<Coin>(vec![]).unwrap();
+
+            assert_that!(bank_transfer.messages()).is_empty();
+        }
     }
 
     // transfer must be tested via integration test
@@ -340,6 +1011,99 @@ mod test {
 
             assert_that!(response.messages[0].msg).is_equal_to::<CosmosMsg>(bank_msg);
         }
+
+        #[test]
+        fn deposit_from_owner() {
+            let app = MockModule::new();
+            let deps = mock_dependencies();
+
+            let owner = Addr::unchecked("owner");
+            let bank: Bank<'_, MockModule> = app.bank(deps.as_ref());
+            let deposit_msgs = bank
+                .deposit_from(&owner, vec![Asset::cw20(Addr::unchecked("cw20"), 10u128)])
+                .unwrap();
+
+            let expected_msg: CosmosMsg = wasm_execute(
+                "cw20",
+                &cw20::Cw20ExecuteMsg::TransferFrom {
+                    owner: owner.to_string(),
+                    recipient: TEST_PROXY.to_string(),
+                    amount: 10u128.into(),
+                },
+                vec![],
+            )
+            .unwrap()
+            .into();
+            assert_that!(deposit_msgs).is_equal_to(vec![expected_msg]);
+        }
+
+        #[test]
+        fn deposit_from_rejects_native() {
+            let app = MockModule::new();
+            let deps = mock_dependencies();
+
+            let owner = Addr::unchecked("owner");
+            let bank: Bank<'_, MockModule> = app.bank(deps.as_ref());
+            let res = bank.deposit_from(&owner, coins(100u128, "denom"));
+
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(AbstractSdkError::CannotPullNativeAsset {
+                    asset: "native:denom:100".to_string(),
+                    owner,
+                });
+        }
+    }
+
+    mod burn {
+        use super::*;
+
+        #[test]
+        fn burn_native() {
+            let app = MockModule::new();
+            let deps = mock_dependencies();
+
+            let bank = app.bank(deps.as_ref());
+            let burn_action = bank.burn(coins(100u128, "denom")).unwrap();
+
+            let expected_msg: CosmosMsg = CosmosMsg::Bank(BankMsg::Burn {
+                amount: coins(100u128, "denom"),
+            });
+            assert_that!(burn_action.messages()).is_equal_to(vec![expected_msg]);
+        }
+
+        #[test]
+        fn burn_cw20() {
+            let app = MockModule::new();
+            let deps = mock_dependencies();
+
+            let bank = app.bank(deps.as_ref());
+            let burn_action = bank
+                .burn(vec![Asset::cw20(Addr::unchecked("cw20"), 10u128)])
+                .unwrap();
+
+            let expected_msg: CosmosMsg = wasm_execute(
+                "cw20",
+                &cw20::Cw20ExecuteMsg::Burn {
+                    amount: 10u128.into(),
+                },
+                vec![],
+            )
+            .unwrap()
+            .into();
+            assert_that!(burn_action.messages()).is_equal_to(vec![expected_msg]);
+        }
+
+        #[test]
+        fn burn_nothing() {
+            let app = MockModule::new();
+            let deps = mock_dependencies();
+
+            let bank = app.bank(deps.as_ref());
+            let burn_action = bank.burn::<Coin>(vec![]).unwrap();
+
+            assert_that!(burn_action.messages()).is_empty();
+        }
     }
 
     mod withdraw_coins {
@@ -365,6 +1129,459 @@ mod test {
         }
     }
 
+    mod vest {
+        use super::*;
+
+        fn schedule() -> VestingSchedule {
+            VestingSchedule::new(
+                Addr::unchecked("recipient"),
+                Asset::native("asset", 1000u128),
+                Timestamp::from_seconds(100),
+                Timestamp::from_seconds(200),
+                Timestamp::from_seconds(500),
+            )
+            .unwrap()
+        }
+
+        #[test]
+        fn funds_escrow_from_account() {
+            let app = MockModule::new();
+            let deps = mock_dependencies();
+            let env = mock_env();
+
+            let bank = app.bank(deps.as_ref());
+            let (funding, schedule) = bank
+                .vest(
+                    &env,
+                    &Addr::unchecked("recipient"),
+                    coin(1000, "asset"),
+                    Timestamp::from_seconds(100),
+                    Timestamp::from_seconds(500),
+                    Timestamp::from_seconds(200),
+                )
+                .unwrap();
+
+            let expected_msg: CosmosMsg = CosmosMsg::Bank(BankMsg::Send {
+                to_address: env.contract.address.to_string(),
+                amount: coins(1000, "asset"),
+            });
+            assert_that!(funding.messages()).is_equal_to(vec![expected_msg]);
+            assert_that!(schedule).is_equal_to(self::schedule());
+        }
+
+        #[test]
+        fn rejects_cliff_after_end() {
+            let res = VestingSchedule::new(
+                Addr::unchecked("recipient"),
+                Asset::native("asset", 1000u128),
+                Timestamp::from_seconds(100),
+                Timestamp::from_seconds(600),
+                Timestamp::from_seconds(500),
+            );
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(AbstractSdkError::InvalidVestingSchedule {
+                    start: Timestamp::from_seconds(100),
+                    cliff: Timestamp::from_seconds(600),
+                    end: Timestamp::from_seconds(500),
+                });
+        }
+
+        #[test]
+        fn vests_linearly_after_cliff() {
+            let schedule = schedule();
+
+            assert_that!(schedule.vested(Timestamp::from_seconds(150)))
+                .is_equal_to(Uint128::zero());
+            // a quarter of the duration has passed at the cliff
+            assert_that!(schedule.vested(Timestamp::from_seconds(200)))
+                .is_equal_to(Uint128::new(250));
+            // rounds down
+            assert_that!(schedule.vested(Timestamp::from_seconds(301)))
+                .is_equal_to(Uint128::new(502));
+            assert_that!(schedule.vested(Timestamp::from_seconds(600)))
+                .is_equal_to(Uint128::new(1000));
+        }
+
+        #[test]
+        fn releases_after_cliff() {
+            let mut schedule = schedule();
+
+            assert_that!(schedule.release(Timestamp::from_seconds(199)).unwrap()).is_none();
+
+            let msg = schedule.release(Timestamp::from_seconds(300)).unwrap();
+            assert_that!(msg).is_equal_to(Some(CosmosMsg::Bank(BankMsg::Send {
+                to_address: "recipient".to_owned(),
+                amount: coins(500, "asset"),
+            })));
+            assert_that!(schedule.released).is_equal_to(Uint128::new(500));
+
+            // only the newly vested funds are released
+            let msg = schedule.release(Timestamp::from_seconds(400)).unwrap();
+            assert_that!(msg).is_equal_to(Some(CosmosMsg::Bank(BankMsg::Send {
+                to_address: "recipient".to_owned(),
+                amount: coins(250, "asset"),
+            })));
+            assert_that!(schedule.release(Timestamp::from_seconds(400)).unwrap()).is_none();
+        }
+
+        #[test]
+        fn nothing_releasable_before_last_release() {
+            let mut schedule = schedule();
+            schedule.release(Timestamp::from_seconds(400)).unwrap();
+
+            assert_that!(schedule.releasable(Timestamp::from_seconds(300)))
+                .is_equal_to(Uint128::zero());
+            assert_that!(schedule.release(Timestamp::from_seconds(300)).unwrap()).is_none();
+            assert_that!(schedule.released).is_equal_to(Uint128::new(750));
+        }
+    }
+
+    mod portfolio {
+        use abstract_std::{
+            objects::price_source::PriceSource,
+            proxy::{AssetsInfoResponse, OracleAsset, QueryMsg},
+        };
+        use cw_asset::AssetInfo;
+
+        use super::*;
+
+        #[test]
+        fn balances_and_total_value() {
+            let app = MockModule::new();
+            let mut deps = mock_dependencies();
+            deps.querier = MockQuerierBuilder::default()
+                .with_smart_handler(TEST_PROXY, |msg| match from_json(msg).unwrap() {
+                    QueryMsg::AssetsInfo { .. } => to_json_binary(&AssetsInfoResponse {
+                        assets: vec![
+                            (
+                                AssetInfo::native("base"),
+                                OracleAsset {
+                                    price_source: PriceSource::None,
+                                    complexity: 0,
+                                },
+                            ),
+                            (
+                                AssetInfo::native("other"),
+                                OracleAsset {
+                                    price_source: PriceSource::ValueAs {
+                                        asset: AssetInfo::native("base"),
+                                        multiplier: Decimal::percent(50),
+                                    },
+                                    complexity: 1,
+                                },
+                            ),
+                        ],
+                    })
+                    .map_err(|e| e.to_string()),
+                    QueryMsg::TotalValue {} => to_json_binary(&AccountValue {
+                        total_value: Asset::native("base", 150u128),
+                        breakdown: vec![
+                            (AssetInfo::native("base"), Uint128::new(100)),
+                            (AssetInfo::native("other"), Uint128::new(50)),
+                        ],
+                    })
+                    .map_err(|e| e.to_string()),
+                    _ => panic!("unexpected query"),
+                })
+                .build();
+            deps.querier
+                .update_balance(TEST_PROXY, vec![coin(100, "base"), coin(100, "other")]);
+
+            let bank = app.bank(deps.as_ref());
+            let portfolio = bank.portfolio().unwrap();
+
+            assert_that!(portfolio.balances).is_equal_to(vec![
+                Asset::native("base", 100u128),
+                Asset::native("other", 100u128),
+            ]);
+            assert_that!(portfolio.total_value.breakdown).is_equal_to(vec![
+                (AssetInfo::native("base"), Uint128::new(100)),
+                (AssetInfo::native("other"), Uint128::new(50)),
+            ]);
+            assert_that!(portfolio.total_value.total_value)
+                .is_equal_to(Asset::native("base", 150u128));
+        }
+    }
+
+    mod can_afford {
+        use super::*;
+
+        #[test]
+        fn sufficient_balance() {
+            let app = MockModule::new();
+            let deps = mock_deps_with_balance(vec![coin(100, EUR), coin(50, USD)]);
+
+            let bank = app.bank(deps.as_ref());
+            let payouts = vec![
+                AnsAsset::new(EUR, 60u128),
+                AnsAsset::new(USD, 50u128),
+                AnsAsset::new(EUR, 40u128),
+            ];
+
+            assert_that!(bank.can_afford(&payouts)).is_ok().is_true();
+            assert_that!(bank.shortfall(&payouts)).is_ok().is_empty();
+        }
+
+        #[test]
+        fn insufficient_balance() {
+            let app = MockModule::new();
+            let deps = mock_deps_with_balance(vec![coin(100, EUR)]);
+
+            let bank = app.bank(deps.as_ref());
+            let payouts = vec![
+                AnsAsset::new(EUR, 60u128),
+                AnsAsset::new(USD, 10u128),
+                AnsAsset::new(EUR, 60u128),
+            ];
+
+            assert_that!(bank.can_afford(&payouts)).is_ok().is_false();
+            assert_that!(bank.shortfall(&payouts))
+                .is_ok()
+                .is_equal_to(vec![AnsAsset::new(EUR, 20u128), AnsAsset::new(USD, 10u128)]);
+        }
+    }
+
+    mod transfer_fraction {
+        use super::*;
+
+        #[test]
+        fn half_is_rounded_down() {
+            let app = MockModule::new();
+            let deps = mock_deps_with_balance(vec![coin(101, EUR)]);
+            let recipient = Addr::unchecked("recipient");
+
+            let bank = app.bank(deps.as_ref());
+            let transfer = bank
+                .transfer_fraction(&AssetEntry::new(EUR), Decimal::percent(50), &recipient)
+                .unwrap();
+
+            let expected_msg: CosmosMsg = BankMsg::Send {
+                to_address: recipient.to_string(),
+                amount: coins(50, EUR),
+            }
+            .into();
+            assert_that!(transfer.messages()).is_equal_to(vec![expected_msg]);
+        }
+
+        #[test]
+        fn full_balance() {
+            let app = MockModule::new();
+            let deps = mock_deps_with_balance(vec![coin(101, EUR)]);
+            let recipient = Addr::unchecked("recipient");
+
+            let bank = app.bank(deps.as_ref());
+            let transfer = bank
+                .transfer_fraction(&AssetEntry::new(EUR), Decimal::one(), &recipient)
+                .unwrap();
+
+            let expected_msg: CosmosMsg = BankMsg::Send {
+                to_address: recipient.to_string(),
+                amount: coins(101, EUR),
+            }
+            .into();
+            assert_that!(transfer.messages()).is_equal_to(vec![expected_msg]);
+        }
+
+        #[test]
+        fn rounded_to_zero_is_skipped() {
+            let app = MockModule::new();
+            let deps = mock_deps_with_balance(vec![coin(101, EUR)]);
+            let recipient = Addr::unchecked("recipient");
+
+            let bank = app.bank(deps.as_ref());
+            for fraction in [Decimal::zero(), Decimal::permille(1)] {
+                let transfer = bank
+                    .transfer_fraction(&AssetEntry::new(EUR), fraction, &recipient)
+                    .unwrap();
+                assert_that!(transfer.messages()).is_empty();
+            }
+        }
+
+        #[test]
+        fn more_than_balance() {
+            let app = MockModule::new();
+            let deps = mock_deps_with_balance(vec![coin(101, EUR)]);
+
+            let bank = app.bank(deps.as_ref());
+            let res = bank.transfer_fraction(
+                &AssetEntry::new(EUR),
+                Decimal::percent(150),
+                &Addr::unchecked("recipient"),
+            );
+
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(AbstractSdkError::InvalidTransferFraction {
+                    fraction: Decimal::percent(150),
+                });
+        }
+    }
+
+    mod held_denom_count {
+        use super::*;
+
+        #[test]
+        fn counts_held_denoms() {
+            let app = MockModule::new();
+            let mut deps = mock_dependencies();
+            deps.querier.update_balance(
+                TEST_PROXY,
+                vec![coin(100, EUR), coin(50, USD), coin(1, "udust")],
+            );
+
+            let bank = app.bank(deps.as_ref());
+
+            assert_that!(bank.held_denom_count()).is_ok().is_equal_to(3);
+        }
+
+        #[test]
+        fn empty_account() {
+            let app = MockModule::new();
+            let deps = mock_dependencies();
+
+            let bank = app.bank(deps.as_ref());
+
+            assert_that!(bank.held_denom_count()).is_ok().is_equal_to(0);
+        }
+    }
+
+    mod sweep_to {
+        use abstract_std::{
+            objects::price_source::PriceSource,
+            proxy::{AssetsInfoResponse, OracleAsset, QueryMsg},
+        };
+
+        use super::*;
+
+        #[test]
+        fn sweeps_all_assets() {
+            let app = MockModule::new();
+            let mut deps = mock_dependencies();
+            deps.querier = MockQuerierBuilder::default()
+                .with_smart_handler(TEST_PROXY, |msg| match from_json(msg).unwrap() {
+                    QueryMsg::AssetsInfo { .. } => to_json_binary(&AssetsInfoResponse {
+                        assets: vec![
+                            (
+                                AssetInfo::native("denom0"),
+                                OracleAsset {
+                                    price_source: PriceSource::None,
+                                    complexity: 0,
+                                },
+                            ),
+                            (
+                                AssetInfo::cw20(Addr::unchecked("token")),
+                                OracleAsset {
+                                    price_source: PriceSource::None,
+                                    complexity: 0,
+                                },
+                            ),
+                            (
+                                AssetInfo::cw20(Addr::unchecked("empty_token")),
+                                OracleAsset {
+                                    price_source: PriceSource::None,
+                                    complexity: 0,
+                                },
+                            ),
+                        ],
+                    })
+                    .map_err(|e| e.to_string()),
+                    _ => panic!("unexpected query"),
+                })
+                .with_smart_handler("token", |_| {
+                    to_json_binary(&cw20::BalanceResponse {
+                        balance: Uint128::new(42),
+                    })
+                    .map_err(|e| e.to_string())
+                })
+                .with_smart_handler("empty_token", |_| {
+                    to_json_binary(&cw20::BalanceResponse {
+                        balance: Uint128::zero(),
+                    })
+                    .map_err(|e| e.to_string())
+                })
+                .build();
+            let balances: Vec<Coin> = (0..=MAX_SWEEP_COINS_PER_SEND)
+                .map(|i| coin(100, format!("denom{i}")))
+                .collect();
+            deps.querier.update_balance(TEST_PROXY, balances.clone());
+
+            let destination = Addr::unchecked("destination_proxy");
+            let bank = app.bank(deps.as_ref());
+            let sweep = bank.sweep_to(&destination).unwrap();
+
+            let (first, rest) = balances.split_at(MAX_SWEEP_COINS_PER_SEND);
+            let expected_msgs: Vec<CosmosMsg> = vec![
+                BankMsg::Send {
+                    to_address: destination.to_string(),
+                    amount: first.to_vec(),
+                }
+                .into(),
+                BankMsg::Send {
+                    to_address: destination.to_string(),
+                    amount: rest.to_vec(),
+                }
+                .into(),
+                Asset::cw20(Addr::unchecked("token"), 42u128)
+                    .transfer_msg(&destination)
+                    .unwrap(),
+            ];
+            assert_that!(sweep.messages()).is_equal_to(expected_msgs);
+        }
+
+        #[test]
+        fn empty_account() {
+            let app = MockModule::new();
+            let mut deps = mock_dependencies();
+            deps.querier = MockQuerierBuilder::default()
+                .with_smart_handler(TEST_PROXY, |_| {
+                    to_json_binary(&AssetsInfoResponse { assets: vec![] })
+                        .map_err(|e| e.to_string())
+                })
+                .build();
+
+            let bank = app.bank(deps.as_ref());
+            let sweep = bank
+                .sweep_to(&Addr::unchecked("destination_proxy"))
+                .unwrap();
+
+            assert_that!(sweep.messages()).is_empty();
+        }
+    }
+
+    mod balance_at {
+        use super::*;
+
+        #[test]
+        fn unsupported_without_archive() {
+            let app = MockModule::new();
+            let mut deps = mock_dependencies();
+            deps.querier = MockAnsHost::new().with_defaults().to_querier();
+
+            let bank = app.bank(deps.as_ref());
+
+            assert_that!(bank.balance_at(&AssetEntry::new(EUR), 10))
+                .is_err()
+                .is_equal_to(AbstractSdkError::HistoricalQueryUnsupported { height: 10 });
+        }
+
+        #[test]
+        fn mock_archive_returns_current_balance() {
+            let app = MockModule::new();
+            let mut deps = mock_dependencies();
+            deps.querier = MockAnsHost::new().with_defaults().to_querier();
+            deps.querier
+                .update_balance(TEST_PROXY, vec![coin(100, EUR)]);
+
+            let archive = MockArchiveQuerier::new(deps.as_ref().querier);
+            let bank = app.bank(deps.as_ref()).with_archive(&archive);
+
+            assert_that!(bank.balance_at(&AssetEntry::new(EUR), 10))
+                .is_ok()
+                .is_equal_to(bank.balance(&AssetEntry::new(EUR)).unwrap());
+        }
+    }
+
     mod send_coins {
         use super::*;
 
@@ -416,5 +1633,62 @@ mod test {
                 }),
             );
         }
+
+        #[test]
+        fn send_cw20_with_reply() {
+            let app = MockModule::new();
+            let deps = mock_dependencies();
+            let expected_recipient = Addr::unchecked("recipient");
+
+            let bank = app.bank(deps.as_ref());
+            let hook_msg = Empty {};
+            let asset = Addr::unchecked("asset");
+            let coin = Asset::cw20(asset.clone(), 100u128);
+            let actual_res = bank.send_with_reply(
+                coin.clone(),
+                &expected_recipient,
+                &hook_msg,
+                ReplyOn::Success,
+                42,
+            );
+
+            let expected_action = bank.send(coin, &expected_recipient, &hook_msg).unwrap();
+            let expected = SubMsg {
+                id: 42,
+                msg: wasm_execute(
+                    TEST_PROXY,
+                    &abstract_std::proxy::ExecuteMsg::ModuleAction {
+                        msgs: expected_action.messages(),
+                    },
+                    vec![],
+                )
+                .unwrap()
+                .into(),
+                gas_limit: None,
+                reply_on: ReplyOn::Success,
+            };
+            assert_that!(actual_res).is_ok().is_equal_to(expected);
+        }
+
+        #[test]
+        fn send_coins_with_reply() {
+            let app = MockModule::new();
+            let deps = mock_dependencies();
+
+            let bank = app.bank(deps.as_ref());
+            let actual_res = bank.send_with_reply(
+                coin(100u128, "asset"),
+                &Addr::unchecked("recipient"),
+                &Empty {},
+                ReplyOn::Always,
+                1,
+            );
+
+            assert_that!(actual_res.unwrap_err()).is_equal_to::<AbstractSdkError>(
+                AbstractSdkError::Asset(AssetError::UnavailableMethodForNative {
+                    method: "send".into(),
+                }),
+            );
+        }
     }
 }
@@ -4,12 +4,14 @@
 
 use abstract_macros::with_abstract_event;
 use abstract_std::proxy::ExecuteMsg;
-use cosmwasm_std::{CosmosMsg, Deps, ReplyOn, Response, SubMsg};
+use cosmwasm_std::{
+    coin, Addr, BankMsg, Coin, CosmosMsg, Deps, ReplyOn, Response, SubMsg, Uint128, WasmMsg,
+};
 
 use super::{AbstractApi, ApiIdentification};
 use crate::{
     features::{AccountExecutor, ModuleIdentification},
-    AbstractSdkResult, AccountAction,
+    AbstractSdkError, AbstractSdkResult, AccountAction,
 };
 
 /// Execute an `AccountAction` on the Account.
@@ -95,6 +97,46 @@ impl<'a, T: Execution> Executor<'a, T> {
         Ok(ExecutorMsg(msg))
     }
 
+    /// Execute the msgs on the Account after sending a `fee` from the Account to the `treasury`.
+    /// The fee transfer is executed first, in the same proxy call as the actions.
+    /// A zero-amount fee is skipped. Errors if the Account doesn't hold enough of the fee denom
+    /// to pay the fee and the amount of that denom the actions send out through bank sends and
+    /// wasm execute/instantiate funds.
+    pub fn execute_with_fee(
+        &self,
+        actions: Vec<AccountAction>,
+        fee: Coin,
+        treasury: &Addr,
+    ) -> AbstractSdkResult<ExecutorMsg> {
+        if fee.amount.is_zero() {
+            return self.execute(actions);
+        }
+        let proxy_addr = self.base.proxy_address(self.deps)?;
+        let balance = self.deps.querier.query_balance(&proxy_addr, &fee.denom)?;
+        let spent = coin(
+            actions
+                .iter()
+                .flat_map(|a| a.messages())
+                .map(|msg| native_spend(&msg, &fee.denom))
+                .sum::<Uint128>()
+                .u128(),
+            &fee.denom,
+        );
+        if balance.amount < fee.amount + spent.amount {
+            return Err(AbstractSdkError::InsufficientFeeBalance {
+                proxy_addr,
+                fee,
+                spent,
+                balance,
+            });
+        }
+        let fee_action = AccountAction::from(CosmosMsg::Bank(BankMsg::Send {
+            to_address: treasury.to_string(),
+            amount: vec![fee],
+        }));
+        self.execute(std::iter::once(fee_action).chain(actions).collect())
+    }
+
     /// Execute the msgs on the Account.
     /// These messages will be executed on the proxy contract and the sending module must be whitelisted.
     /// The execution will be executed in a submessage and the reply will be sent to the provided `reply_on`.
@@ -114,6 +156,20 @@ impl<'a, T: Execution> Executor<'a, T> {
         Ok(sub_msg)
     }
 
+    /// Execute the msgs on the Account in a submessage that can use at most `gas_limit` gas.
+    /// Works like [`Executor::execute_with_reply`], use it to cap the gas of risky calls
+    /// so running out of gas only fails the submessage instead of draining the whole transaction.
+    pub fn execute_with_reply_and_gas_limit(
+        &self,
+        actions: Vec<AccountAction>,
+        reply_on: ReplyOn,
+        id: u64,
+        gas_limit: u64,
+    ) -> AbstractSdkResult<SubMsg> {
+        let sub_msg = self.execute_with_reply(actions, reply_on, id)?;
+        Ok(sub_msg.with_gas_limit(gas_limit))
+    }
+
     /// Execute a single msg on the Account.
     /// This message will be executed on the proxy contract. Any data returned from the execution will be forwarded to the proxy's response through a reply.
     /// The resulting data should be available in the reply of the specified ID.
@@ -133,6 +189,27 @@ impl<'a, T: Execution> Executor<'a, T> {
         Ok(sub_msg)
     }
 
+    /// Execute the actions on the Account in a submessage that reflects the execution data.
+    /// Works like [`Executor::execute_with_reply`] but the proxy forwards the `data` of the executed message
+    /// so it can be decoded in the reply of the specified ID.
+    ///
+    /// The proxy can only forward the data of a single message, so the actions must contain exactly one message.
+    pub fn execute_with_reply_with_data(
+        &self,
+        actions: Vec<AccountAction>,
+        reply_on: ReplyOn,
+        id: u64,
+    ) -> AbstractSdkResult<SubMsg> {
+        let mut msgs: Vec<CosmosMsg> = actions.into_iter().flat_map(|a| a.messages()).collect();
+        if msgs.len() != 1 {
+            return Err(AbstractSdkError::generic_err(format!(
+                "Expected exactly one message to reflect data from, got {}",
+                msgs.len()
+            )));
+        }
+        self.execute_with_reply_and_data(msgs.remove(0), reply_on, id)
+    }
+
     /// Execute the msgs on the Account.
     /// These messages will be executed on the proxy contract and the sending module must be whitelisted.
     /// Return a "standard" response for the executed messages. (with the provided action).
@@ -148,6 +225,22 @@ impl<'a, T: Execution> Executor<'a, T> {
     }
 }
 
+/// Amount of `denom` that `msg` sends out of the Account.
+fn native_spend(msg: &CosmosMsg, denom: &str) -> Uint128 {
+    let funds = match msg {
+        CosmosMsg::Bank(BankMsg::Send { amount, .. }) => amount,
+        CosmosMsg::Wasm(WasmMsg::Execute { funds, .. })
+        | CosmosMsg::Wasm(WasmMsg::Instantiate { funds, .. })
+        | CosmosMsg::Wasm(WasmMsg::Instantiate2 { funds, .. }) => funds,
+        _ => return Uint128::zero(),
+    };
+    funds
+        .iter()
+        .filter(|c| c.denom == denom)
+        .map(|c| c.amount)
+        .sum()
+}
+
 /// CosmosMsg from the executor methods
 #[must_use = "ExecutorMsg should be provided to Response::add_message"]
 #[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq, Eq))]
@@ -231,6 +324,101 @@ mod test {
         }
     }
 
+    mod execute_with_fee {
+        use super::*;
+
+        fn treasury() -> Addr {
+            Addr::unchecked("treasury")
+        }
+
+        #[test]
+        fn fee_is_sent_first() {
+            let mut deps = mock_dependencies();
+            deps.querier.update_balance(TEST_PROXY, coins(100, "juno"));
+            let stub = MockModule::new();
+            let executor = stub.executor(deps.as_ref());
+
+            let actions = vec![mock_bank_send(coins(50, "juno"))];
+            let actual_res =
+                executor.execute_with_fee(actions.clone(), coin(10, "juno"), &treasury());
+
+            let mut expected_msgs = vec![CosmosMsg::Bank(BankMsg::Send {
+                to_address: treasury().to_string(),
+                amount: coins(10, "juno"),
+            })];
+            expected_msgs.extend(flatten_actions(actions));
+            let expected = ExecutorMsg(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: TEST_PROXY.to_string(),
+                msg: to_json_binary(&ExecuteMsg::ModuleAction {
+                    msgs: expected_msgs,
+                })
+                .unwrap(),
+                funds: vec![],
+            }));
+            assert_that!(actual_res).is_ok().is_equal_to(expected);
+        }
+
+        #[test]
+        fn zero_fee_is_skipped() {
+            let deps = mock_dependencies();
+            let stub = MockModule::new();
+            let executor = stub.executor(deps.as_ref());
+
+            let actions = vec![mock_bank_send(coins(50, "juno"))];
+            let actual_res =
+                executor.execute_with_fee(actions.clone(), coin(0, "juno"), &treasury());
+
+            let expected = executor.execute(actions).unwrap();
+            assert_that!(actual_res).is_ok().is_equal_to(expected);
+        }
+
+        #[test]
+        fn insufficient_balance_errors() {
+            let mut deps = mock_dependencies();
+            deps.querier.update_balance(TEST_PROXY, coins(5, "juno"));
+            let stub = MockModule::new();
+            let executor = stub.executor(deps.as_ref());
+
+            let actual_res = executor.execute_with_fee(vec![], coin(10, "juno"), &treasury());
+
+            assert_that!(actual_res).is_err().is_equal_to(
+                AbstractSdkError::InsufficientFeeBalance {
+                    proxy_addr: Addr::unchecked(TEST_PROXY),
+                    fee: coin(10, "juno"),
+                    spent: coin(0, "juno"),
+                    balance: coin(5, "juno"),
+                },
+            );
+        }
+
+        #[test]
+        fn fee_and_action_spend_must_fit_in_balance() {
+            let mut deps = mock_dependencies();
+            deps.querier.update_balance(TEST_PROXY, coins(100, "juno"));
+            let stub = MockModule::new();
+            let executor = stub.executor(deps.as_ref());
+
+            let actions = vec![
+                mock_bank_send(vec![coin(60, "juno"), coin(500, "osmo")]),
+                AccountAction::from(CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: "contract".to_string(),
+                    msg: Binary::default(),
+                    funds: coins(35, "juno"),
+                })),
+            ];
+            let actual_res = executor.execute_with_fee(actions, coin(10, "juno"), &treasury());
+
+            assert_that!(actual_res).is_err().is_equal_to(
+                AbstractSdkError::InsufficientFeeBalance {
+                    proxy_addr: Addr::unchecked(TEST_PROXY),
+                    fee: coin(10, "juno"),
+                    spent: coin(95, "juno"),
+                    balance: coin(100, "juno"),
+                },
+            );
+        }
+    }
+
     mod execute_with_reply {
         use super::*;
 
@@ -305,6 +493,93 @@ mod test {
         }
     }
 
+    mod execute_with_reply_and_gas_limit {
+        use super::*;
+
+        #[test]
+        fn sets_gas_limit() {
+            let deps = mock_dependencies();
+            let stub = MockModule::new();
+            let executor = stub.executor(deps.as_ref());
+
+            let actions = vec![mock_bank_send(coins(100, "juno"))];
+
+            let actual_res = executor.execute_with_reply_and_gas_limit(
+                actions.clone(),
+                ReplyOn::Always,
+                7,
+                500_000,
+            );
+
+            let expected = SubMsg {
+                id: 7,
+                msg: CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: TEST_PROXY.to_string(),
+                    msg: to_json_binary(&ExecuteMsg::ModuleAction {
+                        msgs: flatten_actions(actions),
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }),
+                gas_limit: Some(500_000),
+                reply_on: ReplyOn::Always,
+            };
+            assert_that!(actual_res).is_ok().is_equal_to(expected);
+        }
+    }
+
+    mod execute_with_reply_with_data {
+        use super::*;
+
+        #[test]
+        fn reflects_data() {
+            let deps = mock_dependencies();
+            let stub = MockModule::new();
+            let executor = stub.executor(deps.as_ref());
+
+            let action = vec![mock_bank_send(coins(1, "denom"))];
+            let expected_reply_on = ReplyOn::Success;
+            let expected_reply_id = 42;
+
+            let actual_res = executor.execute_with_reply_with_data(
+                action.clone(),
+                expected_reply_on.clone(),
+                expected_reply_id,
+            );
+            assert_that!(actual_res).is_ok();
+
+            let expected = SubMsg {
+                id: expected_reply_id,
+                msg: CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: TEST_PROXY.to_string(),
+                    msg: to_json_binary(&ExecuteMsg::ModuleActionWithData {
+                        msg: flatten_actions(action).remove(0),
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }),
+                gas_limit: None,
+                reply_on: expected_reply_on,
+            };
+            assert_that!(actual_res.unwrap()).is_equal_to(expected);
+        }
+
+        #[test]
+        fn multiple_messages_errors() {
+            let deps = mock_dependencies();
+            let stub = MockModule::new();
+            let executor = stub.executor(deps.as_ref());
+
+            let actions = vec![
+                mock_bank_send(coins(1, "denom")),
+                mock_bank_send(coins(2, "denom")),
+            ];
+
+            let actual_res = executor.execute_with_reply_with_data(actions, ReplyOn::Success, 1);
+            assert_that!(actual_res).is_err();
+        }
+    }
+
     mod execute_with_response {
         use super::*;
 
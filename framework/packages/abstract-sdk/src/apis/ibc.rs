@@ -40,7 +40,11 @@ pub trait IbcInterface:
         ```
     */
     fn ibc_client<'a>(&'a self, deps: Deps<'a>) -> IbcClient<Self> {
-        IbcClient { base: self, deps }
+        IbcClient {
+            base: self,
+            deps,
+            timeout_seconds: None,
+        }
     }
 }
 
@@ -82,9 +86,16 @@ impl<'a, T: IbcInterface> ApiIdentification for IbcClient<'a, T> {
 pub struct IbcClient<'a, T: IbcInterface> {
     base: &'a T,
     deps: Deps<'a>,
+    timeout_seconds: Option<u64>,
 }
 
 impl<'a, T: IbcInterface> IbcClient<'a, T> {
+    /// Time out remote actions and ICS20 transfers after `timeout_seconds` instead of the IBC client's default.
+    pub fn with_timeout(mut self, timeout_seconds: u64) -> Self {
+        self.timeout_seconds = Some(timeout_seconds);
+        self
+    }
+
     /// Get address of this module
     pub fn module_address(&self) -> AbstractSdkResult<Addr> {
         let modules = self.base.modules(self.deps);
@@ -235,7 +246,11 @@ impl<'a, T: IbcInterface + AccountExecutor> IbcClient<'a, T> {
         Ok(wasm_execute(
             self.base.proxy_address(self.deps)?.to_string(),
             &ExecuteMsg::IbcAction {
-                msg: IbcClientMsg::RemoteAction { host_chain, action },
+                msg: IbcClientMsg::RemoteAction {
+                    host_chain,
+                    action,
+                    timeout_seconds: self.timeout_seconds,
+                },
             },
             vec![],
         )?
@@ -251,7 +266,11 @@ impl<'a, T: IbcInterface + AccountExecutor> IbcClient<'a, T> {
         Ok(wasm_execute(
             self.base.proxy_address(self.deps)?.to_string(),
             &ExecuteMsg::IbcAction {
-                msg: IbcClientMsg::SendFunds { host_chain, funds },
+                msg: IbcClientMsg::SendFunds {
+                    host_chain,
+                    funds,
+                    timeout_seconds: self.timeout_seconds,
+                },
             },
             vec![],
         )?
@@ -369,6 +388,7 @@ mod test {
                             is_suspended: None,
                         }],
                     },
+                    timeout_seconds: None,
                 },
             })
             .unwrap(),
@@ -395,6 +415,7 @@ mod test {
                 msg: IbcClientMsg::SendFunds {
                     host_chain: TEST_HOST_CHAIN.parse().unwrap(),
                     funds: expected_funds,
+                    timeout_seconds: None,
                 },
             })
             .unwrap(),
@@ -403,4 +424,48 @@ mod test {
         });
         assert_that!(msg.unwrap()).is_equal_to::<CosmosMsg>(expected);
     }
+
+    /// Tests that a custom timeout is passed to the IBC client
+    #[test]
+    fn test_with_timeout() {
+        let deps = mock_dependencies();
+        let stub = MockModule::new();
+        let client = stub.ibc_client(deps.as_ref()).with_timeout(600);
+
+        let action = HostAction::Dispatch {
+            manager_msgs: vec![abstract_std::manager::ExecuteMsg::UpdateStatus {
+                is_suspended: None,
+            }],
+        };
+        let msg = client.host_action(TEST_HOST_CHAIN.parse().unwrap(), action.clone());
+        let expected = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: TEST_PROXY.to_string(),
+            msg: to_json_binary(&ExecuteMsg::IbcAction {
+                msg: IbcClientMsg::RemoteAction {
+                    host_chain: TEST_HOST_CHAIN.parse().unwrap(),
+                    action,
+                    timeout_seconds: Some(600),
+                },
+            })
+            .unwrap(),
+            funds: vec![],
+        });
+        assert_that!(msg).is_ok().is_equal_to::<CosmosMsg>(expected);
+
+        let funds = coins(100, "denom");
+        let msg = client.ics20_transfer(TEST_HOST_CHAIN.parse().unwrap(), funds.clone());
+        let expected = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: TEST_PROXY.to_string(),
+            msg: to_json_binary(&ExecuteMsg::IbcAction {
+                msg: IbcClientMsg::SendFunds {
+                    host_chain: TEST_HOST_CHAIN.parse().unwrap(),
+                    funds,
+                    timeout_seconds: Some(600),
+                },
+            })
+            .unwrap(),
+            funds: vec![],
+        });
+        assert_that!(msg).is_ok().is_equal_to::<CosmosMsg>(expected);
+    }
 }
@@ -61,6 +61,11 @@ pub type SudoHandlerFn<Module, CustomSudoMsg, Error> =
     fn(DepsMut, Env, Module, CustomSudoMsg) -> Result<Response, Error>;
 // ANCHOR_END: sudo
 
+// ANCHOR: uninstall
+/// Function signature for an uninstall handler.
+pub type UninstallHandlerFn<Module, Error> = fn(DepsMut, Env, Module) -> Result<Response, Error>;
+// ANCHOR_END: uninstall
+
 // ANCHOR: reply
 /// Function signature for a reply handler.
 pub type ReplyHandlerFn<Module, Error> = fn(DepsMut, Env, Module, Reply) -> Result<Response, Error>;
@@ -102,6 +107,8 @@ pub struct AbstractContract<Module: Handler + 'static, Error: From<AbstractSdkEr
     pub(crate) ibc_callback_handler: Option<IbcCallbackHandlerFn<Module, Error>>,
     /// Module IBC handler for passing messages between a module on different chains.
     pub(crate) module_ibc_handler: Option<ModuleIbcHandlerFn<Module, Error>>,
+    /// Handler called by the manager before the module is uninstalled.
+    pub(crate) uninstall_handler: Option<UninstallHandlerFn<Module, Error>>,
 }
 
 impl<Module, Error: From<AbstractSdkError>> AbstractContract<Module, Error>
@@ -123,6 +130,7 @@ where
             instantiate_handler: None,
             query_handler: None,
             module_ibc_handler: None,
+            uninstall_handler: None,
         }
     }
     /// Gets the cw2 version of the contract.
@@ -133,6 +141,17 @@ where
     pub fn info(&self) -> (ModuleId, VersionString, ModuleMetadata) {
         self.info
     }
+    /// Ids of all the registered reply handlers, base handlers first.
+    pub fn reply_ids(&self) -> Vec<u64> {
+        self.reply_handlers
+            .iter()
+            .flat_map(|handlers| handlers.iter().map(|(id, _)| *id))
+            .collect()
+    }
+    /// Whether an IBC callback handler is registered.
+    pub fn has_ibc_callback(&self) -> bool {
+        self.ibc_callback_handler.is_some()
+    }
     /// add dependencies to the contract
     pub const fn with_dependencies(mut self, dependencies: &'static [StaticDependency]) -> Self {
         self.dependencies = dependencies;
@@ -165,6 +184,15 @@ where
         self
     }
 
+    /// Add uninstall handler to the contract.
+    pub const fn with_uninstall(
+        mut self,
+        uninstall_handler: UninstallHandlerFn<Module, Error>,
+    ) -> Self {
+        self.uninstall_handler = Some(uninstall_handler);
+        self
+    }
+
     /// Add instantiate handler to the contract.
     pub const fn with_instantiate(
         mut self,
@@ -298,6 +326,7 @@ mod test {
         assert!(contract.execute_handler.is_none());
         assert!(contract.query_handler.is_none());
         assert!(contract.migrate_handler.is_none());
+        assert!(contract.uninstall_handler.is_none());
     }
 
     #[test]
@@ -362,6 +391,14 @@ mod test {
         assert!(contract.migrate_handler.is_some());
     }
 
+    #[test]
+    fn test_with_uninstall() {
+        let contract = MockAppContract::new("test_contract", "0.1.0", ModuleMetadata::default())
+            .with_uninstall(|_, _, _| Ok(Response::default().add_attribute("test", "uninstall")));
+
+        assert!(contract.uninstall_handler.is_some());
+    }
+
     #[test]
     fn test_with_reply_handlers() {
         const REPLY_ID: u64 = 50u64;
@@ -141,7 +141,7 @@ pub use execute::ExecuteEndpoint;
 pub use ibc_callback::IbcCallbackEndpoint;
 pub use instantiate::InstantiateEndpoint;
 pub use migrate::MigrateEndpoint;
-pub use modules_ibc::ModuleIbcEndpoint;
+pub use modules_ibc::{ModuleIbcEndpoint, MODULE_IBC_NONCE_WINDOW};
 pub use query::QueryEndpoint;
 pub use receive::ReceiveEndpoint;
 pub use reply::ReplyEndpoint;
@@ -1,7 +1,35 @@
 use crate::features::ModuleIdentification;
 use crate::{base::Handler, AbstractSdkError};
-use abstract_std::ibc::ModuleIbcMsg;
-use cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, Response};
+use abstract_std::{ibc::ModuleIbcMsg, objects::TruncatedChainId};
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, Response, Uint128};
+use cw_storage_plus::Map;
+
+/// Number of nonces below the highest received nonce that are still accepted from a sender.
+/// Polytone channels are unordered, so packets can arrive out of order.
+pub const MODULE_IBC_NONCE_WINDOW: u64 = 128;
+
+/// (source chain, sender) -> (highest nonce received, bitmap of received nonces in the window below it).
+/// Bit `i` of the bitmap is set if `highest - i` was received.
+const MODULE_IBC_NONCES: Map<(&TruncatedChainId, &str), (u64, Uint128)> =
+    Map::new("module_ibc_nonces");
+
+/// Record `nonce` in the window, returns `None` if it was already received or is older than the window.
+fn record_nonce((highest, seen): (u64, u128), nonce: u64) -> Option<(u64, u128)> {
+    if nonce > highest {
+        let shift = nonce - highest;
+        let seen = if shift >= MODULE_IBC_NONCE_WINDOW {
+            0
+        } else {
+            seen << shift
+        };
+        return Some((nonce, seen | 1));
+    }
+    let offset = highest - nonce;
+    if offset >= MODULE_IBC_NONCE_WINDOW || seen & (1 << offset) != 0 {
+        return None;
+    }
+    Some((highest, seen | (1 << offset)))
+}
 
 /// Trait for a contract to call itself on an IBC counterpart.
 pub trait ModuleIbcEndpoint: Handler {
@@ -33,6 +61,34 @@ pub trait ModuleIbcEndpoint: Handler {
                 .ok_or(AbstractSdkError::NoModuleIbcHandler(
                     self.module_id().to_string(),
                 ))?;
+
+        // Reject messages that were already delivered, before they reach the handler
+        if let Some(nonce) = msg.nonce {
+            // The source ibc-client assigns nonces per sending contract, which is the module version on an Account
+            let module = &msg.src_module_info.module;
+            let module = format!("{}:{}", module.id(), module.version);
+            let sender = match &msg.src_account_id {
+                Some(account_id) => format!("{account_id}:{module}"),
+                None => module,
+            };
+            let key = (&msg.src_module_info.chain, sender.as_str());
+            let (highest, seen) = MODULE_IBC_NONCES
+                .may_load(deps.storage, key)
+                .map_err(AbstractSdkError::from)?
+                .unwrap_or_default();
+            let Some((highest, seen)) = record_nonce((highest, seen.u128()), nonce) else {
+                return Err(AbstractSdkError::ModuleIbcNonceReplayed {
+                    chain: msg.src_module_info.chain.to_string(),
+                    sender,
+                    nonce,
+                }
+                .into());
+            };
+            MODULE_IBC_NONCES
+                .save(deps.storage, key, &(highest, Uint128::new(seen)))
+                .map_err(AbstractSdkError::from)?;
+        }
+
         handler(deps, env, self, msg.src_module_info, msg.msg)
     }
 }
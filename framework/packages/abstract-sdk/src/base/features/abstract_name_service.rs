@@ -1,11 +1,19 @@
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    collections::BTreeMap,
+};
+
 use abstract_std::{
     ans_host::{
         AssetPairingFilter, AssetPairingMapEntry, PoolAddressListResponse, QueryMsg,
         RegisteredDexesResponse,
     },
-    objects::{ans_host::AnsHost, DexAssetPairing},
+    objects::{ans_host::AnsHost, AssetEntry, DexAssetPairing},
 };
-use cosmwasm_std::Deps;
+use cosmwasm_std::{to_json_vec, Deps};
+use cw_asset::AssetInfo;
+use serde::Serialize;
 
 use super::ModuleIdentification;
 use crate::apis::{AbstractApi, ApiIdentification};
@@ -25,6 +33,15 @@ pub trait AbstractNameService: Sized {
             host: self.ans_host(deps).unwrap(),
         }
     }
+
+    /// Construct a name service client that caches asset resolutions.
+    /// The cache lives as long as the returned client, so construct it once per message.
+    fn cached_ans<'a>(&'a self, deps: Deps<'a>) -> ResolveCache<'a, Self> {
+        ResolveCache {
+            ans: self.name_service(deps),
+            entries: RefCell::default(),
+        }
+    }
 }
 /// ANCHOR_END: ans
 
@@ -105,3 +122,137 @@ impl<'a, T: ModuleIdentification + AbstractNameService> AbstractNameServiceClien
             .map_err(|error| self.wrap_query_error(error))
     }
 }
+
+/// Name service client that memoizes resolutions, with the same query interface as [`AbstractNameServiceClient`].
+/// Only use it within a single message to avoid serving stale entries.
+pub struct ResolveCache<'a, T: AbstractNameService> {
+    ans: AbstractNameServiceClient<'a, T>,
+    /// Resolved entries, keyed by the entry's type and JSON representation
+    entries: RefCell<BTreeMap<(TypeId, Vec<u8>), Box<dyn Any>>>,
+}
+
+impl<'a, T: ModuleIdentification + AbstractNameService> ResolveCache<'a, T> {
+    /// Query ans entry, querying the ANS only on the first lookup of the entry.
+    pub fn query<R>(&self, entry: &R) -> AbstractSdkResult<R::Output>
+    where
+        R: Resolve + Serialize + 'static,
+        R::Output: Clone + 'static,
+    {
+        let key = (TypeId::of::<R>(), to_json_vec(entry)?);
+        if let Some(output) = self
+            .entries
+            .borrow()
+            .get(&key)
+            .and_then(|output| output.downcast_ref::<R::Output>())
+        {
+            return Ok(output.clone());
+        }
+        let output = self.ans.query(entry)?;
+        self.entries
+            .borrow_mut()
+            .insert(key, Box::new(output.clone()));
+        Ok(output)
+    }
+
+    /// Returns if the entry is registered on the ANS, see [`AbstractNameServiceClient::is_registered`].
+    pub fn is_registered<R>(&self, entry: &R) -> bool
+    where
+        R: Resolve + Serialize + 'static,
+        R::Output: Clone + 'static,
+    {
+        self.query(entry).is_ok()
+    }
+
+    /// Assert that an entry is registered on the ANS. Will return an Err if the entry is not registered.
+    pub fn assert_registered<R>(&self, entry: &R) -> AbstractSdkResult<()>
+    where
+        R: Resolve + Serialize + 'static,
+        R::Output: Clone + 'static,
+    {
+        self.query(entry).map(|_| ())
+    }
+
+    /// Resolve an asset entry, querying the ANS only on the first lookup.
+    pub fn asset(&self, entry: &AssetEntry) -> AbstractSdkResult<AssetInfo> {
+        self.query(entry)
+    }
+
+    /// Resolve an asset info into its entry, querying the ANS only on the first lookup.
+    pub fn asset_entry(&self, info: &AssetInfo) -> AbstractSdkResult<AssetEntry> {
+        self.query(info)
+    }
+
+    /// Get AnsHost
+    pub fn host(&self) -> &AnsHost {
+        self.ans.host()
+    }
+
+    /// Get the underlying name service client.
+    pub fn name_service(&self) -> &AbstractNameServiceClient<'a, T> {
+        &self.ans
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::Cell, rc::Rc};
+
+    use abstract_std::objects::AnsAsset;
+    use abstract_testing::prelude::*;
+    use cosmwasm_std::{testing::mock_dependencies, to_json_binary};
+    use cw_asset::Asset;
+    use speculoos::prelude::*;
+
+    use super::*;
+    use crate::mock_module::MockModule;
+
+    #[test]
+    fn cached_ans_queries_once() {
+        let mut deps = mock_dependencies();
+        let ans_queries = Rc::new(Cell::new(0));
+        let counter = ans_queries.clone();
+        deps.querier = MockQuerierBuilder::default()
+            .with_raw_handler(TEST_ANS_HOST, move |_| {
+                counter.set(counter.get() + 1);
+                to_json_binary(&AssetInfo::native(TTOKEN)).map_err(|e| e.to_string())
+            })
+            .build();
+
+        let module = MockModule::new();
+        let ans = module.cached_ans(deps.as_ref());
+        let entry = AssetEntry::new(TTOKEN);
+
+        let first = ans.asset(&entry).unwrap();
+        let second = ans.asset(&entry).unwrap();
+
+        assert_that!(first).is_equal_to(AssetInfo::native(TTOKEN));
+        assert_that!(second).is_equal_to(first);
+        assert_that!(ans_queries.get()).is_equal_to(1);
+    }
+
+    #[test]
+    fn cached_ans_resolves_through_query() {
+        let mut deps = mock_dependencies();
+        let ans_queries = Rc::new(Cell::new(0));
+        let counter = ans_queries.clone();
+        deps.querier = MockQuerierBuilder::default()
+            .with_raw_handler(TEST_ANS_HOST, move |_| {
+                counter.set(counter.get() + 1);
+                to_json_binary(&AssetInfo::native(TTOKEN)).map_err(|e| e.to_string())
+            })
+            .build();
+
+        let module = MockModule::new();
+        let ans = module.cached_ans(deps.as_ref());
+        let entry = AssetEntry::new(TTOKEN);
+
+        assert_that!(ans.is_registered(&entry)).is_true();
+        let info = ans.query(&entry).unwrap();
+        let asset = ans.query(&AnsAsset::new(TTOKEN, 10u128)).unwrap();
+
+        assert_that!(info).is_equal_to(AssetInfo::native(TTOKEN));
+        assert_that!(asset).is_equal_to(Asset::native(TTOKEN, 10u128));
+        // The asset is a different entry, so it's resolved once more
+        assert_that!(ans_queries.get()).is_equal_to(2);
+    }
+}
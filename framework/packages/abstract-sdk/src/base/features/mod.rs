@@ -5,7 +5,7 @@ mod executor;
 mod identification;
 mod module_identification;
 
-pub use abstract_name_service::{AbstractNameService, AbstractNameServiceClient};
+pub use abstract_name_service::{AbstractNameService, AbstractNameServiceClient, ResolveCache};
 pub use abstract_registry_access::AbstractRegistryAccess;
 pub use dependencies::Dependencies;
 pub use executor::AccountExecutor;
@@ -5,6 +5,7 @@ use cw2::ContractVersion;
 use super::contract_base::{
     AbstractContract, ExecuteHandlerFn, IbcCallbackHandlerFn, InstantiateHandlerFn,
     MigrateHandlerFn, ModuleIbcHandlerFn, QueryHandlerFn, ReceiveHandlerFn, SudoHandlerFn,
+    UninstallHandlerFn,
 };
 use crate::{
     base::{
@@ -173,6 +174,11 @@ where
         let contract = self.contract();
         contract.module_ibc_handler
     }
+    /// Get an uninstall handler if it exists.
+    fn maybe_uninstall_handler(&self) -> Option<UninstallHandlerFn<Self, Self::Error>> {
+        let contract = self.contract();
+        contract.uninstall_handler
+    }
     /// Get a reply handler if it exists.
     fn maybe_reply_handler(&self, id: u64) -> Option<ReplyHandlerFn<Self, Self::Error>> {
         let contract = self.contract();
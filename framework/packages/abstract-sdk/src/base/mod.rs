@@ -10,10 +10,10 @@ mod handler;
 pub use contract_base::{
     AbstractContract, ExecuteHandlerFn, IbcCallbackHandlerFn, InstantiateHandlerFn,
     MigrateHandlerFn, ModuleIbcHandlerFn, ModuleId, ModuleMetadata, QueryHandlerFn,
-    ReceiveHandlerFn, ReplyHandlerFn, SudoHandlerFn, VersionString,
+    ReceiveHandlerFn, ReplyHandlerFn, SudoHandlerFn, UninstallHandlerFn, VersionString,
 };
 pub use endpoints::{
     ExecuteEndpoint, IbcCallbackEndpoint, InstantiateEndpoint, MigrateEndpoint, ModuleIbcEndpoint,
-    QueryEndpoint, ReceiveEndpoint, ReplyEndpoint, SudoEndpoint,
+    QueryEndpoint, ReceiveEndpoint, ReplyEndpoint, SudoEndpoint, MODULE_IBC_NONCE_WINDOW,
 };
 pub use handler::Handler;
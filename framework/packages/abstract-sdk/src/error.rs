@@ -1,7 +1,7 @@
 #![allow(missing_docs)]
 use std::fmt::{Display, Formatter};
 
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Coin, Decimal, Timestamp};
 use cw_asset::AssetError;
 use thiserror::Error;
 
@@ -66,6 +66,27 @@ pub enum AbstractSdkError {
     #[error("Called an IBC module action on {0}, when no endpoint was registered.")]
     NoModuleIbcHandler(String),
 
+    // module ibc message was already processed
+    #[error("Module IBC message from {sender} on {chain} replayed: nonce {nonce} was already received or is too old.")]
+    ModuleIbcNonceReplayed {
+        chain: String,
+        sender: String,
+        nonce: u64,
+    },
+
+    // historical query can't be served
+    #[error("Historical queries at height {height} are not supported in this environment.")]
+    HistoricalQueryUnsupported { height: u64 },
+
+    // account can't pay the execution fee
+    #[error("Account {proxy_addr} can't pay the execution fee of {fee} on top of the {spent} spent by the actions, balance: {balance}.")]
+    InsufficientFeeBalance {
+        proxy_addr: Addr,
+        fee: Coin,
+        spent: Coin,
+        balance: Coin,
+    },
+
     // admin of proxy is not set
     #[error("Admin of proxy {proxy_addr} is not set.")]
     AdminNotSet { proxy_addr: Addr },
@@ -78,6 +99,32 @@ pub enum AbstractSdkError {
         error: Box<AbstractError>,
     },
 
+    // Vesting schedule with its timestamps out of order
+    #[error("Invalid vesting schedule: start {start}, cliff {cliff} and end {end} must be in increasing order.")]
+    InvalidVestingSchedule {
+        start: Timestamp,
+        cliff: Timestamp,
+        end: Timestamp,
+    },
+
+    // Fraction of a balance to transfer is larger than the balance
+    #[error("Can't transfer a fraction of {fraction} of a balance, it must be at most 1.")]
+    InvalidTransferFraction { fraction: Decimal },
+
+    // Native coins can't be transferred on behalf of another address
+    #[error(
+        "Can't pull native asset {asset} from {owner}, only cw20 tokens support TransferFrom."
+    )]
+    CannotPullNativeAsset { asset: String, owner: Addr },
+
+    // Reply id of an isolated transfer doesn't fit in a u64
+    #[error("Reply id offset {offset} plus transfer index {index} overflows.")]
+    ReplyIdOverflow { offset: u64, index: usize },
+
+    // Module state is already initialized
+    #[error("Module {module} is already instantiated.")]
+    AlreadyInstantiated { module: String },
+
     // Queried address is not a module
     #[error("Queried address {addr} is not a module: {err}")]
     NotAModule { addr: Addr, err: String },
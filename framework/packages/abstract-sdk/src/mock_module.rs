@@ -1,12 +1,20 @@
 //! Mock module for API and feature testing
 
-use abstract_std::objects::{
-    ans_host::AnsHost, dependency::StaticDependency, version_control::VersionControlContract,
+use abstract_std::{
+    ibc::{Callback, IbcResponseMsg, IbcResult},
+    objects::{
+        ans_host::AnsHost, dependency::StaticDependency, version_control::VersionControlContract,
+    },
 };
 use abstract_testing::prelude::*;
-use cosmwasm_std::{Addr, Deps};
+use cosmwasm_std::{
+    testing::mock_info, Addr, Deps, DepsMut, Env, QuerierWrapper, Response, Uint128,
+};
+use cw_asset::AssetInfo;
 
 use crate::{
+    apis::bank::ArchiveQuerier,
+    base::IbcCallbackEndpoint,
     features::{
         AbstractNameService, AbstractRegistryAccess, AccountExecutor, AccountIdentification,
         Dependencies, ModuleIdentification,
@@ -33,7 +41,7 @@ impl ModuleIdentification for MockModule {
 impl AbstractNameService for MockModule {
     fn ans_host(&self, _deps: Deps) -> AbstractSdkResult<AnsHost> {
         Ok(AnsHost {
-            address: Addr::unchecked("ans"),
+            address: Addr::unchecked(TEST_ANS_HOST),
         })
     }
 }
@@ -88,3 +96,46 @@ impl abstract_std::adapter::AdapterQueryMsg for MockModuleQueryMsg {}
 impl abstract_std::app::AppExecuteMsg for MockModuleExecuteMsg {}
 
 impl abstract_std::app::AppQueryMsg for MockModuleQueryMsg {}
+
+/// Invokes the IBC callback handler of `module` as if the IBC client relayed `result` for `callback`.
+///
+/// The sender is set to the module's IBC client address, so the module's querier must be able to
+/// resolve the IBC client of the Account (i.e. the manager's `ACCOUNT_MODULES` entry for it).
+pub fn mock_ibc_callback<Module: IbcCallbackEndpoint>(
+    module: Module,
+    deps: DepsMut,
+    env: Env,
+    callback: Callback,
+    result: IbcResult,
+) -> Result<Response, Module::Error> {
+    let ibc_client = module.ibc_client_addr(deps.as_ref())?;
+    module.ibc_callback(
+        deps,
+        env,
+        mock_info(ibc_client.as_str(), &[]),
+        IbcResponseMsg { callback, result },
+    )
+}
+
+/// [`ArchiveQuerier`] for mock environments.
+/// These keep no history, so the current balance is returned for any height.
+pub struct MockArchiveQuerier<'a> {
+    querier: QuerierWrapper<'a>,
+}
+
+impl<'a> MockArchiveQuerier<'a> {
+    pub fn new(querier: QuerierWrapper<'a>) -> Self {
+        Self { querier }
+    }
+}
+
+impl ArchiveQuerier for MockArchiveQuerier<'_> {
+    fn balance_at(
+        &self,
+        asset: &AssetInfo,
+        address: &Addr,
+        _height: u64,
+    ) -> AbstractSdkResult<Option<Uint128>> {
+        Ok(Some(asset.query_balance(&self.querier, address)?))
+    }
+}
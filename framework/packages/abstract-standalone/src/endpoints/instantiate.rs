@@ -1,6 +1,6 @@
 use abstract_sdk::{
     feature_objects::{AnsHost, VersionControlContract},
-    AbstractSdkResult,
+    AbstractSdkError, AbstractSdkResult,
 };
 use abstract_std::{
     objects::module_version::set_module_data,
@@ -15,6 +15,7 @@ impl StandaloneContract {
     /// Instantiates the `Standalone` state for this contract.
     ///
     /// **Note:** This contract can only be instantiated by the abstract module factory.
+    /// Errors if the state is already set, so the account linkage can't be overwritten.
     pub fn instantiate(
         &self,
         deps: DepsMut,
@@ -22,6 +23,12 @@ impl StandaloneContract {
         msg: StandaloneInstantiateMsg,
         is_migratable: bool,
     ) -> AbstractSdkResult<()> {
+        if self.base_state.exists(deps.storage) {
+            return Err(AbstractSdkError::AlreadyInstantiated {
+                module: self.module_id().to_owned(),
+            });
+        }
+
         let StandaloneInstantiateMsg {
             ans_host_address,
             version_control_address,
@@ -50,3 +57,36 @@ impl StandaloneContract {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use abstract_sdk::AbstractSdkError;
+    use abstract_std::standalone::StandaloneInstantiateMsg;
+    use abstract_testing::prelude::*;
+    use speculoos::prelude::*;
+
+    use crate::mock::*;
+
+    #[test]
+    fn second_instantiate_errors() {
+        let mut deps = mock_init();
+        let state = BASIC_MOCK_STANDALONE.load_state(&deps.storage).unwrap();
+
+        let res = BASIC_MOCK_STANDALONE.instantiate(
+            deps.as_mut(),
+            mock_info(TEST_MODULE_FACTORY, &[]),
+            StandaloneInstantiateMsg {
+                ans_host_address: "other_ans_host".to_owned(),
+                version_control_address: "other_version_control".to_owned(),
+            },
+            false,
+        );
+        assert_that!(res)
+            .is_err()
+            .is_equal_to(AbstractSdkError::AlreadyInstantiated {
+                module: TEST_MODULE_ID.to_owned(),
+            });
+        // state is left untouched
+        assert_that!(BASIC_MOCK_STANDALONE.load_state(&deps.storage).unwrap()).is_equal_to(state);
+    }
+}
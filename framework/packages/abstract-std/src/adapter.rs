@@ -123,6 +123,16 @@ pub enum AdapterBaseMsg {
     },
 }
 
+/// Sudo message for the adapter
+/// [`AdapterSudoMsg::Module`] is forwarded to the adapter's sudo handler, other variants are handled by the adapter base.
+#[cosmwasm_schema::cw_serde]
+pub enum AdapterSudoMsg<ModuleMsg = Empty> {
+    /// Update the version control address used to verify Accounts
+    UpdateRegistry { version_control: String },
+    /// Custom sudo message of the adapter
+    Module(ModuleMsg),
+}
+
 /// Query adapter message
 #[cosmwasm_schema::cw_serde]
 #[derive(QueryResponses, cw_orch::QueryFns)]
@@ -67,6 +67,9 @@ pub enum BaseExecuteMsg {
         ans_host_address: Option<String>,
         version_control_address: Option<String>,
     },
+    /// Called by the manager before the app is uninstalled to run its uninstall handler.
+    /// The app is still whitelisted on the proxy while the handler runs.
+    Uninstall {},
 }
 
 impl<T> From<BaseExecuteMsg> for ExecuteMsg<T> {
@@ -93,6 +96,14 @@ pub enum BaseQueryMsg {
     /// Returns [`TopLevelOwnerResponse`]
     #[returns(TopLevelOwnerResponse)]
     TopLevelOwner {},
+    /// Returns the reply ids and IBC callback the app has handlers registered for
+    /// Returns [`HandlersResponse`]
+    #[returns(HandlersResponse)]
+    Handlers {},
+    /// Returns whether the app's dependencies are installed on its Account at a supported version
+    /// Returns [`DependencyStatusResponse`]
+    #[returns(DependencyStatusResponse)]
+    DependencyStatus {},
 }
 
 impl<T> From<BaseQueryMsg> for QueryMsg<T> {
@@ -108,6 +119,32 @@ pub struct AppConfigResponse {
     pub manager_address: Addr,
 }
 
+/// Handlers registered on the app contract.
+#[cosmwasm_schema::cw_serde]
+pub struct HandlersResponse {
+    /// Reply ids with a registered reply handler, in registration order.
+    pub reply_ids: Vec<u64>,
+    /// Whether an IBC callback handler is registered.
+    pub ibc_callback: bool,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct DependencyStatusResponse {
+    pub dependencies: Vec<DependencyStatus>,
+}
+
+/// Status of a dependency on the app's Account.
+#[cosmwasm_schema::cw_serde]
+pub struct DependencyStatus {
+    pub id: String,
+    /// Version requirements declared by the app.
+    pub version_req: Vec<String>,
+    /// Version installed on the Account, `None` if the dependency isn't installed.
+    pub installed_version: Option<String>,
+    /// Whether the installed version meets all the requirements.
+    pub satisfied: bool,
+}
+
 #[cosmwasm_schema::cw_serde]
 pub struct BaseMigrateMsg {}
 
@@ -98,7 +98,7 @@ pub mod state {
 }
 
 use cosmwasm_schema::QueryResponses;
-use cosmwasm_std::{Addr, Binary};
+use cosmwasm_std::{Addr, Binary, Coin};
 use cw2::ContractVersion;
 
 use self::state::AccountInfo;
@@ -165,11 +165,24 @@ pub enum UpdateSubAccountAction {
 pub struct ModuleInstallConfig {
     pub module: ModuleInfo,
     pub init_msg: Option<Binary>,
+    /// Funds sent to the module on instantiation, on top of its registered `instantiation_funds`.
+    #[serde(default)]
+    pub funds: Vec<Coin>,
 }
 
 impl ModuleInstallConfig {
     pub fn new(module: ModuleInfo, init_msg: Option<Binary>) -> Self {
-        Self { module, init_msg }
+        Self {
+            module,
+            init_msg,
+            funds: vec![],
+        }
+    }
+
+    /// Send `funds` to the module when it gets instantiated.
+    pub fn with_funds(mut self, funds: Vec<Coin>) -> Self {
+        self.funds = funds;
+        self
     }
 }
 
@@ -274,6 +287,10 @@ pub enum QueryMsg {
     /// Returns [`TopLevelOwnerResponse`]
     #[returns(TopLevelOwnerResponse)]
     TopLevelOwner {},
+    /// Query the complete configuration of the Account in a single call.
+    /// Returns [`SnapshotResponse`]
+    #[returns(SnapshotResponse)]
+    Snapshot {},
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -311,6 +328,20 @@ pub struct ModuleInfosResponse {
     pub module_infos: Vec<ManagerModuleInfo>,
 }
 
+/// Snapshot of the complete configuration of an Account.
+#[cosmwasm_schema::cw_serde]
+pub struct SnapshotResponse {
+    pub ownership: cw_ownable::Ownership<Addr>,
+    pub info: AccountInfo<Addr>,
+    pub config: ConfigResponse,
+    /// All the modules installed on the Account
+    pub modules: Vec<ManagerModuleInfo>,
+    /// Addresses whitelisted on the proxy
+    pub whitelisted_addresses: Vec<Addr>,
+    /// Base asset of the proxy, `None` if not set
+    pub base_asset: Option<cw_asset::AssetInfo>,
+}
+
 #[cosmwasm_schema::cw_serde]
 pub struct SubAccountIdsResponse {
     pub sub_accounts: Vec<u32>,
@@ -69,6 +69,9 @@ pub enum ExecuteMsg {
         to_add: Vec<(AssetEntry, UncheckedPriceSource)>,
         to_remove: Vec<AssetEntry>,
     },
+    /// Copies the price sources of another proxy.
+    /// Already configured assets are replaced if `overwrite` is set, and kept otherwise.
+    ImportAssets { from_proxy: String, overwrite: bool },
 }
 #[cosmwasm_schema::cw_serde]
 pub struct MigrateMsg {}
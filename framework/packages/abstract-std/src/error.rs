@@ -4,7 +4,9 @@ use cw_semver::Error as CwSemverError;
 use semver::{Error as SemverError, Version};
 use thiserror::Error;
 
-use crate::objects::{ans_host::AnsHostError, version_control::VersionControlError};
+use crate::objects::{
+    ans_host::AnsHostError, oracle::OracleError, version_control::VersionControlError, AccountId,
+};
 
 /// Wrapper error for the Abstract framework.
 #[derive(Error, Debug, PartialEq)]
@@ -24,6 +26,9 @@ pub enum AbstractError {
     #[error("{0}")]
     AnsHostError(#[from] AnsHostError),
 
+    #[error("{0}")]
+    Oracle(#[from] OracleError),
+
     #[error("Semver error encountered while handling account object: {0}")]
     Semver(String),
 
@@ -53,6 +58,12 @@ pub enum AbstractError {
     #[error("App {0} not installed on Account")]
     AppNotInstalled(String),
 
+    #[error("Standalone {module} not installed on Account {account_id}")]
+    StandaloneNotInstalled {
+        module: String,
+        account_id: AccountId,
+    },
+
     #[error("version for {0} in missing")]
     MissingVersion(String),
 
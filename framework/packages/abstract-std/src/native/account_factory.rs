@@ -40,6 +40,8 @@ pub mod state {
     pub const CONFIG: Item<Config> = Item::new("cfg");
     pub const CONTEXT: Item<Context> = Item::new("contxt");
     pub const LOCAL_ACCOUNT_SEQUENCE: Item<AccountSequence> = Item::new("acseq");
+    /// Account creation is rejected while paused
+    pub const PAUSED: Item<bool> = Item::new("paused");
 }
 
 use cosmwasm_schema::QueryResponses;
@@ -52,6 +54,7 @@ use crate::{
         gov_type::GovernanceDetails,
         AssetEntry,
     },
+    version_control::AccountBase,
 };
 
 /// Msg used on instantiation
@@ -81,6 +84,8 @@ pub enum ExecuteMsg {
         // New module factory contract
         module_factory_address: Option<String>,
     },
+    /// Pause or unpause the creation of new accounts
+    SetPaused { paused: bool },
     /// Creates the core contracts and sets the permissions.
     /// [`crate::manager`] and [`crate::proxy`]
     #[cw_orch(payable)]
@@ -118,6 +123,10 @@ pub enum QueryMsg {
     /// Returns [`ConfigResponse`]
     #[returns(ConfigResponse)]
     Config {},
+    /// Returns the id and addresses of the next local account created without an account id.
+    /// Returns [`NextAccountResponse`]
+    #[returns(NextAccountResponse)]
+    NextAccount {},
 }
 
 /// Account Factory config response
@@ -127,6 +136,15 @@ pub struct ConfigResponse {
     pub version_control_contract: Addr,
     pub module_factory_address: Addr,
     pub local_account_sequence: AccountSequence,
+    pub is_paused: bool,
+}
+
+/// Account Factory next account response
+#[cosmwasm_schema::cw_serde]
+pub struct NextAccountResponse {
+    pub account_id: AccountId,
+    /// Predicted addresses of the manager and proxy, useful to fund the proxy before creation
+    pub account_base: AccountBase,
 }
 
 /// Sequence numbers for each origin.
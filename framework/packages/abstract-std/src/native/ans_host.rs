@@ -96,6 +96,9 @@ pub enum ExecuteMsg {
     },
     /// Updates the Asset addressbook
     UpdateAssetAddresses {
+        // Allow the same asset to be added more than once in `to_add`, the last entry wins
+        #[serde(default)]
+        allow_overwrite: bool,
         // Assets to update or add
         to_add: Vec<(String, AssetInfoUnchecked)>,
         // Assets to remove
@@ -1,7 +1,7 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    to_json_binary, wasm_execute, Binary, CosmosMsg, Empty, Event, QueryRequest, StdError,
-    StdResult,
+    from_json, to_json_binary, wasm_execute, Binary, CosmosMsg, Empty, Event, QueryRequest,
+    StdError, StdResult,
 };
 use polytone::callbacks::{Callback as PolytoneCallback, ErrorResponse, ExecutionResponse};
 use schemars::JsonSchema;
@@ -10,7 +10,8 @@ use serde::Serialize;
 use crate::{
     base::ExecuteMsg,
     ibc_client,
-    objects::{module::ModuleInfo, TruncatedChainId},
+    ibc_host::DispatchAck,
+    objects::{module::ModuleInfo, AccountId, TruncatedChainId},
 };
 
 /// Callback from modules, that is turned into an IbcResponseMsg by the ibc client
@@ -104,6 +105,8 @@ impl IbcResult {
         }
     }
 
+    /// A host action that failed on the remote chain but was acknowledged with a [`DispatchAck::Error`]
+    /// results in an execution error.
     pub fn from_execute(
         callback: PolytoneCallback,
         initiator_msg: Binary,
@@ -114,7 +117,7 @@ impl IbcResult {
             )),
             PolytoneCallback::Execute(e) => Ok(Self::Execute {
                 initiator_msg,
-                result: e,
+                result: e.and_then(check_dispatch_ack),
             }),
             PolytoneCallback::FatalError(e) => Ok(Self::FatalError(e)),
         }
@@ -158,12 +161,35 @@ impl IbcResult {
     }
 }
 
+/// Turn an execution acknowledged with a [`DispatchAck::Error`] by the ibc-host into an error.
+/// Only data that decodes as a [`DispatchAck`] envelope is considered, any other data is left as is.
+fn check_dispatch_ack(response: ExecutionResponse) -> Result<ExecutionResponse, String> {
+    let ack = response
+        .result
+        .iter()
+        .filter_map(|msg_response| msg_response.data.as_ref())
+        .find_map(|data| {
+            let data = cw_utils::parse_execute_response_data(data).ok()?.data?;
+            from_json::<DispatchAck>(data).ok()
+        });
+    match ack {
+        Some(DispatchAck::Error(error)) => Err(error),
+        Some(DispatchAck::Ok(_)) | None => Ok(response),
+    }
+}
+
 #[cw_serde]
 pub struct ModuleIbcMsg {
     /// Sender Module Identification
     pub src_module_info: ModuleIbcInfo,
     /// The message sent by the module
     pub msg: Binary,
+    /// Account of the sending module on the source chain.
+    /// `None` if the sending module isn't installed on a specific Account, like adapters.
+    pub src_account_id: Option<AccountId>,
+    /// Sequence number assigned by the ibc-client of the source chain for the sending module.
+    /// Receiving modules reject nonces they already received from the same sender.
+    pub nonce: Option<u64>,
 }
 
 // ANCHOR: module_ibc_msg
@@ -55,6 +55,9 @@ pub mod state {
     pub const ACCOUNTS: Map<(&AccountTrace, AccountSequence, &TruncatedChainId), String> =
         Map::new("accs");
 
+    /// (sending module, host_chain) -> last nonce assigned to a module-to-module message
+    pub const MODULE_IBC_NONCES: Map<(&Addr, &TruncatedChainId), u64> = Map::new("mibcn");
+
     // For callbacks tests
     pub const ACKS: Item<Vec<String>> = Item::new("tmpc");
 }
@@ -84,6 +87,15 @@ pub enum ExecuteMsg {
         /// Address of the abstract host deployed on the remote chain
         host: String,
     },
+    /// Owner method: Updates the polytone note and/or remote host of an already registered chain
+    UpdateInfrastructure {
+        /// Chain to update the infrastructure for ("juno", "osmosis", etc.)
+        chain: TruncatedChainId,
+        /// New polytone note (locally deployed)
+        note: Option<String>,
+        /// New address of the abstract host deployed on the remote chain
+        host: Option<String>,
+    },
     /// Owner method: Update the config on IBC client
     UpdateConfig {
         ans_host: Option<String>,
@@ -97,6 +109,9 @@ pub enum ExecuteMsg {
         /// Example: "osmosis"
         host_chain: TruncatedChainId,
         funds: Vec<Coin>,
+        /// Seconds until the transfer times out.
+        /// Defaults to the IBC client's packet lifetime.
+        timeout_seconds: Option<u64>,
     },
     /// Only callable by Account proxy
     /// Register an Account on a remote chain over IBC
@@ -142,6 +157,9 @@ pub enum ExecuteMsg {
         host_chain: TruncatedChainId,
         /// execute the custom host function
         action: HostAction,
+        /// Seconds until the packet times out.
+        /// Defaults to the IBC client's packet lifetime.
+        timeout_seconds: Option<u64>,
     },
     /// Owner method: Remove connection for remote chain
     RemoveHost { host_chain: TruncatedChainId },
@@ -191,11 +209,20 @@ impl InstalledModuleIdentification {
         vc: VersionControlContract,
     ) -> Result<ModuleAddr, AbstractError> {
         let target_module_resolved = vc.query_module(self.module_info.clone(), &deps.querier)?;
+        self.addr_with_reference(deps, vc, target_module_resolved.reference)
+    }
 
+    /// Resolve the address of this module from an already known version-control reference.
+    pub fn addr_with_reference(
+        &self,
+        deps: Deps,
+        vc: VersionControlContract,
+        reference: ModuleReference,
+    ) -> Result<ModuleAddr, AbstractError> {
         let no_account_id_error =
             StdError::generic_err("Account id not specified in installed module definition");
 
-        let target_addr = match &target_module_resolved.reference {
+        let target_addr = match &reference {
             ModuleReference::AccountBase(code_id) => {
                 let target_account_id = self.account_id.clone().ok_or(no_account_id_error)?;
                 let account_base = vc.account_base(&target_account_id, &deps.querier)?;
@@ -222,29 +249,44 @@ impl InstalledModuleIdentification {
             }
             ModuleReference::Native(addr) => addr.clone(),
             ModuleReference::Adapter(addr) => addr.clone(),
-            ModuleReference::App(_) | ModuleReference::Standalone(_) => {
+            ModuleReference::App(_) => {
                 let target_account_id = self.account_id.clone().ok_or(no_account_id_error)?;
-                let account_base = vc.account_base(&target_account_id, &deps.querier)?;
-
-                let module_info: manager::ModuleAddressesResponse = deps.querier.query_wasm_smart(
-                    account_base.manager,
-                    &manager::QueryMsg::ModuleAddresses {
-                        ids: vec![self.module_info.id()],
-                    },
-                )?;
-                module_info
-                    .modules
-                    .first()
+                self.installed_module_addr(deps, &vc, &target_account_id)?
                     .ok_or(AbstractError::AppNotInstalled(self.module_info.to_string()))?
-                    .1
-                    .clone()
+            }
+            ModuleReference::Standalone(_) => {
+                // Standalones have no app-style base, so the account's manager is the only source of their address
+                let target_account_id = self.account_id.clone().ok_or(no_account_id_error)?;
+                self.installed_module_addr(deps, &vc, &target_account_id)?
+                    .ok_or_else(|| AbstractError::StandaloneNotInstalled {
+                        module: self.module_info.to_string(),
+                        account_id: target_account_id,
+                    })?
             }
         };
         Ok(ModuleAddr {
-            reference: target_module_resolved.reference,
+            reference,
             address: target_addr,
         })
     }
+
+    /// Address of this module on the account, as registered on its manager.
+    fn installed_module_addr(
+        &self,
+        deps: Deps,
+        vc: &VersionControlContract,
+        account_id: &AccountId,
+    ) -> Result<Option<Addr>, AbstractError> {
+        let account_base = vc.account_base(account_id, &deps.querier)?;
+
+        let module_info: manager::ModuleAddressesResponse = deps.querier.query_wasm_smart(
+            account_base.manager,
+            &manager::QueryMsg::ModuleAddresses {
+                ids: vec![self.module_info.id()],
+            },
+        )?;
+        Ok(module_info.modules.into_iter().next().map(|(_, addr)| addr))
+    }
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -20,13 +20,22 @@ pub mod state {
     use cw_storage_plus::{Item, Map};
 
     use super::*;
-    use crate::objects::{ans_host::AnsHost, version_control::VersionControlContract};
+    use crate::objects::{
+        ans_host::AnsHost, module_reference::ModuleReference,
+        version_control::VersionControlContract,
+    };
 
     /// Maps a chain name to the proxy it uses to interact on this local chain
     pub const CHAIN_PROXIES: Map<&TruncatedChainId, Addr> = Map::new("ccl");
     pub const REVERSE_CHAIN_PROXIES: Map<&Addr, TruncatedChainId> = Map::new("rev-ccl");
     /// Configuration of the IBC host
     pub const CONFIG: Item<Config> = Item::new("cfg");
+    /// Gas limit of a whole dispatch, unbounded if not set
+    pub const DISPATCH_GAS_BUDGET: Item<u64> = Item::new("dgb");
+    /// Version-control references of modules targeted by module-to-module calls.
+    /// Only modules with an exact version are cached. Version control removes yanked and removed
+    /// versions with [`ExecuteMsg::InvalidateModuleReferences`].
+    pub const MODULE_REFERENCES: Map<&ModuleInfo, ModuleReference> = Map::new("mrc");
 
     // Temporary structure to hold actions to be executed after account creation
     pub const TEMP_ACTION_AFTER_CREATION: Item<ActionAfterCreationCache> = Item::new("act");
@@ -100,6 +109,17 @@ pub enum HostAction {
 }
 // ANCHOR_END: ibc-host-action
 
+/// Data of the host's response to a [`HostAction::Dispatch`].
+/// A failing dispatch is acknowledged with an error instead of failing the whole packet.
+/// See [`IbcResult::from_execute`](crate::ibc::IbcResult::from_execute) for decoding it on the client side.
+#[cosmwasm_schema::cw_serde]
+pub enum DispatchAck {
+    /// All manager messages got executed, holds the data of the manager response (empty if none).
+    Ok(Binary),
+    /// A manager message failed, none of the messages got executed.
+    Error(String),
+}
+
 /// Interface to the Host.
 #[cosmwasm_schema::cw_serde]
 #[derive(cw_orch::ExecuteFns)]
@@ -120,6 +140,17 @@ pub enum ExecuteMsg {
     RemoveChainProxy {
         chain: TruncatedChainId,
     },
+    /// Set the gas limit of a [`HostAction::Dispatch`], shared by all of its manager messages.
+    /// A dispatch exceeding it fails instead of consuming the gas of the whole transaction.
+    /// `None` removes the limit.
+    UpdateDispatchGasBudget {
+        gas_budget: Option<u64>,
+    },
+    /// Remove modules from the cached version-control references.
+    /// Callable by the owner and by version control, which calls it when a module version is yanked or removed.
+    InvalidateModuleReferences {
+        modules: Vec<ModuleInfo>,
+    },
     // ANCHOR: ibc-host-execute
     /// Allows for remote execution from the Polytone implementation
     #[cw_orch(fn_name("ibc_execute"))]
@@ -131,11 +162,19 @@ pub enum ExecuteMsg {
         action: HostAction,
     },
     // ANCHOR_END: ibc-host-execute
+    /// Executes the manager messages of a [`HostAction::Dispatch`].
+    /// Only callable by the host itself, see [`DispatchAck`].
+    ExecuteDispatch {
+        manager: String,
+        manager_msgs: Vec<manager::ExecuteMsg>,
+    },
     /// Performs an execution on a local module
     ModuleExecute {
         source_module: InstalledModuleIdentification,
         target_module: ModuleInfo,
         msg: Binary,
+        /// Replay-protection nonce, forwarded to the target module
+        nonce: Option<u64>,
     },
 }
 
@@ -174,6 +213,7 @@ pub struct ConfigResponse {
     pub ans_host_address: Addr,
     pub account_factory_address: Addr,
     pub version_control_address: Addr,
+    pub dispatch_gas_budget: Option<u64>,
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -62,11 +62,24 @@ pub enum ExecuteMsg {
 pub struct FactoryModuleInstallConfig {
     pub module: ModuleInfo,
     pub init_msg: Option<Binary>,
+    /// Funds sent to the module on instantiation, on top of its registered `instantiation_funds`.
+    #[serde(default)]
+    pub funds: Vec<Coin>,
 }
 
 impl FactoryModuleInstallConfig {
     pub fn new(module: ModuleInfo, init_msg: Option<Binary>) -> Self {
-        Self { module, init_msg }
+        Self {
+            module,
+            init_msg,
+            funds: vec![],
+        }
+    }
+
+    /// Send `funds` to the module when it gets instantiated.
+    pub fn with_funds(mut self, funds: Vec<Coin>) -> Self {
+        self.funds = funds;
+        self
     }
 }
 
@@ -105,8 +105,10 @@ pub struct InstantiateMsg {
 #[derive(cw_orch::ExecuteFns)]
 pub enum ExecuteMsg {
     /// Remove some version of a module
+    /// Invalidates its cached reference on the registered IBC host
     RemoveModule { module: ModuleInfo },
     /// Yank a version of a module so that it may not be installed
+    /// Invalidates its cached reference on the registered IBC host
     /// Only callable by Admin
     YankModule { module: ModuleInfo },
     /// Propose new modules to the version registry
@@ -135,6 +137,12 @@ pub enum ExecuteMsg {
     /// Remove namespace claims
     /// Only admin or root user can call this
     RemoveNamespaces { namespaces: Vec<String> },
+    /// Transfer a namespace claim to another Account
+    /// Only the owner of the Account that claimed the namespace can call this
+    TransferNamespace {
+        namespace: String,
+        to_account: AccountId,
+    },
     /// Register a new Account to the deployed Accounts.
     /// Claims namespace if provided.  
     /// Only Factory can call this
@@ -220,6 +228,16 @@ pub enum QueryMsg {
         start_after: Option<String>,
         limit: Option<u8>,
     },
+    /// Lists every known version of a module, ordered by semver, together with its status.
+    /// All versions of the module are loaded on every page, so the cost grows with the number of versions.
+    /// Returns [`ModuleVersionsResponse`]
+    #[returns(ModuleVersionsResponse)]
+    ModuleVersions {
+        /// Module id in the `namespace:name` format
+        module_id: String,
+        start_after: Option<String>,
+        limit: Option<u8>,
+    },
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -296,6 +314,12 @@ pub struct ModulesListResponse {
     pub modules: Vec<ModuleResponse>,
 }
 
+#[cosmwasm_schema::cw_serde]
+pub struct ModuleVersionsResponse {
+    /// Versions of the module and their registry status, oldest first
+    pub versions: Vec<(String, ModuleStatus)>,
+}
+
 #[cosmwasm_schema::cw_serde]
 pub enum NamespaceResponse {
     Claimed(NamespaceInfo),
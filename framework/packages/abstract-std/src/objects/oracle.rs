@@ -1,22 +1,55 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Deps, DepsMut, Order, StdError, StdResult, Uint128};
+use cosmwasm_std::{Addr, Deps, DepsMut, Order, QuerierWrapper, StdError, StdResult, Uint128};
 use cw_asset::{Asset, AssetInfo};
 use cw_storage_plus::{Bound, Map};
+use thiserror::Error;
 
 use super::{
     ans_host::AnsHost,
     price_source::{AssetConversion, PriceSource, UncheckedPriceSource},
     AssetEntry,
 };
-use crate::AbstractResult;
+use crate::{AbstractError, AbstractResult};
 
 pub type Complexity = u8;
 
 pub const LIST_SIZE_LIMIT: u8 = 15;
 const DEFAULT_PAGE_LIMIT: u8 = 5;
 
+/// Failure modes of the oracle configuration and valuation.
+#[derive(Error, Debug, PartialEq)]
+pub enum OracleError {
+    // asset has no price source configured
+    #[error("Asset {0} not registered on oracle")]
+    NoPriceSource(String),
+
+    // no asset with complexity 0
+    #[error("No base asset registered on oracle")]
+    MissingBaseAsset,
+
+    // more than one asset with complexity 0
+    #[error("{0} base assets registered, must be 1")]
+    MultipleBaseAssets(usize),
+
+    // asset registered more than once
+    #[error("Asset {0} already registered on oracle")]
+    AssetAlreadyRegistered(String),
+
+    // asset provided more than once in a single update
+    #[error("Duplicate assets in oracle update")]
+    DuplicateAssetsInUpdate,
+
+    // too many assets
+    #[error("Oracle list size limit of {LIST_SIZE_LIMIT} exceeded")]
+    ListSizeLimitExceeded,
+
+    // price sources that depend on each other
+    #[error("Circular price source dependency between {assets:?}")]
+    CircularPriceSource { assets: Vec<AssetEntry> },
+}
+
 /// Struct for calculating asset prices/values for a smart contract.
 pub struct Oracle<'a> {
     /// map of human-readable asset names to their human-readable price source
@@ -63,9 +96,7 @@ impl<'a> Oracle<'a> {
             .count();
         let delta: i128 = to_add.len() as i128 - to_remove.len() as i128;
         if current_vault_size as i128 + delta > LIST_SIZE_LIMIT as i128 {
-            return Err(crate::AbstractError::Std(StdError::generic_err(
-                "Oracle list size limit exceeded",
-            )));
+            return Err(OracleError::ListSizeLimitExceeded.into());
         }
 
         let mut all: Vec<AssetEntry> = to_add
@@ -75,10 +106,9 @@ impl<'a> Oracle<'a> {
         all.extend(to_remove.clone());
         all.dedup();
         if all.len() != to_add.len() + to_remove.len() {
-            return Err(crate::AbstractError::Std(StdError::generic_err(
-                "Duplicate assets in update",
-            )));
+            return Err(OracleError::DuplicateAssetsInUpdate.into());
         }
+        self.assert_no_cycles(deps.as_ref(), &to_add, &to_remove)?;
 
         // add assets to oracle
         self.add_assets(deps.branch(), ans, to_add)?;
@@ -90,6 +120,46 @@ impl<'a> Oracle<'a> {
         self.validate(deps.as_ref())
     }
 
+    /// Imports the price sources of another oracle.
+    /// `assets` must be ordered by increasing complexity so dependencies are registered first.
+    /// Already configured assets are replaced if `overwrite` is set, and skipped otherwise.
+    pub fn import_assets(
+        &self,
+        mut deps: DepsMut,
+        ans: &AnsHost,
+        assets: Vec<(AssetEntry, UncheckedPriceSource)>,
+        overwrite: bool,
+    ) -> AbstractResult<()> {
+        let (to_remove, to_add): (Vec<AssetEntry>, Vec<_>) = if overwrite {
+            let to_remove = assets
+                .iter()
+                .filter(|(asset, _)| self.config.has(deps.storage, asset))
+                .map(|(asset, _)| asset.clone())
+                .collect();
+            (to_remove, assets)
+        } else {
+            let to_add = assets
+                .into_iter()
+                .filter(|(asset, _)| !self.config.has(deps.storage, asset))
+                .collect();
+            (vec![], to_add)
+        };
+
+        let current_vault_size = self
+            .config
+            .keys(deps.storage, None, None, Order::Ascending)
+            .count();
+        if current_vault_size - to_remove.len() + to_add.len() > LIST_SIZE_LIMIT as usize {
+            return Err(OracleError::ListSizeLimitExceeded.into());
+        }
+
+        self.assert_no_cycles(deps.as_ref(), &to_add, &to_remove)?;
+        // remove replaced assets first so they can be registered again
+        self.remove_assets(deps.branch(), ans, to_remove)?;
+        self.add_assets(deps.branch(), ans, to_add)?;
+        self.validate(deps.as_ref())
+    }
+
     /// Adds assets to the oracle
     fn add_assets(
         &self,
@@ -133,20 +203,16 @@ impl<'a> Oracle<'a> {
             self.complexity.update(deps.storage, complexity, |v| {
                 let mut v = v.unwrap_or_default();
                 if v.contains(&asset) {
-                    return Err(StdError::generic_err(format!(
-                        "Asset {asset} already registered"
-                    )));
+                    return Err(OracleError::AssetAlreadyRegistered(asset.to_string()).into());
                 }
                 v.push(asset.clone());
-                Result::<_, StdError>::Ok(v)
+                Result::<_, AbstractError>::Ok(v)
             })?;
             self.assets.update(deps.storage, &asset, |v| {
                 if v.is_some() {
-                    return Err(StdError::generic_err(format!(
-                        "asset {asset} already registered"
-                    )));
+                    return Err(OracleError::AssetAlreadyRegistered(asset.to_string()).into());
                 }
-                Ok((price_source, complexity))
+                Result::<_, AbstractError>::Ok((price_source, complexity))
             })?;
         }
 
@@ -163,10 +229,7 @@ impl<'a> Oracle<'a> {
         for asset in assets {
             // assert asset was in config
             if !self.config.has(deps.storage, &asset) {
-                return Err(StdError::generic_err(format!(
-                    "Asset {asset} not registered on oracle"
-                ))
-                .into());
+                return Err(OracleError::NoPriceSource(asset.to_string()).into());
             }
             // remove from config
             self.config.remove(deps.storage, &asset);
@@ -225,7 +288,10 @@ impl<'a> Oracle<'a> {
     /// Does not make use of the cache to prevent querying the same price source multiple times.
     pub fn asset_value(&self, deps: Deps, asset: Asset) -> AbstractResult<Uint128> {
         // get the price source for the asset
-        let (price_source, _) = self.assets.load(deps.storage, &asset.info)?;
+        let (price_source, _) = self
+            .assets
+            .may_load(deps.storage, &asset.info)?
+            .ok_or_else(|| OracleError::NoPriceSource(asset.info.to_string()))?;
         // get the conversions for this asset
         let conversion_rates = price_source.conversion_rates(deps, &asset.info)?;
         if conversion_rates.is_empty() {
@@ -366,17 +432,11 @@ impl<'a> Oracle<'a> {
                 let deps = price_source.dependencies(&asset);
                 for dep in &deps {
                     if !encountered_assets.contains(&dep.to_string()) {
-                        return Err(StdError::generic_err(format!(
-                            "Asset {dep} is an oracle dependency but is not registered"
-                        ))
-                        .into());
+                        return Err(OracleError::NoPriceSource(dep.to_string()).into());
                     }
                 }
                 if !encountered_assets.insert(asset.to_string()) {
-                    return Err(StdError::generic_err(format!(
-                        "Asset {asset} is registered twice"
-                    ))
-                    .into());
+                    return Err(OracleError::AssetAlreadyRegistered(asset.to_string()).into());
                 };
             }
             complexity += 1;
@@ -384,6 +444,57 @@ impl<'a> Oracle<'a> {
         Ok(())
     }
 
+    /// Asserts that the price sources resulting from the update don't depend on each other in a cycle.
+    /// Runs on the configured entries so a cycle is named before any price source is resolved.
+    fn assert_no_cycles(
+        &self,
+        deps: Deps,
+        to_add: &[(AssetEntry, UncheckedPriceSource)],
+        to_remove: &[AssetEntry],
+    ) -> AbstractResult<()> {
+        let mut graph: BTreeMap<AssetEntry, Vec<AssetEntry>> = self
+            .config
+            .range(deps.storage, None, None, Order::Ascending)
+            .map(|res| res.map(|(entry, source)| (entry.clone(), source.dependencies(&entry))))
+            .collect::<StdResult<_>>()?;
+        for entry in to_remove {
+            graph.remove(entry);
+        }
+        for (entry, source) in to_add {
+            graph.insert(entry.clone(), source.dependencies(entry));
+        }
+
+        // depth-first search, a dependency that is still on the path closes a cycle
+        fn visit(
+            entry: &AssetEntry,
+            graph: &BTreeMap<AssetEntry, Vec<AssetEntry>>,
+            path: &mut Vec<AssetEntry>,
+            done: &mut BTreeSet<AssetEntry>,
+        ) -> Result<(), OracleError> {
+            if done.contains(entry) {
+                return Ok(());
+            }
+            if let Some(start) = path.iter().position(|a| a == entry) {
+                return Err(OracleError::CircularPriceSource {
+                    assets: path[start..].to_vec(),
+                });
+            }
+            path.push(entry.clone());
+            for dependency in graph.get(entry).into_iter().flatten() {
+                visit(dependency, graph, path, done)?;
+            }
+            path.pop();
+            done.insert(entry.clone());
+            Ok(())
+        }
+
+        let mut done = BTreeSet::new();
+        for entry in graph.keys() {
+            visit(entry, &graph, &mut vec![], &mut done)?;
+        }
+        Ok(())
+    }
+
     /// Asserts that all dependencies of an asset are registered.
     fn assert_dependencies_exists(
         &self,
@@ -393,9 +504,7 @@ impl<'a> Oracle<'a> {
         for dependency in dependencies {
             let asset_info = self.assets.has(deps.storage, dependency);
             if !asset_info {
-                return Err(crate::AbstractError::Std(StdError::generic_err(format!(
-                    "Asset {dependency} not registered on oracle"
-                ))));
+                return Err(OracleError::NoPriceSource(dependency.to_string()).into());
             }
         }
         Ok(())
@@ -442,11 +551,11 @@ impl<'a> Oracle<'a> {
     }
     /// Get the highest complexity present in the oracle
     fn highest_complexity(&self, deps: Deps) -> AbstractResult<u8> {
-        Ok(self
-            .complexity
+        self.complexity
             .keys(deps.storage, None, None, Order::Descending)
-            .take(1)
-            .collect::<StdResult<Vec<u8>>>()?[0])
+            .next()
+            .transpose()?
+            .ok_or_else(|| OracleError::MissingBaseAsset.into())
     }
 
     /// get the configuration of an asset
@@ -461,17 +570,32 @@ impl<'a> Oracle<'a> {
     pub fn base_asset(&self, deps: Deps) -> AbstractResult<AssetInfo> {
         let base_asset = self.complexity.load(deps.storage, 0);
         let Ok(base_asset) = base_asset else {
-            return Err(StdError::generic_err("No base asset registered").into());
+            return Err(OracleError::MissingBaseAsset.into());
         };
-        let base_asset_len = base_asset.len();
-        if base_asset_len != 1 {
-            return Err(StdError::generic_err(format!(
-                "{base_asset_len} base assets registered, must be 1"
-            ))
-            .into());
+        match base_asset.len() {
+            0 => return Err(OracleError::MissingBaseAsset.into()),
+            1 => {}
+            base_asset_len => return Err(OracleError::MultipleBaseAssets(base_asset_len).into()),
         }
         Ok(base_asset[0].clone())
     }
+
+    /// Raw-query the base asset of the oracle held by the `remote` contract.
+    /// Returns `None` if the remote oracle has no base asset.
+    pub fn query_base_asset(
+        &self,
+        querier: &QuerierWrapper,
+        remote: Addr,
+    ) -> AbstractResult<Option<AssetInfo>> {
+        let base_asset = self
+            .complexity
+            .query(querier, remote, 0)?
+            .unwrap_or_default();
+        match base_asset.len() {
+            0 | 1 => Ok(base_asset.into_iter().next()),
+            base_asset_len => Err(OracleError::MultipleBaseAssets(base_asset_len).into()),
+        }
+    }
 }
 
 #[cw_serde]
@@ -529,7 +653,7 @@ mod tests {
     use super::*;
 
     use abstract_testing::prelude::*;
-    use cosmwasm_std::{coin, testing::*, Decimal};
+    use cosmwasm_std::{coin, testing::*, Decimal, StdResult};
     use speculoos::prelude::*;
 
     use crate::objects::DexAssetPairing;
@@ -716,6 +840,186 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn query_remote_base_asset() -> AResult {
+        let oracle = Oracle::new();
+        let querier = MockQuerierBuilder::default()
+            .with_contract_map_entry(
+                TEST_PROXY,
+                Map::<Complexity, Vec<AssetInfo>>::new("complexity"),
+                (0, vec![AssetInfo::native(USD)]),
+            )
+            .build();
+
+        let base_asset =
+            oracle.query_base_asset(&wrap_querier(&querier), Addr::unchecked(TEST_PROXY))?;
+        assert_that!(base_asset).is_equal_to(Some(AssetInfo::native(USD)));
+
+        // Nothing registered on the remote oracle
+        let querier = MockQuerierBuilder::default()
+            .with_contract_map_key(
+                TEST_PROXY,
+                Map::<Complexity, Vec<AssetInfo>>::new("complexity"),
+                0,
+            )
+            .build();
+        let base_asset =
+            oracle.query_base_asset(&wrap_querier(&querier), Addr::unchecked(TEST_PROXY))?;
+        assert_that!(base_asset).is_none();
+        Ok(())
+    }
+
+    mod errors {
+        use super::*;
+
+        fn setup() -> (MockDeps, AnsHost, Oracle<'static>) {
+            let mut deps = mock_dependencies();
+            deps.querier = MockAnsHost::new().with_defaults().to_querier();
+            (deps, get_ans(), Oracle::new())
+        }
+
+        #[test]
+        fn missing_base_asset() -> AResult {
+            let (deps, _, mut oracle) = setup();
+
+            assert_that!(oracle.base_asset(deps.as_ref()))
+                .is_err()
+                .is_equal_to(AbstractError::Oracle(OracleError::MissingBaseAsset));
+            assert_that!(oracle.account_value(deps.as_ref(), &Addr::unchecked(MOCK_CONTRACT_ADDR)))
+                .is_err()
+                .is_equal_to(AbstractError::Oracle(OracleError::MissingBaseAsset));
+            Ok(())
+        }
+
+        #[test]
+        fn multiple_base_assets() -> AResult {
+            let (mut deps, ans, oracle) = setup();
+
+            let res = oracle.update_assets(
+                deps.as_mut(),
+                &ans,
+                vec![
+                    base_asset(),
+                    (AssetEntry::from(EUR), UncheckedPriceSource::None),
+                ],
+                vec![],
+            );
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(AbstractError::Oracle(OracleError::MultipleBaseAssets(2)));
+            Ok(())
+        }
+
+        #[test]
+        fn no_price_source() -> AResult {
+            let (mut deps, ans, oracle) = setup();
+
+            // dependency not registered
+            let res = oracle.update_assets(deps.as_mut(), &ans, vec![asset_with_dep()], vec![]);
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(AbstractError::Oracle(OracleError::NoPriceSource(
+                    AssetInfo::native(USD).to_string(),
+                )));
+
+            let (mut deps, ans, oracle) = setup();
+            oracle.update_assets(deps.as_mut(), &ans, vec![base_asset()], vec![])?;
+
+            // removing an asset that isn't registered
+            let res = oracle.update_assets(deps.as_mut(), &ans, vec![], vec![AssetEntry::new(EUR)]);
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(AbstractError::Oracle(OracleError::NoPriceSource(
+                    EUR.to_owned(),
+                )));
+
+            // valuing an asset without price source
+            let res = oracle.asset_value(deps.as_ref(), Asset::new(AssetInfo::native(EUR), 1u128));
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(AbstractError::Oracle(OracleError::NoPriceSource(
+                    AssetInfo::native(EUR).to_string(),
+                )));
+            Ok(())
+        }
+
+        #[test]
+        fn asset_already_registered() -> AResult {
+            let (mut deps, ans, oracle) = setup();
+            oracle.update_assets(deps.as_mut(), &ans, vec![base_asset()], vec![])?;
+
+            let res = oracle.update_assets(deps.as_mut(), &ans, vec![base_asset()], vec![]);
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(AbstractError::Oracle(OracleError::AssetAlreadyRegistered(
+                    AssetInfo::native(USD).to_string(),
+                )));
+            Ok(())
+        }
+
+        #[test]
+        fn duplicate_assets_in_update() -> AResult {
+            let (mut deps, ans, oracle) = setup();
+
+            let res = oracle.update_assets(
+                deps.as_mut(),
+                &ans,
+                vec![asset_as_half()],
+                vec![asset_as_half().0],
+            );
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(AbstractError::Oracle(OracleError::DuplicateAssetsInUpdate));
+            Ok(())
+        }
+
+        #[test]
+        fn list_size_limit_exceeded() -> AResult {
+            let (mut deps, ans, oracle) = setup();
+
+            let to_add = (0..=LIST_SIZE_LIMIT)
+                .map(|i| {
+                    (
+                        AssetEntry::new(&format!("asset{i}")),
+                        UncheckedPriceSource::None,
+                    )
+                })
+                .collect();
+            let res = oracle.update_assets(deps.as_mut(), &ans, to_add, vec![]);
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(AbstractError::Oracle(OracleError::ListSizeLimitExceeded));
+            Ok(())
+        }
+
+        #[test]
+        fn circular_price_source() -> AResult {
+            let (mut deps, ans, oracle) = setup();
+            oracle.update_assets(deps.as_mut(), &ans, vec![base_asset()], vec![])?;
+
+            let eur_as_lp = (
+                AssetEntry::new(EUR),
+                UncheckedPriceSource::ValueAs {
+                    asset: AssetEntry::new(EUR_USD_LP),
+                    multiplier: Decimal::one(),
+                },
+            );
+            let lp = (
+                AssetEntry::new(EUR_USD_LP),
+                UncheckedPriceSource::LiquidityToken {},
+            );
+            let res = oracle.update_assets(deps.as_mut(), &ans, vec![eur_as_lp, lp], vec![]);
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(AbstractError::Oracle(OracleError::CircularPriceSource {
+                    assets: vec![AssetEntry::new(EUR_USD_LP), AssetEntry::new(EUR)],
+                }));
+            // nothing was stored
+            assert_that!(oracle.config.has(&deps.storage, &AssetEntry::new(EUR))).is_false();
+            Ok(())
+        }
+    }
+
     // test for pair
 
     // test for LP tokens
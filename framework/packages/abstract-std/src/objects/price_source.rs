@@ -65,6 +65,24 @@ pub enum UncheckedPriceSource {
 }
 
 impl UncheckedPriceSource {
+    /// Returns the entries of the assets that `entry` is priced in.
+    /// Liquidity tokens with a malformed entry have no dependencies, they are rejected by [`Self::check`].
+    pub fn dependencies(&self, entry: &AssetEntry) -> Vec<AssetEntry> {
+        match self {
+            UncheckedPriceSource::Pair(pairing) => [pairing.asset_x(), pairing.asset_y()]
+                .into_iter()
+                .filter(|a| *a != entry)
+                .cloned()
+                .collect(),
+            UncheckedPriceSource::LiquidityToken {} => AnsEntryConvertor::new(entry.clone())
+                .lp_token()
+                .map(|lp_token| lp_token.assets)
+                .unwrap_or_default(),
+            UncheckedPriceSource::ValueAs { asset, .. } => vec![asset.clone()],
+            UncheckedPriceSource::None => vec![],
+        }
+    }
+
     pub fn check(
         self,
         deps: Deps,
@@ -50,7 +50,7 @@ impl TruncatedChainId {
                 expected: format!("between {MIN_CHAIN_NAME_LENGTH} and {MAX_CHAIN_NAME_LENGTH}"),
                 actual: self.0.len().to_string(),
             });
-        // check character set
+        // check character set, this also rejects the account trace separator
         } else if !self.0.chars().all(|c| c.is_ascii_lowercase() || c == '-') {
             return Err(crate::AbstractError::FormattingError {
                 object: "chain_name".into(),
@@ -204,6 +204,26 @@ mod test {
         TruncatedChainId::from_str("a_aoeuoau").unwrap_err();
     }
 
+    #[test]
+    fn local_trace_separator_fails() {
+        let err = TruncatedChainId::from_str("eth>btc").unwrap_err();
+        assert_eq!(
+            err,
+            AbstractError::FormattingError {
+                object: "chain_name".into(),
+                expected: "chain-name".into(),
+                actual: "eth>btc".into(),
+            }
+        );
+        TruncatedChainId::from_string("juno>".to_string()).unwrap_err();
+    }
+
+    #[test]
+    fn valid_name_without_separator_passes() {
+        let chain_name = TruncatedChainId::from_string("osmosis".to_string()).unwrap();
+        assert_that!(chain_name.as_str()).is_equal_to("osmosis");
+    }
+
     #[test]
     fn from_chain_id() {
         let normal_chain_name = TruncatedChainId::from_chain_id("juno-1");
@@ -35,9 +35,24 @@ pub enum CwStakingError {
     #[error("Staking provider {0} is not local to this network.")]
     ForeignDex(String),
 
+    #[error("No host chain registered for staking provider {0}")]
+    UnknownProviderChain(String),
+
+    #[error("Staking provider {0} is hosted on {1}, not on {2}")]
+    ProviderChainMismatch(String, String, String),
+
+    #[error("Only account of abstract namespace can update configuration")]
+    Unauthorized {},
+
     #[error("Cw1155 is unsupported.")]
     Cw1155Unsupported,
 
+    #[error("Can't stake without assets")]
+    NoStakeAssets {},
+
+    #[error("Can't stake a zero amount of {0}")]
+    ZeroStakeAmount(String),
+
     #[error("Can't provide liquidity less than two assets")]
     TooFewAssets {},
 
@@ -4,7 +4,7 @@
 //! `abstract::cw-staking`
 use abstract_std::{
     adapter,
-    objects::{AnsAsset, AssetEntry},
+    objects::{AnsAsset, AssetEntry, TruncatedChainId},
 };
 use cosmwasm_schema::QueryResponses;
 use cosmwasm_std::Empty;
@@ -28,11 +28,22 @@ impl adapter::AdapterQueryMsg for StakingQueryMsg {}
 
 /// A request message that's sent to this staking adapter
 #[cosmwasm_schema::cw_serde]
-pub struct StakingExecuteMsg {
-    /// The name of the staking provider
-    pub provider: ProviderName,
-    /// the action to execute, see [StakingAction]
-    pub action: StakingAction,
+pub enum StakingExecuteMsg {
+    /// Execute a staking action on a provider
+    Action {
+        /// The name of the staking provider
+        provider: ProviderName,
+        /// the action to execute, see [StakingAction]
+        action: StakingAction,
+    },
+    /// Update the host chains of the providers that are reachable over IBC.
+    /// Only callable by the account of the abstract namespace.
+    UpdateProviderChains {
+        /// Providers and the chain they are hosted on
+        to_add: Vec<(ProviderName, TruncatedChainId)>,
+        /// Providers to remove
+        to_remove: Vec<ProviderName>,
+    },
 }
 
 /// Possible actions to perform on the staking contract
@@ -111,6 +122,17 @@ pub enum StakingQueryMsg {
         /// The staking tokens to query
         staking_tokens: Vec<AssetEntry>,
     },
+    /// Get how an action on the given provider would be routed, locally or over IBC
+    /// Returns [`RoutingResponse`]
+    #[returns(RoutingResponse)]
+    WouldRouteOverIbc {
+        /// Name of the provider, optionally prefixed with its chain (e.g. `juno>wyndex`)
+        provider: ProviderName,
+    },
+    /// Get the providers that can be used on the current chain
+    /// Returns [`SupportedProvidersResponse`]
+    #[returns(SupportedProvidersResponse)]
+    SupportedProviders {},
 }
 
 use cosmwasm_std::{Addr, Uint128};
@@ -195,6 +217,24 @@ pub struct RewardTokensResponse {
     pub tokens: Vec<Vec<AssetInfo>>,
 }
 
+/// Response for the routing query
+#[cosmwasm_schema::cw_serde]
+pub struct RoutingResponse {
+    /// Whether actions on the provider are executed over IBC
+    pub over_ibc: bool,
+    /// Name of the provider on the chain it's executed on
+    pub local_provider_name: ProviderName,
+    /// Chain the actions are executed on, `None` if no host chain is registered for the provider
+    pub chain: Option<TruncatedChainId>,
+}
+
+/// Response for the supported providers query
+#[cosmwasm_schema::cw_serde]
+pub struct SupportedProvidersResponse {
+    /// Names of the providers available on the current chain
+    pub providers: Vec<ProviderName>,
+}
+
 /// Response for the unbonding query
 #[cosmwasm_schema::cw_serde]
 pub struct UnbondingResponse {
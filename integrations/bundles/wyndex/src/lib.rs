@@ -495,6 +495,7 @@ impl WynDex {
         abstrct
             .ans_host
             .update_asset_addresses(
+                false,
                 vec![
                     (
                         eur_asset.to_string(),
@@ -91,6 +91,7 @@ pub fn test_send_funds() -> AnyResult<()> {
             msg: abstract_std::ibc_client::ExecuteMsg::SendFunds {
                 host_chain: "juno".parse().unwrap(),
                 funds: coins(test_amount, get_denom(&stargaze, token_subdenom.as_str())),
+                timeout_seconds: None,
             },
         },
     )?;
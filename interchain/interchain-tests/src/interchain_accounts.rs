@@ -744,6 +744,7 @@ mod test {
                 msg: abstract_std::ibc_client::ExecuteMsg::SendFunds {
                     funds: coins(10, origin_denom),
                     host_chain: TruncatedChainId::from_chain_id(STARGAZE),
+                    timeout_seconds: None,
                 },
             },
         )?;
@@ -114,6 +114,7 @@ impl AstroportStake {
         )?;
         // Add assets
         abstr_deployment.name_service().update_asset_addresses(
+            false,
             vec![
                 (ans_asset_a.clone(), asset_a),
                 (ans_asset_b.clone(), asset_b),
@@ -130,9 +131,11 @@ impl AstroportStake {
             .update_pools(vec![(pool, pool_metadata)], vec![])?;
         // Add lp asset
         let lp_token = LpToken::new(Self::name(), vec![ans_asset_a, ans_asset_b]);
-        abstr_deployment
-            .name_service()
-            .update_asset_addresses(vec![(lp_token.to_string(), lp_asset.clone())], vec![])?;
+        abstr_deployment.name_service().update_asset_addresses(
+            false,
+            vec![(lp_token.to_string(), lp_asset.clone())],
+            vec![],
+        )?;
 
         Ok(Self {
             chain,
@@ -76,6 +76,7 @@ impl AstrovaultStake {
         )?;
         // Add assets
         abstr_deployment.name_service().update_asset_addresses(
+            false,
             vec![
                 (ans_asset_a.clone(), asset_a),
                 (ans_asset_b.clone(), asset_b),
@@ -92,9 +93,11 @@ impl AstrovaultStake {
             .update_pools(vec![(pool, pool_metadata)], vec![])?;
         // Add lp asset
         let lp_token = LpToken::new(Self::name(), vec![ans_asset_a, ans_asset_b]);
-        abstr_deployment
-            .name_service()
-            .update_asset_addresses(vec![(lp_token.to_string(), lp_asset.clone())], vec![])?;
+        abstr_deployment.name_service().update_asset_addresses(
+            false,
+            vec![(lp_token.to_string(), lp_asset.clone())],
+            vec![],
+        )?;
 
         let rewards_sources: Vec<RewardSourceResponse> = chain.query(
             &astrovault::lp_staking::query_msg::QueryMsg::RewardSources {
@@ -77,6 +77,7 @@ impl KujiraStake {
         )?;
         // Add assets
         abstr_deployment.name_service().update_asset_addresses(
+            false,
             vec![
                 (ans_asset_a.to_string(), asset_a),
                 (ans_asset_b.to_string(), asset_b),
@@ -93,9 +94,11 @@ impl KujiraStake {
         abstr_deployment
             .name_service()
             .update_pools(vec![(pool, pool_metadata)], vec![])?;
-        abstr_deployment
-            .name_service()
-            .update_asset_addresses(vec![(lp_token.to_string(), lp_asset.clone())], vec![])?;
+        abstr_deployment.name_service().update_asset_addresses(
+            false,
+            vec![(lp_token.to_string(), lp_asset.clone())],
+            vec![],
+        )?;
 
         Ok(Self {
             chain,
@@ -1,17 +1,24 @@
 use abstract_adapter::sdk::{
-    feature_objects::AnsHost,
-    features::{AbstractNameService, AbstractResponse, AccountIdentification},
-    IbcInterface, Resolve,
+    features::{AbstractNameService, AbstractResponse, AccountIdentification, ResolveCache},
+    IbcInterface, ModuleRegistryInterface,
 };
 use abstract_adapter::std::ibc::Callback;
-use abstract_adapter::std::objects::TruncatedChainId;
-use abstract_staking_standard::msg::{ExecuteMsg, ProviderName, StakingAction, StakingExecuteMsg};
-use cosmwasm_std::{to_json_binary, Coin, Deps, DepsMut, Env, MessageInfo};
+use abstract_adapter::std::objects::{
+    namespace::{Namespace, ABSTRACT_NAMESPACE},
+    TruncatedChainId,
+};
+use abstract_adapter_utils::identity::decompose_platform_name;
+use abstract_staking_standard::{
+    msg::{ExecuteMsg, ProviderName, StakingAction, StakingExecuteMsg},
+    CwStakingError,
+};
+use cosmwasm_std::{ensure_eq, to_json_binary, Coin, Deps, DepsMut, Env, MessageInfo};
 
 use crate::{
     adapter::CwStakingAdapter,
     contract::{CwStakingAdapter as CwStakingContract, StakingResult},
     resolver::{self, is_over_ibc},
+    state::PROVIDER_CHAINS,
     CW_STAKING_ADAPTER_ID,
 };
 
@@ -23,18 +30,92 @@ pub fn execute_handler(
     adapter: CwStakingContract,
     msg: StakingExecuteMsg,
 ) -> StakingResult {
-    let StakingExecuteMsg {
-        provider: provider_name,
-        action,
-    } = msg;
-    // if provider is on an app-chain, execute the action on the app-chain
-    let (local_provider_name, is_over_ibc) = is_over_ibc(&env, &provider_name)?;
-    if is_over_ibc {
-        handle_ibc_request(&deps, info, &adapter, local_provider_name, &action)
-    } else {
-        // the action can be executed on the local chain
-        handle_local_request(deps, env, info, adapter, action, local_provider_name)
+    match msg {
+        StakingExecuteMsg::Action {
+            provider: provider_name,
+            action,
+        } => {
+            validate_action(&action)?;
+            // if provider is on an app-chain, execute the action on the app-chain
+            let (local_provider_name, is_over_ibc) = is_over_ibc(&env, &provider_name)?;
+            if is_over_ibc {
+                // is_over_ibc only routes providers prefixed with a chain over IBC
+                let (requested_chain, _) = decompose_platform_name(&provider_name);
+                let requested_chain = requested_chain.unwrap_or_default();
+                let host_chain =
+                    provider_host_chain(deps.as_ref(), &local_provider_name, &requested_chain)?;
+                handle_ibc_request(
+                    &deps,
+                    info,
+                    &adapter,
+                    local_provider_name,
+                    host_chain,
+                    &action,
+                )
+            } else {
+                // the action can be executed on the local chain
+                handle_local_request(deps, env, info, adapter, action, local_provider_name)
+            }
+        }
+        StakingExecuteMsg::UpdateProviderChains { to_add, to_remove } => {
+            // Only namespace owner (abstract) can change the provider chains
+            let namespace = adapter
+                .module_registry(deps.as_ref())?
+                .query_namespace(Namespace::new(ABSTRACT_NAMESPACE)?)?;
+
+            // unwrap namespace, since it's unlikely to have unclaimed abstract namespace
+            let namespace_info = namespace.unwrap();
+            ensure_eq!(
+                namespace_info.account_base,
+                adapter.target_account.clone().unwrap(),
+                CwStakingError::Unauthorized {}
+            );
+
+            for provider in to_remove {
+                PROVIDER_CHAINS.remove(deps.storage, &provider);
+            }
+            for (provider, chain) in to_add {
+                chain.verify()?;
+                PROVIDER_CHAINS.save(deps.storage, &provider, &chain)?;
+            }
+
+            Ok(adapter.response("update_provider_chains"))
+        }
+    }
+}
+
+/// Resolve the chain that hosts the given provider, it must be the chain the request is made for
+fn provider_host_chain(
+    deps: Deps,
+    provider_name: &ProviderName,
+    requested_chain: &str,
+) -> StakingResult<TruncatedChainId> {
+    let host_chain = PROVIDER_CHAINS
+        .may_load(deps.storage, provider_name)?
+        .ok_or_else(|| CwStakingError::UnknownProviderChain(provider_name.clone()))?;
+    ensure_eq!(
+        host_chain.as_str(),
+        requested_chain,
+        CwStakingError::ProviderChainMismatch(
+            provider_name.clone(),
+            host_chain.to_string(),
+            requested_chain.to_owned(),
+        )
+    );
+    Ok(host_chain)
+}
+
+/// Reject actions that would result in a no-op stake or an empty ICS20 transfer
+fn validate_action(action: &StakingAction) -> StakingResult<()> {
+    if let StakingAction::Stake { assets, .. } = action {
+        if assets.is_empty() {
+            return Err(CwStakingError::NoStakeAssets {});
+        }
+        if let Some(asset) = assets.iter().find(|asset| asset.amount.is_zero()) {
+            return Err(CwStakingError::ZeroStakeAmount(asset.name.to_string()));
+        }
     }
+    Ok(())
 }
 
 /// Handle an adapter request that can be executed on the local chain
@@ -60,19 +141,18 @@ fn handle_local_request(
 }
 
 /// Handle a request that needs to be executed on a remote chain
-/// TODO, this doesn't work as is. This should be corrected when working with ibc hooks ?
 fn handle_ibc_request(
     deps: &DepsMut,
     info: MessageInfo,
     adapter: &CwStakingContract,
     provider_name: ProviderName,
+    host_chain: TruncatedChainId,
     action: &StakingAction,
 ) -> StakingResult {
-    let host_chain = TruncatedChainId::from_string(provider_name.clone())?; // TODO : Especially this line is faulty
-    let ans = adapter.name_service(deps.as_ref());
+    let ans = adapter.cached_ans(deps.as_ref());
     let ibc_client = adapter.ibc_client(deps.as_ref());
     // get the to-be-sent assets from the action
-    let coins = resolve_assets_to_transfer(deps.as_ref(), action, ans.host())?;
+    let coins = resolve_assets_to_transfer(&ans, action)?;
     // construct the ics20 call(s)
     let ics20_transfer_msg = ibc_client.ics20_transfer(host_chain.clone(), coins)?;
     // construct the action to be called on the host
@@ -81,7 +161,7 @@ fn handle_ibc_request(
         manager_msgs: vec![abstract_adapter::std::manager::ExecuteMsg::ExecOnModule {
             module_id: CW_STAKING_ADAPTER_ID.to_string(),
             exec_msg: to_json_binary::<ExecuteMsg>(
-                &StakingExecuteMsg {
+                &StakingExecuteMsg::Action {
                     provider: provider_name.clone(),
                     action: action.clone(),
                 }
@@ -96,7 +176,7 @@ fn handle_ibc_request(
         None
     } else {
         Some(Callback {
-            msg: to_json_binary(&StakingExecuteMsg {
+            msg: to_json_binary(&StakingExecuteMsg::Action {
                 provider: provider_name.clone(),
                 action: action.clone(),
             })?,
@@ -110,21 +190,104 @@ fn handle_ibc_request(
         .add_messages(vec![ics20_transfer_msg, ibc_action_msg]))
 }
 
-/// Resolve the assets to be transferred to the host chain for the given action
+/// Resolve the assets to be transferred to the host chain for the given action.
+/// Assets that are staked more than once are resolved only once.
 fn resolve_assets_to_transfer(
-    deps: Deps,
-    dex_action: &StakingAction,
-    ans_host: &AnsHost,
+    ans: &ResolveCache<CwStakingContract>,
+    staking_action: &StakingAction,
 ) -> StakingResult<Vec<Coin>> {
-    match dex_action {
+    match staking_action {
         StakingAction::Stake { assets, .. } => {
             let resolved: Vec<Coin> = assets
-                .resolve(&deps.querier, ans_host)?
-                .into_iter()
-                .map(Coin::try_from)
-                .collect::<Result<_, cw_asset::AssetError>>()?;
+                .iter()
+                .map(|asset| {
+                    let info = ans.asset(&asset.name)?;
+                    Ok(Coin::try_from(cw_asset::Asset::new(info, asset.amount))?)
+                })
+                .collect::<StakingResult<_>>()?;
             Ok(resolved)
         }
-        _ => Ok(vec![]),
+        // The staked position lives on the host chain, unstaking doesn't require funds
+        StakingAction::Unstake { .. } => Ok(vec![]),
+        // Claimed tokens and rewards are paid out on the host chain
+        StakingAction::Claim { .. } | StakingAction::ClaimRewards { .. } => Ok(vec![]),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use abstract_adapter::abstract_testing::prelude::*;
+    use abstract_adapter::sdk::base::InstantiateEndpoint;
+    use abstract_adapter::std::{
+        adapter::{BaseInstantiateMsg, InstantiateMsg},
+        objects::{AnsAsset, AssetEntry},
+    };
+    use cosmwasm_std::{
+        coin,
+        testing::{mock_dependencies, mock_env, mock_info},
+        Empty,
+    };
+    use speculoos::prelude::*;
+
+    use super::*;
+    use crate::contract::CW_STAKING_ADAPTER;
+
+    fn resolve(action: StakingAction) -> StakingResult<Vec<Coin>> {
+        let mut deps = mock_dependencies();
+        deps.querier = MockAnsHost::new().with_defaults().to_querier();
+        CW_STAKING_ADAPTER.instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER, &[]),
+            InstantiateMsg {
+                base: BaseInstantiateMsg {
+                    ans_host_address: TEST_ANS_HOST.to_owned(),
+                    version_control_address: TEST_VERSION_CONTROL.to_owned(),
+                },
+                module: Empty {},
+            },
+        )?;
+        let adapter = CW_STAKING_ADAPTER;
+        let ans = adapter.cached_ans(deps.as_ref());
+        resolve_assets_to_transfer(&ans, &action)
+    }
+
+    #[test]
+    fn stake_transfers_assets() -> StakingResult<()> {
+        let coins = resolve(StakingAction::Stake {
+            assets: vec![AnsAsset::new(EUR, 100u128), AnsAsset::new(USD, 50u128)],
+            unbonding_period: None,
+        })?;
+        assert_that!(coins).is_equal_to(vec![coin(100, EUR), coin(50, USD)]);
+        Ok(())
+    }
+
+    #[test]
+    fn stake_cw20_is_not_transferable() {
+        let res = resolve(StakingAction::Stake {
+            assets: vec![AnsAsset::new(EUR_USD_LP, 100u128)],
+            unbonding_period: None,
+        });
+        assert_that!(res).is_err();
+    }
+
+    #[test]
+    fn unstake_and_claims_transfer_nothing() -> StakingResult<()> {
+        let coins = resolve(StakingAction::Unstake {
+            assets: vec![AnsAsset::new(EUR, 100u128)],
+            unbonding_period: None,
+        })?;
+        assert_that!(coins).is_empty();
+
+        let coins = resolve(StakingAction::Claim {
+            assets: vec![AssetEntry::new(EUR)],
+        })?;
+        assert_that!(coins).is_empty();
+
+        let coins = resolve(StakingAction::ClaimRewards {
+            assets: vec![AssetEntry::new(EUR)],
+        })?;
+        assert_that!(coins).is_empty();
+        Ok(())
     }
 }
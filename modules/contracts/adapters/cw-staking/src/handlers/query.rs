@@ -1,10 +1,15 @@
 use abstract_adapter::sdk::features::{AbstractNameService, AbstractRegistryAccess};
-use abstract_staking_standard::{msg::StakingQueryMsg, CwStakingError};
+use abstract_adapter::std::objects::TruncatedChainId;
+use abstract_staking_standard::{
+    msg::{RoutingResponse, StakingQueryMsg, SupportedProvidersResponse},
+    CwStakingError,
+};
 use cosmwasm_std::{to_json_binary, Binary, Deps, Env, StdError};
 
 use crate::{
     contract::{CwStakingAdapter, StakingResult},
     resolver::{self, is_over_ibc},
+    state::PROVIDER_CHAINS,
 };
 /// Handle queries related to staking
 pub fn query_handler(
@@ -122,5 +127,24 @@ pub fn query_handler(
                 Ok(to_json_binary(&provider.query_rewards(&deps.querier)?)?)
             }
         }
+        StakingQueryMsg::WouldRouteOverIbc { provider } => {
+            // same routing as the execute handler
+            let (local_provider_name, over_ibc) = is_over_ibc(&env, &provider)?;
+            let chain = if over_ibc {
+                PROVIDER_CHAINS.may_load(deps.storage, &local_provider_name)?
+            } else {
+                Some(TruncatedChainId::new(&env))
+            };
+            Ok(to_json_binary(&RoutingResponse {
+                over_ibc,
+                local_provider_name,
+                chain,
+            })?)
+        }
+        StakingQueryMsg::SupportedProviders {} => {
+            Ok(to_json_binary(&SupportedProvidersResponse {
+                providers: resolver::supported_local_providers(&env),
+            })?)
+        }
     }
 }
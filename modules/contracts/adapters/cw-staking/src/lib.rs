@@ -3,6 +3,7 @@ pub mod contract;
 mod handlers;
 
 mod resolver;
+pub mod state;
 
 pub mod msg {
     pub use abstract_staking_standard::msg::*;
@@ -95,7 +96,7 @@ pub mod interface {
             let account = account.as_ref();
             let swap_msg = crate::msg::ExecuteMsg::Module(adapter::AdapterRequestMsg {
                 proxy_address: Some(account.proxy.addr_str()?),
-                request: StakingExecuteMsg { provider, action },
+                request: StakingExecuteMsg::Action { provider, action },
             });
             self.execute(&swap_msg, None).map_err(Into::into)
         }
@@ -1,7 +1,7 @@
 use abstract_adapter_utils::identity::{
     decompose_platform_name, is_available_on, is_current_chain,
 };
-use abstract_staking_standard::{CwStakingCommand, CwStakingError, Identify};
+use abstract_staking_standard::{msg::ProviderName, CwStakingCommand, CwStakingError, Identify};
 use cosmwasm_std::Env;
 
 use crate::contract::StakingResult;
@@ -59,6 +59,29 @@ pub(crate) fn resolve_local_provider(
     }
 }
 
+/// Names of the local providers that are available on the current chain
+pub(crate) fn supported_local_providers(env: &Env) -> Vec<ProviderName> {
+    #[allow(unused_mut)]
+    let mut names: Vec<&str> = vec![];
+    #[cfg(feature = "wynd")]
+    names.push(abstract_wyndex_adapter::WYNDEX);
+    #[cfg(feature = "osmosis")]
+    names.push(abstract_osmosis_adapter::OSMOSIS);
+    #[cfg(feature = "astroport")]
+    names.push(abstract_astroport_adapter::ASTROPORT);
+    #[cfg(feature = "bow")]
+    names.push(abstract_kujira_adapter::staking::BOW);
+    #[cfg(feature = "astrovault")]
+    names.push(abstract_astrovault_adapter::ASTROVAULT);
+    names
+        .into_iter()
+        .filter(|name| {
+            identify_provider(name).is_ok_and(|provider| is_available_on(provider, env, None))
+        })
+        .map(str::to_owned)
+        .collect()
+}
+
 /// Given a FULL provider nam (e.g. juno>wyndex), returns wether the request is local or over IBC
 pub fn is_over_ibc(env: &Env, platform_name: &str) -> StakingResult<(String, bool)> {
     let (chain_name, local_platform_name) = decompose_platform_name(platform_name);
@@ -91,7 +91,7 @@ impl<Chain: MutCwEnv, StakingProvider: MockStaking> StakingTester<Chain, Staking
         self.staking_adapter.execute(
             &crate::msg::ExecuteMsg::Module(adapter::AdapterRequestMsg {
                 proxy_address: Some(proxy_addr.to_string()),
-                request: StakingExecuteMsg {
+                request: StakingExecuteMsg::Action {
                     provider: self.provider.name(),
                     action: StakingAction::Stake {
                         assets: vec![AnsAsset::new(ans_stake_token.clone(), stake_value)],
@@ -135,7 +135,7 @@ impl<Chain: MutCwEnv, StakingProvider: MockStaking> StakingTester<Chain, Staking
         self.staking_adapter.execute(
             &crate::msg::ExecuteMsg::Module(adapter::AdapterRequestMsg {
                 proxy_address: Some(proxy_addr.to_string()),
-                request: StakingExecuteMsg {
+                request: StakingExecuteMsg::Action {
                     provider: self.provider.name(),
                     action: StakingAction::Stake {
                         assets: vec![AnsAsset::new(ans_stake_token.clone(), stake_value * 2)],
@@ -150,7 +150,7 @@ impl<Chain: MutCwEnv, StakingProvider: MockStaking> StakingTester<Chain, Staking
         self.staking_adapter.execute(
             &crate::msg::ExecuteMsg::Module(adapter::AdapterRequestMsg {
                 proxy_address: Some(proxy_addr.to_string()),
-                request: StakingExecuteMsg {
+                request: StakingExecuteMsg::Action {
                     provider: self.provider.name(),
                     action: StakingAction::Unstake {
                         assets: vec![AnsAsset::new(ans_stake_token.clone(), stake_value)],
@@ -169,7 +169,7 @@ impl<Chain: MutCwEnv, StakingProvider: MockStaking> StakingTester<Chain, Staking
         self.staking_adapter.execute(
             &crate::msg::ExecuteMsg::Module(adapter::AdapterRequestMsg {
                 proxy_address: Some(proxy_addr.to_string()),
-                request: StakingExecuteMsg {
+                request: StakingExecuteMsg::Action {
                     provider: self.provider.name(),
                     action: StakingAction::Unstake {
                         assets: vec![AnsAsset::new(ans_stake_token.clone(), stake_value)],
@@ -219,7 +219,7 @@ impl<Chain: MutCwEnv, StakingProvider: MockStaking> StakingTester<Chain, Staking
         self.staking_adapter.execute(
             &crate::msg::ExecuteMsg::Module(adapter::AdapterRequestMsg {
                 proxy_address: Some(proxy_addr.to_string()),
-                request: StakingExecuteMsg {
+                request: StakingExecuteMsg::Action {
                     provider: self.provider.name(),
                     action: StakingAction::Stake {
                         assets: vec![AnsAsset::new(ans_stake_token.clone(), stake_value)],
@@ -235,7 +235,7 @@ impl<Chain: MutCwEnv, StakingProvider: MockStaking> StakingTester<Chain, Staking
         self.staking_adapter.execute(
             &crate::msg::ExecuteMsg::Module(adapter::AdapterRequestMsg {
                 proxy_address: Some(proxy_addr.to_string()),
-                request: StakingExecuteMsg {
+                request: StakingExecuteMsg::Action {
                     provider: self.provider.name(),
                     action: StakingAction::ClaimRewards {
                         assets: vec![AssetEntry::new(&ans_stake_token)],
@@ -287,7 +287,7 @@ impl<Chain: MutCwEnv, StakingProvider: MockStaking> StakingTester<Chain, Staking
         self.staking_adapter.execute(
             &crate::msg::ExecuteMsg::Module(adapter::AdapterRequestMsg {
                 proxy_address: Some(proxy_addr.to_string()),
-                request: StakingExecuteMsg {
+                request: StakingExecuteMsg::Action {
                     provider: self.provider.name(),
                     action: StakingAction::Stake {
                         assets: vec![AnsAsset::new(ans_stake_token.clone(), stake_value)],
@@ -0,0 +1,5 @@
+use abstract_adapter::std::objects::TruncatedChainId;
+use cw_storage_plus::Map;
+
+/// Host chain of the providers that are reachable over IBC
+pub const PROVIDER_CHAINS: Map<&str, TruncatedChainId> = Map::new("provider_chains");
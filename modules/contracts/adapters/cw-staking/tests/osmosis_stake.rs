@@ -99,7 +99,7 @@ mod osmosis_test {
         ) -> Result<(), AbstractInterfaceError> {
             let stake_msg = ExecuteMsg::Module(adapter::AdapterRequestMsg {
                 proxy_address: None,
-                request: StakingExecuteMsg {
+                request: StakingExecuteMsg::Action {
                     provider,
                     action: StakingAction::Stake {
                         assets: stake_assets,
@@ -122,7 +122,7 @@ mod osmosis_test {
         ) -> Result<(), AbstractInterfaceError> {
             let stake_msg = ExecuteMsg::Module(adapter::AdapterRequestMsg {
                 proxy_address: None,
-                request: StakingExecuteMsg {
+                request: StakingExecuteMsg::Action {
                     provider,
                     action: StakingAction::Unstake {
                         assets: stake_assets,
@@ -144,7 +144,7 @@ mod osmosis_test {
         ) -> Result<(), AbstractInterfaceError> {
             let claim_msg = ExecuteMsg::Module(adapter::AdapterRequestMsg {
                 proxy_address: None,
-                request: StakingExecuteMsg {
+                request: StakingExecuteMsg::Action {
                     provider,
                     action: StakingAction::Claim {
                         assets: stake_assets,
@@ -165,7 +165,7 @@ mod osmosis_test {
         ) -> Result<(), AbstractInterfaceError> {
             let claim_rewards_msg = ExecuteMsg::Module(adapter::AdapterRequestMsg {
                 proxy_address: None,
-                request: StakingExecuteMsg {
+                request: StakingExecuteMsg::Action {
                     provider,
                     action: StakingAction::ClaimRewards {
                         assets: stake_assets,
@@ -208,6 +208,7 @@ mod osmosis_test {
         deployment
             .ans_host
             .update_asset_addresses(
+                false,
                 vec![
                     ("osmo".to_string(), cw_asset::AssetInfoBase::native(ASSET_1)),
                     ("atom".to_string(), cw_asset::AssetInfoBase::native(ASSET_2)),
@@ -416,6 +417,7 @@ mod osmosis_test {
         deployment
             .ans_host
             .update_asset_addresses(
+                false,
                 vec![
                     (
                         "osmo2".to_string(),
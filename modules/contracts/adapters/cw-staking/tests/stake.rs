@@ -1,17 +1,24 @@
 mod common;
 
 use abstract_adapter::abstract_interface::{
-    Abstract, AbstractAccount, AdapterDeployer, DeployStrategy,
+    Abstract, AbstractAccount, AbstractInterfaceError, AdapterDeployer, DeployStrategy,
 };
 use abstract_adapter::std::{
-    adapter::BaseQueryMsgFns,
-    objects::{module_version::ModuleDataResponse, AnsAsset, AssetEntry},
+    adapter::{AdapterRequestMsg, BaseQueryMsgFns},
+    objects::{
+        module_version::ModuleDataResponse, AnsAsset, AssetEntry, TruncatedChainId,
+        ABSTRACT_ACCOUNT_ID,
+    },
 };
 use abstract_cw_staking::{
     contract::CONTRACT_VERSION, interface::CwStakingAdapter, msg::StakingQueryMsgFns,
 };
-use abstract_staking_standard::msg::{
-    Claim, RewardTokensResponse, StakingInfo, StakingInfoResponse, UnbondingResponse,
+use abstract_staking_standard::{
+    msg::{
+        Claim, ExecuteMsg, RewardTokensResponse, RoutingResponse, StakingAction, StakingExecuteMsg,
+        StakingInfo, StakingInfoResponse, UnbondingResponse,
+    },
+    CwStakingError,
 };
 use cosmwasm_std::{coin, Uint128};
 use cw20::msg::Cw20ExecuteMsgFns;
@@ -83,6 +90,10 @@ fn staking_inited() -> anyhow::Result<()> {
         tokens: vec![vec![AssetInfoBase::Native(WYND_TOKEN.to_owned())]],
     });
 
+    // wyndex is available on the test chain
+    let supported = staking.supported_providers()?;
+    assert_that!(supported.providers).contains(WYNDEX_WITHOUT_CHAIN.to_owned());
+
     let module_data = staking.module_data()?;
     assert_eq!(
         module_data,
@@ -145,6 +156,161 @@ fn stake_lp_wthout_chain() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn stake_rejects_empty_and_zero_assets() -> anyhow::Result<()> {
+    let (_, _, staking, os) = setup_mock()?;
+
+    let dur = Some(cw_utils::Duration::Time(2));
+    let staking_err = |provider: &str, assets: Vec<AnsAsset>| -> CwStakingError {
+        let err = staking
+            .staking_action(
+                provider.into(),
+                StakingAction::Stake {
+                    assets,
+                    unbonding_period: dur,
+                },
+                &os,
+            )
+            .unwrap_err();
+        let AbstractInterfaceError::Orch(orch_error) = err else {
+            panic!("unexpected error type");
+        };
+        orch_error.downcast().unwrap()
+    };
+
+    // local provider
+    assert_that!(staking_err(WYNDEX, vec![])).is_equal_to(CwStakingError::NoStakeAssets {});
+    assert_that!(staking_err(WYNDEX, vec![AnsAsset::new(EUR_USD_LP, 0u128)]))
+        .is_equal_to(CwStakingError::ZeroStakeAmount(EUR_USD_LP.to_owned()));
+
+    // provider over IBC
+    assert_that!(staking_err("juno>wyndex", vec![])).is_equal_to(CwStakingError::NoStakeAssets {});
+    assert_that!(staking_err(
+        "juno>wyndex",
+        vec![
+            AnsAsset::new(EUR_USD_LP, 100u128),
+            AnsAsset::new(EUR_USD_LP, 0u128)
+        ]
+    ))
+    .is_equal_to(CwStakingError::ZeroStakeAmount(EUR_USD_LP.to_owned()));
+
+    Ok(())
+}
+
+#[test]
+fn ibc_stake_requires_registered_provider_chain() -> anyhow::Result<()> {
+    let (chain, _, staking, os) = setup_mock()?;
+    let abstr = Abstract::load_from(chain)?;
+
+    let stake = |account: &AbstractAccount<MockBech32>| {
+        staking.staking_action(
+            "juno>wyndex".into(),
+            StakingAction::Stake {
+                assets: vec![AnsAsset::new(EUR_USD_LP, 100u128)],
+                unbonding_period: Some(cw_utils::Duration::Time(2)),
+            },
+            account,
+        )
+    };
+    let update_provider_chains = |account: &AbstractAccount<MockBech32>, chain: &str| {
+        staking.execute(
+            &ExecuteMsg::Module(AdapterRequestMsg {
+                proxy_address: Some(account.proxy.addr_str()?),
+                request: StakingExecuteMsg::UpdateProviderChains {
+                    to_add: vec![(
+                        WYNDEX_WITHOUT_CHAIN.to_owned(),
+                        TruncatedChainId::from_string(chain.to_owned()).unwrap(),
+                    )],
+                    to_remove: vec![],
+                },
+            }),
+            None,
+        )
+    };
+    let unknown_provider_chain =
+        CwStakingError::UnknownProviderChain(WYNDEX_WITHOUT_CHAIN.to_owned());
+
+    // provider has no registered host chain
+    let AbstractInterfaceError::Orch(orch_error) = stake(&os).unwrap_err() else {
+        panic!("unexpected error type");
+    };
+    assert_that!(orch_error.downcast::<CwStakingError>()?).is_equal_to(&unknown_provider_chain);
+
+    // only the abstract account can register provider chains
+    let err = update_provider_chains(&os, "juno").unwrap_err();
+    assert_that!(err.downcast::<CwStakingError>()?).is_equal_to(CwStakingError::Unauthorized {});
+
+    // provider is registered on another chain than the requested one
+    let account0 = AbstractAccount::new(&abstr, ABSTRACT_ACCOUNT_ID);
+    update_provider_chains(&account0, "osmosis")?;
+    let AbstractInterfaceError::Orch(orch_error) = stake(&os).unwrap_err() else {
+        panic!("unexpected error type");
+    };
+    assert_that!(orch_error.downcast::<CwStakingError>()?).is_equal_to(
+        CwStakingError::ProviderChainMismatch(
+            WYNDEX_WITHOUT_CHAIN.to_owned(),
+            "osmosis".to_owned(),
+            "juno".to_owned(),
+        ),
+    );
+
+    // the host chain is resolved, so the request gets to the ICS20 transfer,
+    // which can't send the cw20 LP token
+    update_provider_chains(&account0, "juno")?;
+    let AbstractInterfaceError::Orch(orch_error) = stake(&os).unwrap_err() else {
+        panic!("unexpected error type");
+    };
+    assert!(matches!(
+        orch_error.downcast::<CwStakingError>()?,
+        CwStakingError::AssetError(cw_asset::AssetError::CannotCastToStdCoin { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn would_route_over_ibc() -> anyhow::Result<()> {
+    let (chain, _, staking, _) = setup_mock()?;
+    let abstr = Abstract::load_from(chain)?;
+
+    // local provider
+    let routing = staking.would_route_over_ibc(WYNDEX.to_owned())?;
+    assert_that!(routing).is_equal_to(RoutingResponse {
+        over_ibc: false,
+        local_provider_name: WYNDEX_WITHOUT_CHAIN.to_owned(),
+        chain: Some(TruncatedChainId::from_string("cosmos-testnet".to_owned())?),
+    });
+
+    // provider on another chain, its host chain isn't registered yet
+    let routing = staking.would_route_over_ibc("juno>wyndex".to_owned())?;
+    assert_that!(routing).is_equal_to(RoutingResponse {
+        over_ibc: true,
+        local_provider_name: WYNDEX_WITHOUT_CHAIN.to_owned(),
+        chain: None,
+    });
+
+    let juno = TruncatedChainId::from_string("juno".to_owned())?;
+    let account0 = AbstractAccount::new(&abstr, ABSTRACT_ACCOUNT_ID);
+    staking.execute(
+        &ExecuteMsg::Module(AdapterRequestMsg {
+            proxy_address: Some(account0.proxy.addr_str()?),
+            request: StakingExecuteMsg::UpdateProviderChains {
+                to_add: vec![(WYNDEX_WITHOUT_CHAIN.to_owned(), juno.clone())],
+                to_remove: vec![],
+            },
+        }),
+        None,
+    )?;
+    let routing = staking.would_route_over_ibc("juno>wyndex".to_owned())?;
+    assert_that!(routing).is_equal_to(RoutingResponse {
+        over_ibc: true,
+        local_provider_name: WYNDEX_WITHOUT_CHAIN.to_owned(),
+        chain: Some(juno),
+    });
+
+    Ok(())
+}
+
 #[test]
 fn unstake_lp() -> anyhow::Result<()> {
     let (_, _, staking, os) = setup_mock()?;
@@ -71,9 +71,11 @@ impl<Chain: MutCwEnv, Dex: MockDex> DexTester<Chain, Dex> {
         let lp_asset = {
             let (pool, pool_metadata, lp_asset) = dex.create_pool()?;
             // Add assets
-            abstr_deployment
-                .name_service()
-                .update_asset_addresses(vec![dex.asset_a(), dex.asset_b()], vec![])?;
+            abstr_deployment.name_service().update_asset_addresses(
+                false,
+                vec![dex.asset_a(), dex.asset_b()],
+                vec![],
+            )?;
             // Add dex
             abstr_deployment
                 .name_service()
@@ -84,9 +86,11 @@ impl<Chain: MutCwEnv, Dex: MockDex> DexTester<Chain, Dex> {
                 .update_pools(vec![(pool, pool_metadata)], vec![])?;
             // Add lp asset
             let lp_token = LpToken::new(dex.name(), vec![dex.asset_a().0, dex.asset_b().0]);
-            abstr_deployment
-                .name_service()
-                .update_asset_addresses(vec![(lp_token.to_string(), lp_asset.clone())], vec![])?;
+            abstr_deployment.name_service().update_asset_addresses(
+                false,
+                vec![(lp_token.to_string(), lp_asset.clone())],
+                vec![],
+            )?;
             lp_asset
         };
 
@@ -150,6 +150,7 @@ fn setup_mock() -> anyhow::Result<(
     deployment
         .ans_host
         .update_asset_addresses(
+            false,
             vec![
                 ("atom".to_string(), cw_asset::AssetInfoBase::native(atom)),
                 ("osmo".to_string(), cw_asset::AssetInfoBase::native(osmo)),
@@ -232,6 +233,7 @@ fn swap_concentrated_liquidity() -> AnyResult<()> {
     deployment
         .ans_host
         .update_asset_addresses(
+            false,
             vec![
                 (
                     "osmo2".to_string(),
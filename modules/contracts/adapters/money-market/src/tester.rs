@@ -74,6 +74,7 @@ impl<Chain: MutCwEnv, Moneymarket: MockMoneyMarket> MoneyMarketTester<Chain, Mon
 
         // Registering assets
         abstr_deployment.name_service().update_asset_addresses(
+            false,
             vec![moneymarket.lending_asset(), moneymarket.collateral_asset()],
             vec![],
         )?;
@@ -123,6 +123,7 @@ fn setup() -> anyhow::Result<(
 
     abstr_deployment.ans_host.execute(
         &abstract_app::std::ans_host::ExecuteMsg::UpdateAssetAddresses {
+            allow_overwrite: false,
             to_add: vec![("denom".to_owned(), AssetInfo::native(DENOM).into())],
             to_remove: vec![],
         },
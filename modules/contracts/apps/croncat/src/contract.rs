@@ -24,6 +24,7 @@ pub const CRONCAT_APP: CroncatApp = CroncatApp::new(CRONCAT_ID, CRONCAT_MODULE_V
     .with_instantiate(handlers::instantiate_handler)
     .with_execute(handlers::execute_handler)
     .with_query(handlers::query_handler)
+    .with_migrate(handlers::migrate_handler)
     .with_replies(&[
         (TASK_CREATE_REPLY_ID, replies::create_task_reply),
         (TASK_REMOVE_REPLY_ID, replies::task_remove_reply),
@@ -54,7 +54,9 @@ fn update_config(deps: DepsMut, msg_info: MessageInfo, app: CroncatApp) -> Cronc
     // Only the admin should be able to call this
     app.admin.assert_admin(deps.as_ref(), &msg_info.sender)?;
 
-    CONFIG.save(deps.storage, &Config {})?;
+    let name_service = app.name_service(deps.as_ref());
+    let factory_addr = factory_addr(&name_service)?;
+    CONFIG.save(deps.storage, &Config { factory_addr })?;
     Ok(app.response("update_config"))
 }
 
@@ -82,8 +84,7 @@ fn create_task(
 
     let (funds, cw20s) = sort_funds(deps.api, assets)?;
 
-    let name_service = app.name_service(deps.as_ref());
-    let factory_addr = factory_addr(&name_service)?;
+    let factory_addr = CONFIG.load(deps.storage)?.factory_addr;
     let executor = app.executor(deps.as_ref());
 
     // Getting needed croncat addresses from factory
@@ -148,8 +149,7 @@ fn remove_task(
     let key = (msg_info.sender, task_tag);
     let (task_hash, task_version) = ACTIVE_TASKS.load(deps.storage, key.clone())?;
 
-    let name_service = app.name_service(deps.as_ref());
-    let factory_addr = factory_addr(&name_service)?;
+    let factory_addr = CONFIG.load(deps.storage)?.factory_addr;
     let tasks_addr = get_croncat_contract(
         &deps.querier,
         factory_addr.clone(),
@@ -231,8 +231,7 @@ fn refill_task(
 
     let executor = app.executor(deps);
 
-    let name_service = app.name_service(deps);
-    let factory_addr = factory_addr(&name_service)?;
+    let factory_addr = CONFIG.load(deps.storage)?.factory_addr;
     let manager_addr = get_croncat_contract(
         &deps.querier,
         factory_addr,
@@ -15,11 +15,10 @@ pub fn instantiate_handler(
     app: CroncatApp,
     _msg: AppInstantiateMsg,
 ) -> CroncatResult {
-    CONFIG.save(deps.storage, &Config {})?;
-
     let name_service = app.name_service(deps.as_ref());
-    utils::factory_addr(&name_service).map_err(|err| {
+    let factory_addr = utils::factory_addr(&name_service).map_err(|err| {
         AbstractSdkError::generic_err(format!("Cron Cat Factory not found in ANS: {err:?}"))
     })?;
+    CONFIG.save(deps.storage, &Config { factory_addr })?;
     Ok(Response::new())
 }
@@ -0,0 +1,16 @@
+use abstract_app::sdk::{features::AbstractNameService, AbstractResponse};
+use cosmwasm_std::{DepsMut, Empty, Env};
+
+use crate::{
+    contract::{CroncatApp, CroncatResult},
+    state::{Config, CONFIG},
+    utils,
+};
+
+pub fn migrate_handler(deps: DepsMut, _env: Env, app: CroncatApp, _msg: Empty) -> CroncatResult {
+    // Back-fill the factory address for apps instantiated before it was cached in the config
+    let name_service = app.name_service(deps.as_ref());
+    let factory_addr = utils::factory_addr(&name_service)?;
+    CONFIG.save(deps.storage, &Config { factory_addr })?;
+    Ok(app.response("migrate"))
+}
@@ -1,6 +1,5 @@
 use std::collections::HashMap;
 
-use abstract_app::sdk::features::AbstractNameService;
 use cosmwasm_std::{to_json_binary, Addr, Binary, Deps, Env, QuerierWrapper, StdResult};
 use croncat_integration_utils::{task_creation::get_croncat_contract, MANAGER_NAME, TASKS_NAME};
 use croncat_sdk_manager::{msg::ManagerQueryMsg, types::TaskBalanceResponse};
@@ -11,7 +10,6 @@ use crate::{
     contract::{CroncatApp, CroncatResult},
     msg::{ActiveTasksByCreatorResponse, ActiveTasksResponse, AppQueryMsg, ConfigResponse},
     state::{ACTIVE_TASKS, CONFIG},
-    utils::factory_addr,
 };
 
 pub const DEFAULT_LIMIT: u32 = 50;
@@ -48,7 +46,7 @@ fn check_if_task_exists(
 pub fn query_handler(
     deps: Deps,
     _env: Env,
-    app: &CroncatApp,
+    _app: &CroncatApp,
     msg: AppQueryMsg,
 ) -> CroncatResult<Binary> {
     match msg {
@@ -57,7 +55,7 @@ pub fn query_handler(
             start_after,
             limit,
             checked,
-        } => to_json_binary(&query_active_tasks(deps, app, start_after, limit, checked)?),
+        } => to_json_binary(&query_active_tasks(deps, start_after, limit, checked)?),
         AppQueryMsg::ActiveTasksByCreator {
             creator_addr,
             start_after,
@@ -65,7 +63,6 @@ pub fn query_handler(
             checked,
         } => to_json_binary(&query_active_tasks_by_creator(
             deps,
-            app,
             creator_addr,
             start_after,
             limit,
@@ -74,15 +71,15 @@ pub fn query_handler(
         AppQueryMsg::TaskInfo {
             creator_addr,
             task_tag,
-        } => to_json_binary(&query_task_info(deps, app, creator_addr, task_tag)?),
+        } => to_json_binary(&query_task_info(deps, creator_addr, task_tag)?),
         AppQueryMsg::TaskBalance {
             creator_addr,
             task_tag,
-        } => to_json_binary(&query_task_balance(deps, app, creator_addr, task_tag)?),
+        } => to_json_binary(&query_task_balance(deps, creator_addr, task_tag)?),
         AppQueryMsg::ManagerAddr {
             creator_addr,
             task_tag,
-        } => to_json_binary(&query_manager_addr(deps, app, creator_addr, task_tag)?),
+        } => to_json_binary(&query_manager_addr(deps, creator_addr, task_tag)?),
     }
     .map_err(Into::into)
 }
@@ -94,7 +91,6 @@ fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
 
 fn query_active_tasks(
     deps: Deps,
-    app: &CroncatApp,
     start_after: Option<(String, String)>,
     limit: Option<u32>,
     checked: Option<bool>,
@@ -115,8 +111,7 @@ fn query_active_tasks(
 
     let response = match check {
         true => {
-            let name_service = app.name_service(deps);
-            let factory_addr = factory_addr(&name_service)?;
+            let factory_addr = CONFIG.load(deps.storage)?.factory_addr;
             let mut manager_addrs = HashMap::new();
             let mut removed_tasks = Vec::new();
 
@@ -159,7 +154,6 @@ fn query_active_tasks(
 
 fn query_active_tasks_by_creator(
     deps: Deps,
-    app: &CroncatApp,
     creator: String,
     start_after: Option<String>,
     limit: Option<u32>,
@@ -178,8 +172,7 @@ fn query_active_tasks_by_creator(
 
     match check {
         true => {
-            let name_service = app.name_service(deps);
-            let factory_addr = factory_addr(&name_service)?;
+            let factory_addr = CONFIG.load(deps.storage)?.factory_addr;
             let mut manager_addrs = HashMap::new();
             let mut removed_tasks = Vec::new();
 
@@ -219,15 +212,13 @@ fn query_active_tasks_by_creator(
 
 fn query_task_info(
     deps: Deps,
-    app: &CroncatApp,
     creator_addr: String,
     task_tag: String,
 ) -> CroncatResult<TaskResponse> {
     let creator_addr = deps.api.addr_validate(&creator_addr)?;
     let (task_hash, task_version) = ACTIVE_TASKS.load(deps.storage, (creator_addr, task_tag))?;
 
-    let name_service = app.name_service(deps);
-    let factory_addr = factory_addr(&name_service)?;
+    let factory_addr = CONFIG.load(deps.storage)?.factory_addr;
     let tasks_addr = get_croncat_contract(
         &deps.querier,
         factory_addr,
@@ -243,15 +234,13 @@ fn query_task_info(
 
 fn query_task_balance(
     deps: Deps,
-    app: &CroncatApp,
     creator_addr: String,
     task_tag: String,
 ) -> CroncatResult<TaskBalanceResponse> {
     let creator_addr = deps.api.addr_validate(&creator_addr)?;
     let (task_hash, task_version) = ACTIVE_TASKS.load(deps.storage, (creator_addr, task_tag))?;
 
-    let name_service = app.name_service(deps);
-    let factory_addr = factory_addr(&name_service)?;
+    let factory_addr = CONFIG.load(deps.storage)?.factory_addr;
     let manager_addr = get_croncat_contract(
         &deps.querier,
         factory_addr,
@@ -265,17 +254,11 @@ fn query_task_balance(
     Ok(task_balance)
 }
 
-fn query_manager_addr(
-    deps: Deps,
-    app: &CroncatApp,
-    creator_addr: String,
-    task_tag: String,
-) -> CroncatResult<Addr> {
+fn query_manager_addr(deps: Deps, creator_addr: String, task_tag: String) -> CroncatResult<Addr> {
     let creator_addr = deps.api.addr_validate(&creator_addr)?;
     let (_, task_version) = ACTIVE_TASKS.load(deps.storage, (creator_addr, task_tag))?;
 
-    let name_service = app.name_service(deps);
-    let factory_addr = factory_addr(&name_service)?;
+    let factory_addr = CONFIG.load(deps.storage)?.factory_addr;
     let manager_addr = get_croncat_contract(
         &deps.querier,
         factory_addr,
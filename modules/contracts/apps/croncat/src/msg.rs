@@ -22,7 +22,7 @@ pub struct AppInstantiateMsg {}
 #[derive(cw_orch::ExecuteFns)]
 pub enum AppExecuteMsg {
     /// Update config
-    /// currently this method is just placeholder
+    /// Re-resolves the CronCat factory address from ANS
     UpdateConfig {},
     /// Create new croncat task
     CreateTask {
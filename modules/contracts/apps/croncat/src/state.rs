@@ -2,7 +2,10 @@ use cosmwasm_std::Addr;
 use cw_storage_plus::{Item, Map};
 
 #[cosmwasm_schema::cw_serde]
-pub struct Config {}
+pub struct Config {
+    /// CronCat factory address, resolved from ANS
+    pub factory_addr: Addr,
+}
 
 pub const CONFIG: Item<Config> = Item::new("config");
 
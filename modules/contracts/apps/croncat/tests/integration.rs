@@ -214,6 +214,7 @@ struct TestingSetup {
     abstr_deployment: Abstract<MockBech32>,
     module_contract: Croncat<MockBech32>,
     cw20_addr: Addr,
+    factory_addr: Addr,
     mock: MockBech32,
 }
 
@@ -267,6 +268,7 @@ fn setup() -> anyhow::Result<TestingSetup> {
         abstr_deployment,
         module_contract: contract,
         cw20_addr,
+        factory_addr,
         mock,
     })
 }
@@ -488,17 +490,25 @@ fn admin() -> anyhow::Result<()> {
 fn update_config() -> anyhow::Result<()> {
     // Set up the environment and contract
     let TestingSetup {
-        module_contract, ..
+        module_contract,
+        factory_addr,
+        ..
     } = setup()?;
 
+    // factory address is cached at instantiation
     let config_res: ConfigResponse = module_contract.config()?;
 
-    assert_eq!(config_res.config, Config {});
+    assert_eq!(
+        config_res.config,
+        Config {
+            factory_addr: factory_addr.clone()
+        }
+    );
 
     module_contract.update_config()?;
 
     let config_res: ConfigResponse = module_contract.config()?;
-    assert_eq!(config_res.config, Config {});
+    assert_eq!(config_res.config, Config { factory_addr });
     Ok(())
 }
 
@@ -284,6 +284,7 @@ fn setup() -> anyhow::Result<(
     let abstr_deployment = Abstract::deploy_on(mock.clone(), sender.to_string())?;
     abstr_deployment.ans_host.execute(
         &abstract_app::std::ans_host::ExecuteMsg::UpdateAssetAddresses {
+            allow_overwrite: false,
             to_add: vec![("denom".to_owned(), AssetInfo::native(DENOM).into())],
             to_remove: vec![],
         },
@@ -302,6 +302,7 @@ fn test_tip_swap_and_not_swap() -> anyhow::Result<()> {
     abstr_deployment
         .ans_host
         .update_asset_addresses(
+            false,
             vec![(
                 tip_currency1.to_string(),
                 cw_asset::AssetInfoBase::native(tip_currency1.to_string()),
@@ -360,6 +361,7 @@ fn test_cw20_tip() -> anyhow::Result<()> {
     abstr_deployment
         .ans_host
         .update_asset_addresses(
+            false,
             vec![(
                 cw20_token.address()?.to_string(),
                 cw_asset::AssetInfoBase::cw20(cw20_token.address()?.to_string()),
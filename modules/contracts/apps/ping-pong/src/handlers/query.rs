@@ -1,15 +1,22 @@
-use cosmwasm_std::{to_json_binary, Binary, Deps, Env, StdResult};
+use cosmwasm_std::{to_json_binary, Binary, Deps, Env, Order, StdResult};
+use cw_storage_plus::Bound;
 
 use crate::{
     contract::{App, AppResult},
-    msg::{AppQueryMsg, BlockHeightResponse, GameStatusResponse},
-    state::{LOSSES, WINS},
+    msg::{AppQueryMsg, BlockHeightResponse, GameStatusResponse, HistoryResponse},
+    state::{GAMES, LOSSES, WINS},
 };
 
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 100;
+
 pub fn query_handler(deps: Deps, env: Env, _app: &App, msg: AppQueryMsg) -> AppResult<Binary> {
     match msg {
         AppQueryMsg::GameStatus {} => to_json_binary(&query_wins(deps)?),
         AppQueryMsg::BlockHeight {} => to_json_binary(&query_block_height(env)?),
+        AppQueryMsg::History { start_after, limit } => {
+            to_json_binary(&query_history(deps, start_after, limit)?)
+        }
     }
     .map_err(Into::into)
 }
@@ -26,3 +33,19 @@ fn query_block_height(env: Env) -> StdResult<BlockHeightResponse> {
         height: env.block.height,
     })
 }
+
+fn query_history(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<HistoryResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let end = start_after.map(Bound::exclusive);
+
+    let games = GAMES
+        .range(deps.storage, None, end, Order::Descending)
+        .take(limit)
+        .collect::<StdResult<_>>()?;
+
+    Ok(HistoryResponse { games })
+}
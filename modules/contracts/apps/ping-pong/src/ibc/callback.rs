@@ -9,7 +9,7 @@ use crate::{
     contract::{App, AppResult},
     handlers::execute::ping_pong,
     msg::{BlockHeightResponse, PingPongCallbackMsg},
-    state::{LOSSES, WINS},
+    state::{record_game, GameRecord, LOSSES, WINS},
 };
 
 pub fn ibc_callback(
@@ -39,9 +39,27 @@ pub fn ibc_callback(
                 }
                 // we lost
                 LOSSES.update(deps.storage, |l| AppResult::Ok(l + 1))?;
+                record_game(
+                    deps.storage,
+                    &GameRecord {
+                        opponent_chain,
+                        initiated: true,
+                        won: false,
+                        block_height: env.block.height,
+                    },
+                )?;
                 Ok(app.response("lost"))
             } else {
                 WINS.update(deps.storage, |w| AppResult::Ok(w + 1))?;
+                record_game(
+                    deps.storage,
+                    &GameRecord {
+                        opponent_chain,
+                        initiated: true,
+                        won: true,
+                        block_height: env.block.height,
+                    },
+                )?;
                 Ok(app.response("won"))
             }
         }
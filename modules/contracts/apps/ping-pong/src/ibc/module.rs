@@ -5,7 +5,7 @@ use crate::{
     contract::{App, AppResult},
     error::AppError,
     msg::{PingOrPong, PingPongIbcMsg},
-    state::LOSSES,
+    state::{record_game, GameRecord, LOSSES},
 };
 
 pub fn receive_module_ibc(
@@ -41,6 +41,15 @@ pub fn receive_module_ibc(
     } else {
         // else we lost
         LOSSES.update(deps.storage, |l| AppResult::Ok(l + 1))?;
+        record_game(
+            deps.storage,
+            &GameRecord {
+                opponent_chain: source_module.chain,
+                initiated: false,
+                won: false,
+                block_height: env.block.height,
+            },
+        )?;
     }
     Ok(resp)
 }
@@ -1,7 +1,7 @@
 use abstract_app::objects::TruncatedChainId;
 use cosmwasm_schema::QueryResponses;
 
-use crate::contract::App;
+use crate::{contract::App, state::GameRecord};
 
 // This is used for type safety and re-exporting the contract endpoint structs.
 abstract_app::app_msg_types!(App, AppExecuteMsg, AppQueryMsg);
@@ -30,6 +30,12 @@ pub enum AppQueryMsg {
     /// Returns last ping pong that was initiated through this smart contract
     #[returns(BlockHeightResponse)]
     BlockHeight {},
+    /// Returns finished games counted in the game status, newest first
+    #[returns(HistoryResponse)]
+    History {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -63,6 +69,11 @@ pub struct BlockHeightResponse {
     pub height: u64,
 }
 
+#[cosmwasm_schema::cw_serde]
+pub struct HistoryResponse {
+    pub games: Vec<(u64, GameRecord)>,
+}
+
 #[cosmwasm_schema::cw_serde]
 pub struct PreviousPingPongResponse {
     pub pongs: Option<u32>,
@@ -1,4 +1,27 @@
-use cw_storage_plus::Item;
+use abstract_app::objects::TruncatedChainId;
+use cosmwasm_std::{Order, StdResult, Storage};
+use cw_storage_plus::{Item, Map};
 
 pub const WINS: Item<u32> = Item::new("wins");
 pub const LOSSES: Item<u32> = Item::new("losses");
+/// Finished games keyed by an incrementing game id
+pub const GAMES: Map<u64, GameRecord> = Map::new("games");
+
+#[cosmwasm_schema::cw_serde]
+pub struct GameRecord {
+    pub opponent_chain: TruncatedChainId,
+    /// Whether this module started the game
+    pub initiated: bool,
+    pub won: bool,
+    pub block_height: u64,
+}
+
+/// Append a finished game to the history
+pub fn record_game(storage: &mut dyn Storage, record: &GameRecord) -> StdResult<()> {
+    let next_id = GAMES
+        .keys(storage, None, None, Order::Descending)
+        .next()
+        .transpose()?
+        .map_or(0, |id| id + 1);
+    GAMES.save(storage, next_id, record)
+}
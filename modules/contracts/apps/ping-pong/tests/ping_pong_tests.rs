@@ -1,6 +1,7 @@
 use abstract_app::abstract_interface::VCQueryFns;
 use abstract_app::objects::namespace::Namespace;
 use abstract_app::objects::AccountId;
+use abstract_app::std::app::{BaseQueryMsgFns, HandlersResponse};
 
 use abstract_app::std::ABSTRACT_EVENT_TYPE;
 use abstract_client::{AbstractClient, Application, Environment, RemoteAccount};
@@ -12,7 +13,8 @@ use cw_orch::{anyhow, prelude::*};
 use cw_orch_interchain::prelude::*;
 
 use ping_pong::contract::APP_ID;
-use ping_pong::msg::{AppInstantiateMsg, AppQueryMsg, GameStatusResponse};
+use ping_pong::msg::{AppInstantiateMsg, AppQueryMsg, GameStatusResponse, HistoryResponse};
+use ping_pong::state::GameRecord;
 use ping_pong::{AppExecuteMsgFns, AppInterface, AppQueryMsgFns};
 
 const JUNO: &str = "juno-1";
@@ -105,6 +107,15 @@ fn successful_install() -> anyhow::Result<()> {
     let game_status = app1.game_status()?;
     assert_eq!(game_status, GameStatusResponse { wins: 0, losses: 0 });
 
+    let handlers = app1.handlers()?;
+    assert_eq!(
+        handlers,
+        HandlersResponse {
+            reply_ids: vec![],
+            ibc_callback: true,
+        }
+    );
+
     let module_addrs = env
         .remote_account
         .module_addresses(vec![APP_ID.to_owned()])?;
@@ -171,6 +182,39 @@ fn successful_ping_pong() -> anyhow::Result<()> {
 
     let wins: GameStatusResponse = remote_app.game_status()?;
     assert_eq!(wins.losses, 1);
+
+    // history is newest first
+    let stargaze = TruncatedChainId::from_chain_id(STARGAZE);
+    let HistoryResponse { games } = app.history(None, None)?;
+    assert_eq!(games.len(), 2);
+    assert_eq!((games[0].0, games[0].1.won), (1, true));
+    assert_eq!((games[1].0, games[1].1.won), (0, false));
+    assert!(games
+        .iter()
+        .all(|(_, game)| game.initiated && game.opponent_chain == stargaze));
+
+    // paginate, arguments are (limit, start_after)
+    let HistoryResponse { games } = app.history(Some(1), None)?;
+    assert_eq!(games.len(), 1);
+    assert_eq!(games[0].0, 1);
+    let HistoryResponse { games } = app.history(None, Some(1))?;
+    assert_eq!(games.len(), 1);
+    assert_eq!(games[0].0, 0);
+
+    // stargaze lost the game it received
+    let HistoryResponse { games } = remote_app.history(None, None)?;
+    assert_eq!(
+        games,
+        vec![(
+            0,
+            GameRecord {
+                opponent_chain: TruncatedChainId::from_chain_id(JUNO),
+                initiated: false,
+                won: false,
+                block_height: games[0].1.block_height,
+            }
+        )]
+    );
     Ok(())
 }
 
@@ -30,6 +30,7 @@ const SUBSCRIPTION_MODULE: SubscriptionApp =
         .with_execute(handlers::execute_handler)
         .with_instantiate(handlers::instantiate_handler)
         .with_query(handlers::query_handler)
+        .with_migrate(handlers::migrate_handler)
         .with_receive(handlers::receive_cw20);
 
 // export endpoints
@@ -58,8 +59,8 @@ mod tests {
     use crate::{
         msg::{HookReceiverExecuteMsg, UnsubscribedHookMsg},
         state::{
-            Subscriber, SubscriptionConfig, SubscriptionState, INCOME_TWA, SUBSCRIBERS,
-            SUBSCRIPTION_CONFIG, SUBSCRIPTION_STATE,
+            Subscriber, SubscriptionConfig, SubscriptionState, EXPIRED_SUBSCRIBERS, INCOME_TWA,
+            SUBSCRIBERS, SUBSCRIPTION_CONFIG, SUBSCRIPTION_STATE,
         },
     };
 
@@ -95,7 +96,13 @@ mod tests {
             )
             .unwrap();
         SUBSCRIPTION_STATE
-            .save(depsmut.storage, &SubscriptionState { active_subs: 1 })
+            .save(
+                depsmut.storage,
+                &SubscriptionState {
+                    active_subs: 1,
+                    expired_subs: 0,
+                },
+            )
             .unwrap();
 
         let res =
@@ -136,7 +143,13 @@ mod tests {
             )
             .unwrap();
         SUBSCRIPTION_STATE
-            .save(depsmut.storage, &SubscriptionState { active_subs: 1 })
+            .save(
+                depsmut.storage,
+                &SubscriptionState {
+                    active_subs: 1,
+                    expired_subs: 0,
+                },
+            )
             .unwrap();
 
         let res =
@@ -152,4 +165,45 @@ mod tests {
         }));
         assert_eq!(res.messages, vec![expected_msg]);
     }
+
+    #[test]
+    fn migrate_recounts_expired_subscribers() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let app = SUBSCRIPTION_MODULE;
+
+        for expired in ["bob", "alice"] {
+            EXPIRED_SUBSCRIBERS
+                .save(
+                    deps.as_mut().storage,
+                    &Addr::unchecked(expired),
+                    &Subscriber {
+                        expiration_timestamp: env.block.time,
+                        last_emission_claim_timestamp: env.block.time,
+                    },
+                )
+                .unwrap();
+        }
+        // State stored before the expired subscribers were counted
+        SUBSCRIPTION_STATE
+            .save(
+                deps.as_mut().storage,
+                &SubscriptionState {
+                    active_subs: 1,
+                    expired_subs: 0,
+                },
+            )
+            .unwrap();
+
+        handlers::migrate_handler(deps.as_mut(), env, app, SubscriptionMigrateMsg {}).unwrap();
+
+        let state = SUBSCRIPTION_STATE.load(&deps.storage).unwrap();
+        assert_eq!(
+            state,
+            SubscriptionState {
+                active_subs: 1,
+                expired_subs: 2,
+            }
+        );
+    }
 }
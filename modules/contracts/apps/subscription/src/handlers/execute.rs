@@ -1,8 +1,11 @@
 use abstract_app::sdk::{
     cw_helpers::Clearable, AbstractResponse, AccountAction, Execution, TransferInterface,
 };
-use cosmwasm_std::{Addr, Decimal, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Uint128};
+use cosmwasm_std::{
+    Addr, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult, Uint128,
+};
 use cw_asset::{Asset, AssetInfoUnchecked};
+use cw_storage_plus::Bound;
 
 use crate::{
     contract::{SubscriptionApp, SubscriptionResult},
@@ -39,6 +42,9 @@ pub fn execute_handler(
         SubscriptionExecuteMsg::Unsubscribe { unsubscribe_addrs } => {
             unsubscribe(deps, env, app, unsubscribe_addrs)
         }
+        SubscriptionExecuteMsg::ProcessExpirations { start_after, limit } => {
+            process_expirations(deps, env, app, start_after, limit)
+        }
         SubscriptionExecuteMsg::ClaimEmissions { addr } => {
             claim_subscriber_emissions(&app, &mut deps, &env, addr)
         }
@@ -113,7 +119,10 @@ pub fn try_pay(
                 * config.subscription_cost_per_second,
         )?;
         // Remove from expired list in case it's re-sub
-        EXPIRED_SUBSCRIBERS.remove(deps.storage, &subscriber_addr);
+        if EXPIRED_SUBSCRIBERS.has(deps.storage, &subscriber_addr) {
+            EXPIRED_SUBSCRIBERS.remove(deps.storage, &subscriber_addr);
+            subscription_state.expired_subs = subscription_state.expired_subs.saturating_sub(1);
+        }
 
         SUBSCRIBERS.save(deps.storage, &subscriber_addr, &subscriber)?;
         subscription_state.active_subs += 1;
@@ -142,6 +151,71 @@ pub fn unsubscribe(
         .iter()
         .map(|human| deps.api.addr_validate(human))
         .collect::<StdResult<_>>()?;
+
+    let (msgs, canceled_subs) = expire_subscribers(deps, &env, &app, unsubscribe_addrs)?;
+    // Error if no one unsubbed
+    if canceled_subs.is_empty() {
+        return Err(SubscriptionError::NoOneUnsubbed {});
+    }
+    Ok(app.response("unsubscribe").add_messages(msgs))
+}
+
+/// Scan the active subscribers and unsubscribe the expired ones.
+/// Permissionless, so it can be called regularly by anyone (e.g. a keeper) to clean up the subscribers.
+pub fn process_expirations(
+    deps: DepsMut,
+    env: Env,
+    app: SubscriptionApp,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> SubscriptionResult {
+    let start_after = start_after
+        .map(|human| deps.api.addr_validate(&human))
+        .transpose()?;
+    let limit = limit.map_or(MAX_UNSUBS, |limit| (limit as usize).min(MAX_UNSUBS));
+
+    let scanned: Vec<(Addr, Subscriber)> = SUBSCRIBERS
+        .range(
+            deps.storage,
+            start_after.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<_>>()?;
+    // Cursor for the next call, none once all subscribers are scanned
+    let next_start_after = match scanned.last() {
+        Some((addr, _)) if scanned.len() == limit => addr.to_string(),
+        _ => String::new(),
+    };
+    let scanned_count = scanned.len();
+    let expired_addrs: Vec<Addr> = scanned
+        .into_iter()
+        .filter(|(_, subscriber)| subscriber.is_expired(&env.block))
+        .map(|(addr, _)| addr)
+        .collect();
+
+    let (msgs, expired_subs) = expire_subscribers(deps, &env, &app, expired_addrs)?;
+    Ok(app
+        .custom_response(
+            "process_expirations",
+            vec![
+                ("scanned", scanned_count.to_string()),
+                ("expired", expired_subs.len().to_string()),
+                ("next_start_after", next_start_after),
+            ],
+        )
+        .add_messages(msgs))
+}
+
+/// Move the expired subscribers of `addrs` to the expired subscribers, claiming their outstanding emissions.
+/// Returns the claim and unsubscribe hook messages, along with the addresses of the unsubscribed subscribers.
+fn expire_subscribers(
+    deps: DepsMut,
+    env: &Env,
+    app: &SubscriptionApp,
+    addrs: Vec<Addr>,
+) -> SubscriptionResult<(Vec<CosmosMsg>, Vec<String>)> {
     let mut subscription_state = SUBSCRIPTION_STATE.load(deps.storage)?;
     let subscription_config = SUBSCRIPTION_CONFIG.load(deps.storage)?;
     let mut canceled_subs: Vec<String> = vec![];
@@ -149,19 +223,19 @@ pub fn unsubscribe(
 
     // update income
     INCOME_TWA.accumulate(
-        &env,
+        env,
         deps.storage,
         Decimal::from_atomics(Uint128::from(subscription_state.active_subs), 0)?
             * subscription_config.subscription_cost_per_second,
     )?;
 
-    for addr in unsubscribe_addrs.into_iter() {
+    for addr in addrs.into_iter() {
         let mut subscriber = SUBSCRIBERS.load(deps.storage, &addr)?;
         if subscriber.is_expired(&env.block) {
             let maybe_claim_msg = match claim_emissions_msg(
-                &app,
+                app,
                 deps.as_ref(),
-                &env,
+                env,
                 &mut subscriber,
                 &addr,
                 subscription_config
@@ -179,6 +253,7 @@ pub fn unsubscribe(
             };
 
             subscription_state.active_subs -= 1;
+            subscription_state.expired_subs += 1;
             SUBSCRIBERS.remove(deps.storage, &addr);
             EXPIRED_SUBSCRIBERS.save(deps.storage, &addr, &subscriber)?;
             canceled_subs.push(addr.into_string());
@@ -189,27 +264,25 @@ pub fn unsubscribe(
         }
     }
 
-    // Error if no one unsubbed
-    if canceled_subs.is_empty() {
-        return Err(SubscriptionError::NoOneUnsubbed {});
-    }
-
     // update subscription count
     SUBSCRIPTION_STATE.save(deps.storage, &subscription_state)?;
 
-    let mut response = app
-        .response("unsubscribe")
-        .add_messages(app.executor(deps.as_ref()).execute(claim_actions));
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    if !claim_actions.is_empty() {
+        msgs.push(app.executor(deps.as_ref()).execute(claim_actions)?.into());
+    }
 
     if let Some(hook) = subscription_config.unsubscribe_hook_addr {
-        let msg = UnsubscribedHookMsg {
-            unsubscribed: canceled_subs,
+        if !canceled_subs.is_empty() {
+            let msg = UnsubscribedHookMsg {
+                unsubscribed: canceled_subs.clone(),
+            }
+            .into_cosmos_msg(hook)?;
+            msgs.push(msg);
         }
-        .into_cosmos_msg(hook)?;
-        response = response.add_message(msg);
     }
 
-    Ok(response)
+    Ok((msgs, canceled_subs))
 }
 
 // Claim emissions
@@ -222,14 +295,13 @@ pub fn claim_emissions_msg(
     subscription_per_second_emissions: EmissionType<Addr>,
     subscription_state: &SubscriptionState,
 ) -> SubscriptionResult<Option<AccountAction>> {
-    if subscriber.last_emission_claim_timestamp >= env.block.time {
+    // Emissions stop accruing once the subscription expires
+    let accrue_until = subscriber.emissions_accrue_until(&env.block);
+    if subscriber.last_emission_claim_timestamp >= accrue_until {
         return Err(SubscriptionError::EmissionsAlreadyClaimed {});
     }
 
-    let duration = env
-        .block
-        .time
-        .minus_seconds(subscriber.last_emission_claim_timestamp.seconds());
+    let duration = accrue_until.minus_seconds(subscriber.last_emission_claim_timestamp.seconds());
     let seconds_passed = duration.seconds();
 
     let asset = match subscription_per_second_emissions {
@@ -250,7 +322,7 @@ pub fn claim_emissions_msg(
 
     if !asset.amount.is_zero() {
         // Update only if there was claim
-        subscriber.last_emission_claim_timestamp = env.block.time;
+        subscriber.last_emission_claim_timestamp = accrue_until;
 
         let send_msg = app.bank(deps).transfer(vec![asset], subscriber_addr)?;
         Ok(Some(send_msg))
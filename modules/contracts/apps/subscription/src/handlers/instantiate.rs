@@ -26,7 +26,10 @@ pub fn instantiate_handler(
             .transpose()?,
     };
 
-    let subscription_state: SubscriptionState = SubscriptionState { active_subs: 0 };
+    let subscription_state: SubscriptionState = SubscriptionState {
+        active_subs: 0,
+        expired_subs: 0,
+    };
     SUBSCRIPTION_CONFIG.save(deps.storage, &subscription_config)?;
     SUBSCRIPTION_STATE.save(deps.storage, &subscription_state)?;
 
@@ -0,0 +1,29 @@
+use abstract_app::sdk::AbstractResponse;
+use cosmwasm_std::{DepsMut, Env, Order, StdError};
+
+use crate::{
+    contract::{SubscriptionApp, SubscriptionResult},
+    msg::SubscriptionMigrateMsg,
+    state::{EXPIRED_SUBSCRIBERS, SUBSCRIPTION_STATE},
+};
+
+/// Handle the app migrate msg
+/// The top-level Abstract app does version checking and dispatches to this handler
+pub fn migrate_handler(
+    deps: DepsMut,
+    _env: Env,
+    app: SubscriptionApp,
+    _msg: SubscriptionMigrateMsg,
+) -> SubscriptionResult {
+    // Recompute the expired subscribers counter for apps instantiated before it was tracked
+    let expired_subs = EXPIRED_SUBSCRIBERS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .count();
+    let expired_subs = u32::try_from(expired_subs)
+        .map_err(|_| StdError::generic_err("expired subscribers count overflows u32"))?;
+    SUBSCRIPTION_STATE.update(deps.storage, |mut state| -> SubscriptionResult<_> {
+        state.expired_subs = expired_subs;
+        Ok(state)
+    })?;
+    Ok(app.response("migrate"))
+}
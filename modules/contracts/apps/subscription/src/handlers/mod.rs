@@ -1,9 +1,10 @@
 pub mod execute;
 pub mod instantiate;
+pub mod migrate;
 pub mod query;
 pub mod receive;
 
 pub use crate::handlers::{
-    execute::execute_handler, instantiate::instantiate_handler, query::query_handler,
-    receive::receive_cw20,
+    execute::execute_handler, instantiate::instantiate_handler, migrate::migrate_handler,
+    query::query_handler, receive::receive_cw20,
 };
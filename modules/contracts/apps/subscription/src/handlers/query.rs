@@ -1,19 +1,23 @@
 use abstract_app::std::objects::voting::DEFAULT_LIMIT;
-use cosmwasm_std::{to_json_binary, Binary, Deps, Env, StdResult, Uint128};
+use cosmwasm_std::{to_json_binary, Binary, Decimal, Deps, Env, StdResult, Uint128};
 use cw_asset::Asset;
 use cw_storage_plus::Bound;
 
 use crate::{
     contract::{SubscriptionApp, SubscriptionResult},
     msg::{
-        StateResponse, SubscriberResponse, SubscribersResponse, SubscriptionFeeResponse,
-        SubscriptionQueryMsg,
+        MetricsResponse, StateResponse, SubscriberResponse, SubscribersResponse,
+        SubscriptionFeeResponse, SubscriptionQueryMsg,
     },
     state::{
-        EXPIRED_SUBSCRIBERS, INCOME_TWA, SUBSCRIBERS, SUBSCRIPTION_CONFIG, SUBSCRIPTION_STATE,
+        EmissionType, EXPIRED_SUBSCRIBERS, INCOME_TWA, SUBSCRIBERS, SUBSCRIPTION_CONFIG,
+        SUBSCRIPTION_STATE,
     },
 };
 
+/// Max number of subscribers returned by a single [`SubscriptionQueryMsg::Subscribers`] query
+pub(crate) const MAX_LIMIT: u64 = 100;
+
 pub fn query_handler(
     deps: Deps,
     _env: Env,
@@ -28,6 +32,7 @@ pub fn query_handler(
                 subscription: subscription_state,
             })
         }
+        SubscriptionQueryMsg::Metrics {} => to_json_binary(&query_metrics(deps)?),
         SubscriptionQueryMsg::Fee {} => {
             let config = SUBSCRIPTION_CONFIG.load(deps.storage)?;
             let twa_data = INCOME_TWA.load(deps.storage)?;
@@ -54,6 +59,27 @@ pub fn query_handler(
     .map_err(Into::into)
 }
 
+fn query_metrics(deps: Deps) -> SubscriptionResult<MetricsResponse> {
+    let config = SUBSCRIPTION_CONFIG.load(deps.storage)?;
+    let state = SUBSCRIPTION_STATE.load(deps.storage)?;
+
+    let active_subs = Decimal::from_atomics(Uint128::from(state.active_subs), 0)?;
+    let emissions_per_second = match config.subscription_per_second_emissions {
+        EmissionType::None => Decimal::zero(),
+        // Shared emissions are only paid out while there are subscribers
+        EmissionType::SecondShared(_, _) if state.active_subs == 0 => Decimal::zero(),
+        EmissionType::SecondShared(shared, _) => shared,
+        EmissionType::SecondPerUser(per_user, _) => per_user * active_subs,
+    };
+
+    Ok(MetricsResponse {
+        active_subs: state.active_subs,
+        expired_subs: state.expired_subs,
+        income_per_second: active_subs * config.subscription_cost_per_second,
+        emissions_per_second,
+    })
+}
+
 fn query_subscriber(deps: Deps, addr: String) -> SubscriptionResult<SubscriberResponse> {
     let addr = deps.api.addr_validate(&addr)?;
     let subscription_state = if let Some(sub) = SUBSCRIBERS.may_load(deps.storage, &addr)? {
@@ -82,7 +108,7 @@ fn query_subscribers(
     expired_subs: Option<bool>,
 ) -> SubscriptionResult<SubscribersResponse> {
     let min = start_after.as_ref().map(Bound::exclusive);
-    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
     let subscribed = !expired_subs.unwrap_or(false);
     let map = match subscribed {
         true => SUBSCRIBERS,
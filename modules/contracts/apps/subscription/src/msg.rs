@@ -77,6 +77,14 @@ pub enum SubscriptionExecuteMsg {
         /// List of inactive accounts to move to the `DORMANT_SUBSCRIBERS` list
         unsubscribe_addrs: Vec<String>,
     },
+    /// Move expired subscribers to the `DORMANT_SUBSCRIBERS` list.
+    /// Scans at most `limit` active subscribers, starting after `start_after`.
+    ProcessExpirations {
+        /// Continue scanning after this subscriber, see the `next_start_after` attribute of the response
+        start_after: Option<String>,
+        /// Maximum amount of subscribers to scan
+        limit: Option<u32>,
+    },
     /// Claim the emissions for subscriber
     ClaimEmissions {
         /// Address of subscriber
@@ -105,6 +113,10 @@ pub enum SubscriptionQueryMsg {
     /// Returns [`StateResponse`]
     #[returns(StateResponse)]
     State {},
+    /// Get subscriber counts and the current per-second income and emissions
+    /// Returns [`MetricsResponse`]
+    #[returns(MetricsResponse)]
+    Metrics {},
     /// Get config of subscriptions and contributors
     /// Returns [`SubscriptionConfig`]
     #[returns(SubscriptionConfig)]
@@ -126,7 +138,7 @@ pub enum SubscriptionQueryMsg {
     Subscribers {
         /// Start after subscriber address
         start_after: Option<Addr>,
-        /// Limit
+        /// Limit, capped at 100
         limit: Option<u64>,
         /// Get list of expired(inactive) subscribers instead
         expired_subs: Option<bool>,
@@ -151,6 +163,19 @@ pub struct StateResponse {
     pub subscription: SubscriptionState,
 }
 
+/// Query response for [`SubscriptionQueryMsg::Metrics`]
+#[cosmwasm_schema::cw_serde]
+pub struct MetricsResponse {
+    /// amount of active subscribers
+    pub active_subs: u32,
+    /// amount of expired subscribers
+    pub expired_subs: u32,
+    /// Income of all active subscriptions, per second
+    pub income_per_second: Decimal,
+    /// Emissions paid out to all active subscribers, per second
+    pub emissions_per_second: Decimal,
+}
+
 /// Query response for [`SubscriptionQueryMsg::Fee`]
 #[cosmwasm_schema::cw_serde]
 pub struct SubscriptionFeeResponse {
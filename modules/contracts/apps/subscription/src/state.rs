@@ -55,6 +55,9 @@ pub struct SubscriptionConfig {
 pub struct SubscriptionState {
     /// amount of active subscribers
     pub active_subs: u32,
+    /// amount of expired subscribers
+    #[serde(default)]
+    pub expired_subs: u32,
 }
 
 /// Stored info for each subscriber.
@@ -81,6 +84,11 @@ impl Subscriber {
     pub fn is_expired(&self, block: &BlockInfo) -> bool {
         block.time >= self.expiration_timestamp
     }
+
+    /// Time up to which emissions accrue: the current block time, capped at the expiration.
+    pub fn emissions_accrue_until(&self, block: &BlockInfo) -> Timestamp {
+        block.time.min(self.expiration_timestamp)
+    }
 }
 
 /// Average number of subscribers
@@ -6,7 +6,10 @@ use abstract_app::std::objects::{
 use abstract_client::{builder::cw20_builder, AbstractClient, Application, Environment, Publisher};
 use abstract_subscription::{
     contract::interface::SubscriptionInterface,
-    msg::{SubscriptionExecuteMsgFns, SubscriptionInstantiateMsg, SubscriptionQueryMsgFns},
+    msg::{
+        MetricsResponse, SubscriptionExecuteMsgFns, SubscriptionInstantiateMsg,
+        SubscriptionQueryMsgFns,
+    },
     state::{EmissionType, Subscriber, SubscriptionConfig},
     SubscriptionError,
 };
@@ -475,6 +478,80 @@ fn claim_emissions_errors() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn claim_emissions_stop_at_expiration() -> anyhow::Result<()> {
+    let subscriber1 = "subscriber1";
+    // For 4 weeks with few hours
+    let sub_amount = coins(90, DENOM);
+    let per_user_emissions = Decimal::from_str("0.00005")?;
+
+    let NativeSubscription {
+        client,
+        subscription_app,
+        payment_asset: _,
+        emission_cw20,
+        mock,
+    } = setup_native(vec![(subscriber1, &sub_amount)])?;
+    let subscriber1 = mock.addr_make(subscriber1);
+
+    subscription_app
+        .call_as(&subscription_app.account().manager()?)
+        .update_subscription_config(
+            None,
+            None,
+            Some(EmissionType::SecondPerUser(
+                per_user_emissions,
+                AssetInfoBase::Cw20(emission_cw20.addr_str()?),
+            )),
+            None,
+        )?;
+
+    subscription_app
+        .call_as(&subscriber1)
+        .pay(None, &sub_amount)?;
+    let start_time = client.block_info()?.time;
+    let expiration = subscription_app
+        .subscriber(subscriber1.to_string())?
+        .subscriber_details
+        .unwrap()
+        .expiration_timestamp;
+
+    // Before expiration: accrues up to the current block
+    client.wait_seconds(WEEK_IN_SECONDS)?;
+    subscription_app.claim_emissions(subscriber1.to_string())?;
+    let balance = emission_cw20.balance(subscriber1.to_string())?;
+    assert_eq!(
+        balance.balance,
+        per_user_emissions * Uint128::from(WEEK_IN_SECONDS)
+    );
+
+    // At expiration: accrues the full subscribed period
+    let full_period =
+        per_user_emissions * Uint128::from(expiration.seconds() - start_time.seconds());
+    client.wait_seconds(expiration.seconds() - client.block_info()?.time.seconds())?;
+    subscription_app.claim_emissions(subscriber1.to_string())?;
+    let balance = emission_cw20.balance(subscriber1.to_string())?;
+    assert_eq!(balance.balance, full_period);
+
+    // Well past expiration: nothing left to claim
+    client.wait_seconds(WEEK_IN_SECONDS * 4)?;
+    let err = subscription_app
+        .claim_emissions(subscriber1.to_string())
+        .unwrap_err();
+    let err: SubscriptionError = err.downcast().unwrap();
+    assert_eq!(err, SubscriptionError::EmissionsAlreadyClaimed {});
+    let balance = emission_cw20.balance(subscriber1.to_string())?;
+    assert_eq!(balance.balance, full_period);
+
+    let subscriber_details = subscription_app
+        .subscriber(subscriber1.to_string())?
+        .subscriber_details
+        .unwrap();
+    assert_eq!(subscriber_details.last_emission_claim_timestamp, expiration);
+
+    Ok(())
+}
+
 #[test]
 fn unsubscribe() -> anyhow::Result<()> {
     let subscriber1 = "subscriber1";
@@ -487,7 +564,7 @@ fn unsubscribe() -> anyhow::Result<()> {
         client,
         subscription_app,
         payment_asset: _,
-        emission_cw20,
+        emission_cw20: _,
         mock,
     } = setup_native(vec![(subscriber1, &sub_amount)])?;
     let subscriber1 = mock.addr_make(subscriber1);
@@ -516,22 +593,8 @@ fn unsubscribe() -> anyhow::Result<()> {
     subscription_app.unsubscribe(vec![subscriber1.to_string()])?;
     let subscriber = subscription_app.subscriber(subscriber1.to_string())?;
 
-    let current_time = client.block_info()?.time;
-
     assert!(!subscriber.currently_subscribed);
 
-    let subscriber_details: Subscriber = subscriber.subscriber_details.unwrap();
-    assert_eq!(
-        subscriber_details.last_emission_claim_timestamp,
-        current_time
-    );
-
-    let b = emission_cw20.balance(subscriber1.to_string())?;
-    // 5 weeks passed until unsubscribe
-    assert_eq!(
-        b.balance,
-        Decimal::from_str("0.00005")? * Uint128::from(WEEK_IN_SECONDS * 5)
-    );
     // Unsubscribe on already unsubscribed user should fail
     assert!(subscription_app
         .unsubscribe(vec![subscriber1.to_string()])
@@ -544,6 +607,51 @@ fn unsubscribe() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn unsubscribe_claims_emissions_until_expiration() -> anyhow::Result<()> {
+    let subscriber1 = "subscriber1";
+
+    // For 4 weeks with few hours
+    let sub_amount = coins(90, DENOM);
+
+    let NativeSubscription {
+        client,
+        subscription_app,
+        payment_asset: _,
+        emission_cw20,
+        mock,
+    } = setup_native(vec![(subscriber1, &sub_amount)])?;
+    let subscriber1 = mock.addr_make(subscriber1);
+
+    subscription_app
+        .call_as(&subscriber1)
+        .pay(None, &sub_amount)?;
+    let start_time = client.block_info()?.time;
+    let expiration = subscription_app
+        .subscriber(subscriber1.to_string())?
+        .subscriber_details
+        .unwrap()
+        .expiration_timestamp;
+
+    // 5 weeks passed until unsubscribe, a week past the expiration
+    client.wait_seconds(WEEK_IN_SECONDS * 5)?;
+    subscription_app.unsubscribe(vec![subscriber1.to_string()])?;
+
+    // Emissions are claimed up to the expiration
+    let subscriber_details: Subscriber = subscription_app
+        .subscriber(subscriber1.to_string())?
+        .subscriber_details
+        .unwrap();
+    assert_eq!(subscriber_details.last_emission_claim_timestamp, expiration);
+
+    let b = emission_cw20.balance(subscriber1.to_string())?;
+    assert_eq!(
+        b.balance,
+        Decimal::from_str("0.00005")? * Uint128::from(expiration.seconds() - start_time.seconds())
+    );
+    Ok(())
+}
+
 #[test]
 fn unsubscribe_part_of_list() -> anyhow::Result<()> {
     let subscriber1 = "subscriber1";
@@ -592,6 +700,191 @@ fn unsubscribe_part_of_list() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn process_expirations() -> anyhow::Result<()> {
+    let subscriber1 = "subscriber1";
+    let subscriber2 = "subscriber2";
+    let subscriber3 = "subscriber3";
+    let NativeSubscription {
+        client,
+        subscription_app,
+        payment_asset: _,
+        emission_cw20: _,
+        mock,
+    } = setup_native(vec![
+        (subscriber1, coins(2200, DENOM).as_slice()),
+        (subscriber2, coins(220, DENOM).as_slice()),
+        (subscriber3, coins(220, DENOM).as_slice()),
+    ])?;
+    let subscriber1 = mock.addr_make(subscriber1);
+    let subscriber2 = mock.addr_make(subscriber2);
+    let subscriber3 = mock.addr_make(subscriber3);
+
+    subscription_app
+        .call_as(&subscriber1)
+        .pay(None, &coins(2200, DENOM))?;
+    subscription_app
+        .call_as(&subscriber2)
+        .pay(None, &coins(220, DENOM))?;
+    subscription_app
+        .call_as(&subscriber3)
+        .pay(None, &coins(220, DENOM))?;
+
+    // Nothing expired yet, processing is a no-op
+    subscription_app.process_expirations(None, None)?;
+    assert_eq!(subscription_app.state()?.subscription.active_subs, 3);
+
+    // subscriber2 and subscriber3 paid for a week, subscriber1 for 10 weeks
+    client.wait_seconds(WEEK_IN_SECONDS * 2)?;
+
+    // Process one subscriber at a time, following the cursor
+    let mut start_after = None;
+    loop {
+        let response = subscription_app.process_expirations(Some(1), start_after)?;
+        let next_start_after = response.event_attr_value("wasm-abstract", "next_start_after")?;
+        if next_start_after.is_empty() {
+            break;
+        }
+        start_after = Some(next_start_after);
+    }
+
+    assert!(
+        subscription_app
+            .subscriber(subscriber1.to_string())?
+            .currently_subscribed
+    );
+    assert!(
+        !subscription_app
+            .subscriber(subscriber2.to_string())?
+            .currently_subscribed
+    );
+    assert!(
+        !subscription_app
+            .subscriber(subscriber3.to_string())?
+            .currently_subscribed
+    );
+    assert_eq!(subscription_app.state()?.subscription.active_subs, 1);
+
+    let dormant = subscription_app.subscribers(Some(true), None, None)?;
+    assert_eq!(dormant.subscribers.len(), 2);
+    Ok(())
+}
+
+#[test]
+fn metrics() -> anyhow::Result<()> {
+    let subscriber1 = "subscriber1";
+    let subscriber2 = "subscriber2";
+    let NativeSubscription {
+        client,
+        subscription_app,
+        payment_asset: _,
+        emission_cw20: _,
+        mock,
+    } = setup_native(vec![
+        (subscriber1, coins(440, DENOM).as_slice()),
+        (subscriber2, coins(220, DENOM).as_slice()),
+    ])?;
+    let subscriber1 = mock.addr_make(subscriber1);
+    let subscriber2 = mock.addr_make(subscriber2);
+    let cost_per_second = Decimal::from_str("0.000037")?;
+    let shared_emissions = Decimal::from_str("0.00005")?;
+
+    assert_eq!(
+        subscription_app.metrics()?,
+        MetricsResponse {
+            active_subs: 0,
+            expired_subs: 0,
+            income_per_second: Decimal::zero(),
+            emissions_per_second: Decimal::zero(),
+        }
+    );
+
+    subscription_app
+        .call_as(&subscriber1)
+        .pay(None, &coins(220, DENOM))?;
+    subscription_app
+        .call_as(&subscriber2)
+        .pay(None, &coins(220, DENOM))?;
+    assert_eq!(
+        subscription_app.metrics()?,
+        MetricsResponse {
+            active_subs: 2,
+            expired_subs: 0,
+            income_per_second: cost_per_second * Decimal::from_atomics(2u128, 0)?,
+            emissions_per_second: shared_emissions,
+        }
+    );
+
+    client.wait_seconds(WEEK_IN_SECONDS * 2)?;
+    subscription_app.process_expirations(None, None)?;
+    assert_eq!(
+        subscription_app.metrics()?,
+        MetricsResponse {
+            active_subs: 0,
+            expired_subs: 2,
+            income_per_second: Decimal::zero(),
+            emissions_per_second: Decimal::zero(),
+        }
+    );
+
+    // re-subscribing moves the subscriber out of the expired count
+    subscription_app
+        .call_as(&subscriber1)
+        .pay(None, &coins(220, DENOM))?;
+    assert_eq!(
+        subscription_app.metrics()?,
+        MetricsResponse {
+            active_subs: 1,
+            expired_subs: 1,
+            income_per_second: cost_per_second,
+            emissions_per_second: shared_emissions,
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn paginate_subscribers() -> anyhow::Result<()> {
+    let subscribers = ["subscriber1", "subscriber2", "subscriber3"];
+    let funds = coins(220, DENOM);
+    let NativeSubscription {
+        client: _,
+        subscription_app,
+        payment_asset: _,
+        emission_cw20: _,
+        mock,
+    } = setup_native(subscribers.iter().map(|s| (*s, funds.as_slice())).collect())?;
+    let mut subscribers: Vec<Addr> = subscribers.iter().map(|s| mock.addr_make(s)).collect();
+    for subscriber in &subscribers {
+        subscription_app
+            .call_as(subscriber)
+            .pay(None, &coins(220, DENOM))?;
+    }
+    // subscribers are returned in address order
+    subscribers.sort();
+
+    let first_page = subscription_app.subscribers(None, Some(2), None)?;
+    let first_page: Vec<Addr> = first_page.subscribers.into_iter().map(|(a, _)| a).collect();
+    assert_eq!(first_page, subscribers[..2]);
+
+    let second_page = subscription_app.subscribers(None, Some(2), Some(first_page[1].clone()))?;
+    let second_page: Vec<Addr> = second_page
+        .subscribers
+        .into_iter()
+        .map(|(a, _)| a)
+        .collect();
+    assert_eq!(second_page, subscribers[2..]);
+
+    // past the last subscriber
+    let empty = subscription_app.subscribers(None, Some(2), Some(subscribers[2].clone()))?;
+    assert!(empty.subscribers.is_empty());
+
+    // no expired subscribers yet
+    let expired = subscription_app.subscribers(Some(true), None, None)?;
+    assert!(expired.subscribers.is_empty());
+    Ok(())
+}
+
 // Helper to raw_query twa
 fn query_twa(chain: &MockBech32, subscription_addr: Addr) -> TimeWeightedAverageData {
     let app = chain.app.borrow();